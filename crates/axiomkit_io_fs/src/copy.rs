@@ -1,21 +1,30 @@
 //! Filesystem tree traversal and copy orchestration.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
-use rayon::ThreadPoolBuilder;
 use rayon::prelude::*;
+use rayon::{ThreadPool, ThreadPoolBuilder};
 
 use crate::report::{CopyReport, CopyReportBuilder};
 use crate::spec::{
-    CopyDepthLimitMode, CopyDirectoryConflictMode, CopyOptionsSpec, CopySymlinkMode, CopyTreeError,
+    CopyDepthLimitMode, CopyDirectoryConflictMode, CopyDstSymlinkConflictMode, CopyErrorKind,
+    CopyFileConflictMode, CopyOptionsSpec, CopyPatternMode, CopyPlanAction, CopyPlanEntry,
+    CopySpecialFileMode, CopySymlinkMode, CopyTreeError,
 };
 use crate::util::{
-    CopyPatternsSpec, calculate_worker_limit, copy_file_with_metadata, create_symbolic_link,
-    derive_destination_path, is_depth_within_limit, is_overlap, should_error_broken_symlink,
-    should_exclude_by_patterns, should_skip_dir_conflict, should_skip_file_conflict,
-    validate_destination_path_safety,
+    CopyFileOutcome, CopyPatternsSpec, CopyRateLimiter, SymlinkRewriteSpec, calculate_worker_limit,
+    copy_dir_metadata, copy_file_with_metadata, create_special_file, create_symbolic_link,
+    derive_destination_path, derive_pattern_subject, hash_file_blake3, hash_file_xxh3,
+    is_depth_within_limit, is_file_unchanged, is_hidden_entry, is_mtime_within_window, is_overlap,
+    link_file_atomic, make_destination_writable, normalize_filename, remove_destination_symlink,
+    sanitize_filename, should_error_broken_symlink, should_exclude_by_patterns,
+    should_skip_dir_conflict, should_skip_file_conflict, sync_parent_dir_to_disk,
+    to_extended_length_path, validate_destination_parent_safety, validate_destination_path_safety,
 };
 
 #[derive(Debug, Clone)]
@@ -38,16 +47,92 @@ struct CopyTaskFileSpec {
     file_dst_path: PathBuf,
 }
 
+/// Duplicate of an earlier file task, hard-linked to its destination
+/// (`should_dedup_destination`).
+#[derive(Debug, Clone)]
+struct CopyTaskLinkSpec {
+    file_task: CopyTaskFileSpec,
+    file_existing_path: PathBuf,
+}
+
+/// What two sources must share for one to be hard-linked to the other's copy:
+/// size, BLAKE3 digest and permissions (Unix mode bits, the read-only flag
+/// elsewhere), since a link cannot carry permissions of its own.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct DedupKey {
+    bytes_src: u64,
+    digest: [u8; 32],
+    permissions_bits: u32,
+}
+
+#[derive(Debug, Clone)]
+struct CopyTaskDirSpec {
+    dir_src_path: PathBuf,
+    dir_dst_path: PathBuf,
+}
+
+/// Source path, destination path and the outcome or classified failure of one
+/// file copy.
+type CopyTaskResult = (
+    PathBuf,
+    PathBuf,
+    Result<CopyFileOutcome, (CopyErrorKind, String)>,
+);
+
 #[derive(Debug)]
 struct CopyContext {
     dir_src_path: PathBuf,
     dir_dst_path: PathBuf,
     copy_options: CopyOptionsSpec,
     copy_patterns: CopyPatternsSpec,
-    workers_max: usize,
     report_builder: CopyReportBuilder,
     visited_dirs: HashSet<(u64, u64)>,
+    /// Destination actions recorded by traversal, in traversal order.
+    plan_entries: Vec<CopyPlanEntry>,
     file_copy_tasks: Vec<CopyTaskFileSpec>,
+    created_dirs: Vec<CopyTaskDirSpec>,
+    rate_limiter: Option<CopyRateLimiter>,
+    symlink_rewrite: Option<SymlinkRewriteSpec>,
+    /// Worker pool for file copies and hashing; `None` runs serially.
+    thread_pool: Option<ThreadPool>,
+    /// Source bytes already scheduled against `bytes_budget_max`.
+    bytes_budget_scheduled: u64,
+    /// Set once a file did not fit `bytes_budget_max`; no further copies run.
+    is_bytes_budget_reached: bool,
+    /// Files matched so far, counted against `files_max`.
+    cnt_files_matched: u64,
+    /// Set once `files_max` is exceeded; traversal stops enqueuing files.
+    is_files_max_reached: bool,
+    /// Content and permissions already queued for copying, to the destination
+    /// that receives it (`should_dedup_destination`).
+    dedup_index: HashMap<DedupKey, PathBuf>,
+}
+
+/// Traversal result of [`plan_copy`], carried out by [`execute_plan`].
+///
+/// Holds the destination actions in traversal order together with the run
+/// state (compiled patterns, worker pool, counters gathered while planning).
+#[derive(Debug)]
+pub struct CopyPlan {
+    /// Destination actions in traversal order; a directory precedes its
+    /// contents. Entries may be inspected, removed or retargeted before
+    /// execution.
+    pub entries: Vec<CopyPlanEntry>,
+    copy_ctx: CopyContext,
+}
+
+impl CopyPlan {
+    fn from_context(mut copy_ctx: CopyContext) -> Self {
+        Self {
+            entries: std::mem::take(&mut copy_ctx.plan_entries),
+            copy_ctx,
+        }
+    }
+
+    /// Counters, warnings and errors gathered while planning.
+    pub fn report(&self) -> CopyReport {
+        self.copy_ctx.report_builder.clone().build()
+    }
 }
 
 /// Copy a directory tree from `dir_source` to `dir_destination`.
@@ -60,34 +145,141 @@ struct CopyContext {
 /// - flatten (`should_keep_tree=false`) vs keep-tree copy mode,
 /// - dry-run and worker count.
 ///
-/// This function performs:
-/// 1. Input validation and destination safety checks.
-/// 2. Directory traversal and file-copy task planning.
-/// 3. Batched file-copy execution (serial or rayon thread pool).
-/// 4. Report aggregation.
+/// Equivalent to `execute_plan(plan_copy(..)?)`.
 ///
 /// Returns [`CopyReport`] when the run completes (with possible per-entry errors
-/// stored in the report). Returns [`CopyTreeError`] only for top-level setup and
-/// validation failures.
+/// stored in the report). Returns [`CopyTreeError`] for top-level setup and
+/// validation failures, and with `should_fail_fast` for the first per-entry
+/// error ([`CopyTreeError::EntryFailed`]).
+///
+/// With the `tracing` feature, the run is an info-level `copy_tree` span.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "info",
+        skip_all,
+        fields(src = %dir_source.as_ref().display(), dst = %dir_destination.as_ref().display())
+    )
+)]
 pub fn copy_tree(
     dir_source: impl AsRef<Path>,
     dir_destination: impl AsRef<Path>,
     copy_options: CopyOptionsSpec,
 ) -> Result<CopyReport, CopyTreeError> {
-    let rule_depth_limit = copy_options.rule_depth_limit;
-    if copy_options.depth_limit == Some(0) {
-        return Err(CopyTreeError::InvalidDepthLimit(
-            "Arg `depth_limit` must be >= 1 or None.".to_string(),
-        ));
-    }
-    if copy_options.depth_limit.is_none() && rule_depth_limit == CopyDepthLimitMode::Exact {
-        return Err(CopyTreeError::InvalidDepthLimit(
-            "`depth_limit` is required when depth_mode='exact'.".to_string(),
-        ));
+    let should_fail_fast = copy_options.should_fail_fast;
+    let copy_plan = plan_copy(dir_source, dir_destination.as_ref(), copy_options)?;
+    // Create the root up front so a failure stays a top-level error.
+    init_destination_root(dir_destination.as_ref())?;
+    let report = execute_plan(copy_plan);
+    derive_run_result(report, should_fail_fast)
+}
+
+/// Traverse `dir_source` and decide what [`copy_tree`] would do, without
+/// writing anything below `dir_destination`.
+///
+/// Runs input validation, filters, conflict rules and destination safety
+/// checks. Nothing is created on disk, not even a missing destination root;
+/// [`execute_plan`] creates it. Entries skipped or failed while planning are
+/// already counted in [`CopyPlan::report`].
+pub fn plan_copy(
+    dir_source: impl AsRef<Path>,
+    dir_destination: impl AsRef<Path>,
+    copy_options: CopyOptionsSpec,
+) -> Result<CopyPlan, CopyTreeError> {
+    let mut copy_ctx =
+        init_tree_context(dir_source.as_ref(), dir_destination.as_ref(), copy_options)?;
+    let path_dir_src = copy_ctx.dir_src_path.clone();
+    walk_directory(&path_dir_src, &mut copy_ctx);
+    Ok(CopyPlan::from_context(copy_ctx))
+}
+
+/// Copy the files at `rel_paths`, relative to `dir_source`, into
+/// `dir_destination` without traversing `dir_source`.
+///
+/// Meant for targeted syncs whose file list comes from an earlier scan or an
+/// external change feed. Each path goes through the same per-file steps as a
+/// file found by [`copy_tree`] (file patterns and depth limits, if set, still
+/// apply, as do conflict rules, destination safety checks and metadata
+/// handling); its depth is its number of components.
+///
+/// A path that is absolute or leaves `dir_source` (`..`) is recorded as an
+/// [`CopyErrorKind::UnsafeDestination`] error, a missing one as
+/// [`CopyErrorKind::MetadataFailed`]; directories are skipped with a warning.
+/// Top-level errors match [`copy_tree`].
+pub fn copy_files(
+    dir_source: impl AsRef<Path>,
+    dir_destination: impl AsRef<Path>,
+    rel_paths: &[PathBuf],
+    copy_options: CopyOptionsSpec,
+) -> Result<CopyReport, CopyTreeError> {
+    let should_fail_fast = copy_options.should_fail_fast;
+    let mut copy_ctx =
+        init_tree_context(dir_source.as_ref(), dir_destination.as_ref(), copy_options)?;
+    init_destination_root(dir_destination.as_ref())?;
+    for _path_rel in rel_paths {
+        if copy_ctx.is_files_max_reached || should_stop_on_error(&copy_ctx) {
+            break;
+        }
+        let path_src = copy_ctx.dir_src_path.join(_path_rel);
+        let is_within_root = _path_rel
+            .components()
+            .all(|_part| matches!(_part, Component::Normal(_) | Component::CurDir));
+        let Some(file_name) = _path_rel.file_name().filter(|_| is_within_root) else {
+            copy_ctx.report_builder.add_error(
+                path_src,
+                CopyErrorKind::UnsafeDestination,
+                format!(
+                    "Path is not a file path relative to the source root: {}",
+                    _path_rel.display()
+                ),
+            );
+            continue;
+        };
+        let meta_src = match fs::symlink_metadata(&path_src) {
+            Ok(v) => v,
+            Err(e) => {
+                copy_ctx.report_builder.add_error(
+                    path_src,
+                    CopyErrorKind::MetadataFailed,
+                    e.to_string(),
+                );
+                continue;
+            }
+        };
+        if meta_src.is_dir() {
+            copy_ctx.report_builder.add_skipped();
+            copy_ctx
+                .report_builder
+                .add_warning(format!("Not a file, skipped: {}", path_src.display()));
+            continue;
+        }
+
+        let file_entry = FileEntryRecord {
+            file_name: file_name.to_string_lossy().to_string(),
+            is_symlink: meta_src.file_type().is_symlink(),
+            file_src_path: path_src,
+        };
+        let depth_value = _path_rel
+            .components()
+            .filter(|_part| matches!(_part, Component::Normal(_)))
+            .count();
+        handle_file_entry(file_entry, depth_value, &mut copy_ctx);
     }
+    let report = execute_plan(CopyPlan::from_context(copy_ctx));
+    derive_run_result(report, should_fail_fast)
+}
 
-    let path_dir_src = dir_source.as_ref().to_path_buf();
-    let path_dir_dst = dir_destination.as_ref().to_path_buf();
+/// Validate inputs (without creating the destination root) and build the
+/// context shared by [`plan_copy`] and [`copy_files`].
+fn init_tree_context(
+    path_dir_src: &Path,
+    path_dir_dst: &Path,
+    copy_options: CopyOptionsSpec,
+) -> Result<CopyContext, CopyTreeError> {
+    validate_copy_options(&copy_options)?;
+
+    let path_dir_src = path_dir_src.to_path_buf();
+    let path_dir_dst = path_dir_dst.to_path_buf();
 
     if !path_dir_src.is_dir() {
         return Err(CopyTreeError::SourceNotDirectory(path_dir_src));
@@ -98,22 +290,224 @@ pub fn copy_tree(
             destination: path_dir_dst,
         });
     }
-    fs::create_dir_all(&path_dir_dst).map_err(|e| CopyTreeError::DestinationInitFailed {
-        path: path_dir_dst.clone(),
-        message: e.to_string(),
-    })?;
-    let meta_dir_dst =
-        fs::symlink_metadata(&path_dir_dst).map_err(|e| CopyTreeError::DestinationInitFailed {
-            path: path_dir_dst.clone(),
-            message: e.to_string(),
-        })?;
-    if meta_dir_dst.file_type().is_symlink() {
-        return Err(CopyTreeError::DestinationInitFailed {
-            path: path_dir_dst,
-            message: "Destination root path must not be a symbolic link.".to_string(),
+    validate_destination_root(&path_dir_dst)?;
+
+    // Deep trees exceed `MAX_PATH` on Windows. Every entry path is derived
+    // from these roots, so prefixing them once covers all file operations.
+    let path_dir_src = to_extended_length_path(&path_dir_src);
+    let path_dir_dst = to_extended_length_path(&path_dir_dst);
+    build_copy_context(path_dir_src, path_dir_dst, copy_options)
+}
+
+/// Carry out a plan from [`plan_copy`] and return the final report.
+///
+/// The destination root is created first (a failure is recorded as a
+/// [`CopyErrorKind::CreateDirFailed`] error and nothing else runs). Directories,
+/// symlinks and special files are then created in plan order; regular files
+/// are copied in batches (serial or rayon thread pool). Destination safety is
+/// re-checked for every entry, so edited plans cannot escape the destination
+/// root. With `should_fail_fast`, execution stops at the first error
+/// (including one already recorded while planning) and the partial report is
+/// returned.
+pub fn execute_plan(copy_plan: CopyPlan) -> CopyReport {
+    let CopyPlan {
+        entries,
+        mut copy_ctx,
+    } = copy_plan;
+    if let Err(e) = init_destination_root(&copy_ctx.dir_dst_path) {
+        copy_ctx.report_builder.add_error(
+            copy_ctx.dir_dst_path.clone(),
+            CopyErrorKind::CreateDirFailed,
+            e.to_string(),
+        );
+        return copy_ctx.report_builder.build();
+    }
+    apply_plan_entries(entries, &mut copy_ctx);
+    flush_file_copy_tasks(&mut copy_ctx);
+    if copy_ctx.copy_options.should_preserve_dir_metadata {
+        apply_created_dir_metadata(&mut copy_ctx);
+    }
+    let report = copy_ctx.report_builder.build();
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        cnt_scanned = report.cnt_scanned,
+        cnt_copied = report.cnt_copied,
+        cnt_skipped = report.cnt_skipped,
+        cnt_linked = report.cnt_linked,
+        cnt_symlinks = report.cnt_symlinks,
+        bytes_copied = report.bytes_copied,
+        cnt_errors = report.error_count(),
+        cnt_warnings = report.warning_count(),
+        "copy finished"
+    );
+    report
+}
+
+/// Copy the single file `file_source` into the directory `dir_destination`.
+///
+/// The file lands at `dir_destination/<file name>` (the directory is created
+/// when missing) and goes through the same per-file path as [`copy_tree`]:
+/// file patterns, conflict and symlink rules, destination safety checks,
+/// metadata preservation, verification and manifest. Tree-only options
+/// (directory patterns, `should_keep_tree`, directory metadata) have no
+/// effect.
+///
+/// Returns [`CopyTreeError::SourceNotFile`] when `file_source` is missing or
+/// a directory, and [`CopyTreeError::SourceDestinationOverlap`] when
+/// `dir_destination` is the directory that already contains the file.
+pub fn copy_entry(
+    file_source: impl AsRef<Path>,
+    dir_destination: impl AsRef<Path>,
+    copy_options: CopyOptionsSpec,
+) -> Result<CopyReport, CopyTreeError> {
+    validate_copy_options(&copy_options)?;
+
+    let path_file_src = file_source.as_ref().to_path_buf();
+    let path_dir_dst = dir_destination.as_ref().to_path_buf();
+
+    let (Some(path_dir_src), Some(file_name)) = (path_file_src.parent(), path_file_src.file_name())
+    else {
+        return Err(CopyTreeError::SourceNotFile(path_file_src));
+    };
+    let meta_src = match fs::symlink_metadata(&path_file_src) {
+        Ok(v) => v,
+        Err(_) => return Err(CopyTreeError::SourceNotFile(path_file_src)),
+    };
+    if path_file_src.is_dir() {
+        return Err(CopyTreeError::SourceNotFile(path_file_src));
+    }
+    let path_dir_src = path_dir_src.to_path_buf();
+    let file_entry = FileEntryRecord {
+        file_name: file_name.to_string_lossy().to_string(),
+        is_symlink: meta_src.file_type().is_symlink(),
+        file_src_path: path_file_src,
+    };
+
+    init_destination_root(&path_dir_dst)?;
+    if let (Ok(path_dir_src_real), Ok(path_dir_dst_real)) = (
+        fs::canonicalize(&path_dir_src),
+        fs::canonicalize(&path_dir_dst),
+    ) && path_dir_src_real == path_dir_dst_real
+    {
+        return Err(CopyTreeError::SourceDestinationOverlap {
+            source: path_dir_src,
+            destination: path_dir_dst,
         });
     }
 
+    // Extended-length paths on Windows, as in `plan_copy`.
+    let file_entry = FileEntryRecord {
+        file_src_path: to_extended_length_path(&file_entry.file_src_path),
+        ..file_entry
+    };
+    let path_dir_src = file_entry
+        .file_src_path
+        .parent()
+        .map_or(path_dir_src, Path::to_path_buf);
+    let path_dir_dst = to_extended_length_path(&path_dir_dst);
+
+    // One file never benefits from a worker pool.
+    let copy_options = CopyOptionsSpec {
+        workers_max: Some(1),
+        ..copy_options
+    };
+    let should_fail_fast = copy_options.should_fail_fast;
+    let mut copy_ctx = build_copy_context(path_dir_src, path_dir_dst, copy_options)?;
+    handle_file_entry(file_entry, 1, &mut copy_ctx);
+    let report = execute_plan(CopyPlan::from_context(copy_ctx));
+    derive_run_result(report, should_fail_fast)
+}
+
+/// Turn the first recorded error into [`CopyTreeError::EntryFailed`] under
+/// `should_fail_fast`.
+fn derive_run_result(
+    report: CopyReport,
+    should_fail_fast: bool,
+) -> Result<CopyReport, CopyTreeError> {
+    match report.errors.first() {
+        Some(record) if should_fail_fast => Err(CopyTreeError::EntryFailed {
+            path: record.path.clone(),
+            message: record.exception.clone(),
+        }),
+        _ => Ok(report),
+    }
+}
+
+/// Whether `should_fail_fast` is set and an error has been recorded.
+fn should_stop_on_error(copy_ctx: &CopyContext) -> bool {
+    copy_ctx.copy_options.should_fail_fast && copy_ctx.report_builder.has_errors()
+}
+
+/// Reject option values that make no sense before touching the filesystem.
+fn validate_copy_options(copy_options: &CopyOptionsSpec) -> Result<(), CopyTreeError> {
+    if copy_options.depth_limit == Some(0) {
+        return Err(CopyTreeError::InvalidDepthLimit(
+            "Arg `depth_limit` must be >= 1 or None.".to_string(),
+        ));
+    }
+    if copy_options.depth_limit.is_none()
+        && copy_options.rule_depth_limit == CopyDepthLimitMode::Exact
+    {
+        return Err(CopyTreeError::InvalidDepthLimit(
+            "`depth_limit` is required when depth_mode='exact'.".to_string(),
+        ));
+    }
+
+    if copy_options.copy_buffer_bytes == Some(0) {
+        return Err(CopyTreeError::InvalidOption(
+            "Arg `copy_buffer_bytes` must be >= 1 or None.".to_string(),
+        ));
+    }
+    if copy_options.throughput_max_bytes_per_sec == Some(0) {
+        return Err(CopyTreeError::InvalidOption(
+            "Arg `throughput_max_bytes_per_sec` must be >= 1 or None.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Create the destination root and refuse a root that is itself a symlink.
+fn init_destination_root(path_dir_dst: &Path) -> Result<(), CopyTreeError> {
+    fs::create_dir_all(path_dir_dst).map_err(|e| CopyTreeError::DestinationInitFailed {
+        path: path_dir_dst.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    validate_destination_root(path_dir_dst)
+}
+
+/// Refuse an existing destination root that is a symlink or not a directory,
+/// without creating anything; a missing root is accepted.
+fn validate_destination_root(path_dir_dst: &Path) -> Result<(), CopyTreeError> {
+    let meta_dir_dst = match fs::symlink_metadata(path_dir_dst) {
+        Ok(v) => v,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => {
+            return Err(CopyTreeError::DestinationInitFailed {
+                path: path_dir_dst.to_path_buf(),
+                message: e.to_string(),
+            });
+        }
+    };
+    let message = if meta_dir_dst.file_type().is_symlink() {
+        "Destination root path must not be a symbolic link."
+    } else if !meta_dir_dst.is_dir() {
+        "Destination root path is not a directory."
+    } else {
+        return Ok(());
+    };
+    Err(CopyTreeError::DestinationInitFailed {
+        path: path_dir_dst.to_path_buf(),
+        message: message.to_string(),
+    })
+}
+
+/// Compile patterns and set up the worker pool, rate limiter and symlink
+/// rewriting shared by one copy run.
+fn build_copy_context(
+    path_dir_src: PathBuf,
+    path_dir_dst: PathBuf,
+    copy_options: CopyOptionsSpec,
+) -> Result<CopyContext, CopyTreeError> {
     let copy_patterns = CopyPatternsSpec::from_raw(
         copy_options.patterns_include_files.as_deref(),
         copy_options.patterns_exclude_files.as_deref(),
@@ -122,154 +516,652 @@ pub fn copy_tree(
         copy_options.rule_pattern,
     )?;
     let workers_max = calculate_worker_limit(copy_options.workers_max);
+    let rate_limiter = copy_options
+        .throughput_max_bytes_per_sec
+        .map(CopyRateLimiter::new);
+
+    let symlink_rewrite = (copy_options.should_rewrite_symlinks_relative
+        && copy_options.should_keep_tree)
+        .then(|| SymlinkRewriteSpec::new(&path_dir_src, &path_dir_dst));
+
+    let mut report_builder = CopyReportBuilder::default();
+    let thread_pool = if workers_max <= 1 {
+        None
+    } else {
+        match ThreadPoolBuilder::new().num_threads(workers_max).build() {
+            Ok(v) => Some(v),
+            Err(_) => {
+                report_builder.add_warning(format!(
+                    "Failed to initialize thread pool (workers={workers_max}); fallback to serial copy."
+                ));
+                None
+            }
+        }
+    };
 
-    let mut copy_ctx = CopyContext {
-        dir_src_path: path_dir_src.clone(),
+    Ok(CopyContext {
+        dir_src_path: path_dir_src,
         dir_dst_path: path_dir_dst,
         copy_options,
         copy_patterns,
-        workers_max,
-        report_builder: CopyReportBuilder::default(),
+        report_builder,
         visited_dirs: HashSet::new(),
+        plan_entries: Vec::new(),
         file_copy_tasks: Vec::new(),
-    };
+        created_dirs: Vec::new(),
+        rate_limiter,
+        symlink_rewrite,
+        thread_pool,
+        bytes_budget_scheduled: 0,
+        is_bytes_budget_reached: false,
+        cnt_files_matched: 0,
+        is_files_max_reached: false,
+        dedup_index: HashMap::new(),
+    })
+}
 
-    walk_directory(&path_dir_src, 0, &mut copy_ctx);
-    flush_file_copy_tasks(&mut copy_ctx);
-    Ok(copy_ctx.report_builder.build())
+/// Queue one destination action for [`execute_plan`].
+fn push_plan_entry(
+    copy_ctx: &mut CopyContext,
+    src_path: PathBuf,
+    dst_path: PathBuf,
+    action: CopyPlanAction,
+) {
+    copy_ctx.plan_entries.push(CopyPlanEntry {
+        src_path,
+        dst_path,
+        action,
+    });
 }
 
-fn should_error_unsafe_destination_path(path_dst: &Path, copy_ctx: &mut CopyContext) -> bool {
-    if let Err(message) = validate_destination_path_safety(path_dst, &copy_ctx.dir_dst_path) {
-        copy_ctx
-            .report_builder
-            .add_error(path_dst.to_path_buf(), message);
+/// Record one dry-run plan entry when `should_collect_plan` is set.
+fn record_plan_entry(
+    copy_ctx: &mut CopyContext,
+    src_path: &Path,
+    dst_path: &Path,
+    action: CopyPlanAction,
+) {
+    if copy_ctx.copy_options.should_dry_run && copy_ctx.copy_options.should_collect_plan {
+        copy_ctx.report_builder.add_plan_entry(CopyPlanEntry {
+            src_path: src_path.to_path_buf(),
+            dst_path: dst_path.to_path_buf(),
+            action,
+        });
+    }
+}
+
+/// Destination path of one entry; records a warning when
+/// `filename_normalization` or `should_sanitize_dest_names` changes the
+/// entry's name.
+fn derive_entry_destination_path(
+    path_src: &Path,
+    item_name: &str,
+    copy_ctx: &mut CopyContext,
+) -> PathBuf {
+    let filename_normalization = copy_ctx.copy_options.filename_normalization;
+    let should_sanitize_dest_names = copy_ctx.copy_options.should_sanitize_dest_names;
+    let path_dst = derive_destination_path(
+        path_src,
+        item_name,
+        &copy_ctx.dir_src_path,
+        &copy_ctx.dir_dst_path,
+        copy_ctx.copy_options.should_keep_tree,
+        filename_normalization,
+        should_sanitize_dest_names,
+    );
+    let name_normalized = match filename_normalization {
+        Some(form) => normalize_filename(item_name, form),
+        None => item_name.to_string(),
+    };
+    if let Some(form) = filename_normalization
+        && name_normalized != item_name
+    {
+        copy_ctx.report_builder.add_warning(format!(
+            "Normalized filename to {}: {} -> {}",
+            form.as_str(),
+            path_src.display(),
+            path_dst.display()
+        ));
+    }
+    if should_sanitize_dest_names && sanitize_filename(&name_normalized) != name_normalized {
+        copy_ctx.report_builder.add_warning(format!(
+            "Sanitized filename for Windows: {} -> {}",
+            path_src.display(),
+            path_dst.display()
+        ));
+    }
+    path_dst
+}
+
+/// Check `path_dst` against the destination safety rules. Under `Replace`, an
+/// existing symlink is removed only when `should_remove_symlink` is set
+/// (execution, never planning).
+fn should_error_unsafe_destination_path(
+    path_dst: &Path,
+    should_remove_symlink: bool,
+    copy_ctx: &mut CopyContext,
+) -> bool {
+    let validation = match copy_ctx.copy_options.rule_conflict_symlink_dst {
+        CopyDstSymlinkConflictMode::Error => {
+            validate_destination_path_safety(path_dst, &copy_ctx.dir_dst_path)
+        }
+        CopyDstSymlinkConflictMode::Replace => {
+            validate_destination_parent_safety(path_dst, &copy_ctx.dir_dst_path).and_then(|_| {
+                if should_remove_symlink {
+                    remove_destination_symlink(path_dst)
+                } else {
+                    Ok(())
+                }
+            })
+        }
+    };
+    if let Err(message) = validation {
+        copy_ctx.report_builder.add_error(
+            path_dst.to_path_buf(),
+            CopyErrorKind::UnsafeDestination,
+            message,
+        );
         return true;
     }
     false
 }
 
-fn execute_copy_task(task: CopyTaskFileSpec, dir_dst_root: &Path) -> (PathBuf, Result<(), String>) {
-    let copy_result =
-        validate_destination_path_safety(&task.file_dst_path, dir_dst_root).and_then(|_| {
-            copy_file_with_metadata(&task.file_src_path, &task.file_dst_path)
-                .map_err(|_e| _e.to_string())
+fn execute_copy_task(
+    task: CopyTaskFileSpec,
+    dir_dst_root: &Path,
+    copy_options: &CopyOptionsSpec,
+    rate_limiter: Option<&CopyRateLimiter>,
+) -> CopyTaskResult {
+    let copy_result = validate_destination_path_safety(&task.file_dst_path, dir_dst_root)
+        .map_err(|_message| (CopyErrorKind::UnsafeDestination, _message))
+        .and_then(|_| {
+            if !copy_options.should_force_overwrite_readonly {
+                return Ok(());
+            }
+            make_destination_writable(&task.file_dst_path).map_err(|e| {
+                (
+                    CopyErrorKind::IoCopyFailed,
+                    format!(
+                        "Failed to make destination writable {} ({e})",
+                        task.file_dst_path.display()
+                    ),
+                )
+            })
+        })
+        .and_then(|_| {
+            copy_file_with_metadata(
+                &task.file_src_path,
+                &task.file_dst_path,
+                dir_dst_root,
+                copy_options,
+                rate_limiter,
+            )
+            .and_then(|_outcome| {
+                if copy_options.should_fsync {
+                    sync_parent_dir_to_disk(&task.file_dst_path)?;
+                }
+                Ok(_outcome)
+            })
+            .map_err(|_e| (CopyErrorKind::IoCopyFailed, _e.to_string()))
+        })
+        .and_then(|_outcome| {
+            if copy_options.should_verify {
+                verify_copied_file(&task.file_src_path, &task.file_dst_path, _outcome)
+            } else {
+                Ok(_outcome)
+            }
         });
 
-    (task.file_dst_path.clone(), copy_result)
+    (task.file_src_path, task.file_dst_path, copy_result)
+}
+
+/// Compare source and destination hashes; remove the destination on mismatch.
+fn verify_copied_file(
+    file_src_path: &Path,
+    file_dst_path: &Path,
+    mut copy_outcome: CopyFileOutcome,
+) -> Result<CopyFileOutcome, (CopyErrorKind, String)> {
+    let hash_src =
+        hash_file_xxh3(file_src_path).map_err(|e| (CopyErrorKind::IoCopyFailed, e.to_string()))?;
+    let hash_dst =
+        hash_file_xxh3(file_dst_path).map_err(|e| (CopyErrorKind::IoCopyFailed, e.to_string()))?;
+    if hash_src != hash_dst {
+        let _ = fs::remove_file(file_dst_path);
+        return Err((
+            CopyErrorKind::VerifyMismatch,
+            format!(
+                "Content hash mismatch after copy: {} (src={hash_src:016x}, dst={hash_dst:016x})",
+                file_dst_path.display()
+            ),
+        ));
+    }
+    copy_outcome.is_verified = true;
+    Ok(copy_outcome)
 }
 
 fn apply_results(
-    results: Vec<(PathBuf, Result<(), String>)>,
+    results: Vec<CopyTaskResult>,
+    should_collect_ext_stats: bool,
     report_builder: &mut CopyReportBuilder,
 ) {
     for _result in results {
-        let (path_dst, copy_result) = _result;
+        let (path_src, path_dst, copy_result) = _result;
         match copy_result {
-            Ok(_) => report_builder.add_copied(),
-            Err(message) => report_builder.add_error(path_dst, message),
+            Ok(copy_outcome) => {
+                for _warning in copy_outcome.warnings {
+                    report_builder.add_warning(_warning);
+                }
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    dst = %path_dst.display(),
+                    bytes = copy_outcome.bytes_copied,
+                    "copied file"
+                );
+                report_builder.add_copied();
+                report_builder.add_bytes_copied(copy_outcome.bytes_copied);
+                if should_collect_ext_stats {
+                    report_builder.add_ext_stats(&path_dst, copy_outcome.bytes_copied);
+                }
+                if copy_outcome.is_verified {
+                    report_builder.add_verified();
+                }
+                if let Some(digest) = copy_outcome.digest {
+                    report_builder.add_manifest_entry(path_dst, digest);
+                }
+            }
+            Err((kind, message)) => {
+                report_builder.add_source_error(path_dst, path_src, kind, message)
+            }
         }
     }
 }
 
-fn flush_file_copy_tasks(copy_ctx: &mut CopyContext) {
-    let file_copy_tasks = std::mem::take(&mut copy_ctx.file_copy_tasks);
-    if file_copy_tasks.is_empty() {
-        return;
-    }
-
-    if copy_ctx.workers_max <= 1 {
-        let results = file_copy_tasks
-            .into_iter()
-            .map(|_task| execute_copy_task(_task, &copy_ctx.dir_dst_path))
-            .collect::<Vec<_>>();
-        apply_results(results, &mut copy_ctx.report_builder);
-        return;
+/// Keep the leading tasks whose source sizes fit in `bytes_budget_max`.
+///
+/// Sizes are reserved in traversal order, so the copied set is the same in
+/// serial and parallel runs. Once one file does not fit, it and every later
+/// task are counted as skipped and a single warning is recorded.
+fn retain_tasks_within_bytes_budget(
+    file_copy_tasks: Vec<CopyTaskFileSpec>,
+    copy_ctx: &mut CopyContext,
+) -> Vec<CopyTaskFileSpec> {
+    if copy_ctx.copy_options.bytes_budget_max.is_none() {
+        return file_copy_tasks;
     }
+    file_copy_tasks
+        .into_iter()
+        .filter(|_task| reserve_bytes_budget(_task, copy_ctx))
+        .collect()
+}
 
-    let thread_pool = ThreadPoolBuilder::new()
-        .num_threads(copy_ctx.workers_max)
-        .build();
-    let Ok(thread_pool) = thread_pool else {
+/// Reserve the source size of `task` against `bytes_budget_max`.
+///
+/// Returns `false` (counting the task as skipped) once the budget is reached;
+/// the first task that does not fit records the warning.
+fn reserve_bytes_budget(task: &CopyTaskFileSpec, copy_ctx: &mut CopyContext) -> bool {
+    let Some(bytes_budget_max) = copy_ctx.copy_options.bytes_budget_max else {
+        return true;
+    };
+    if !copy_ctx.is_bytes_budget_reached {
+        let bytes_src = fs::metadata(&task.file_src_path).map_or(0, |v| v.len());
+        let bytes_scheduled = copy_ctx.bytes_budget_scheduled.saturating_add(bytes_src);
+        if bytes_scheduled <= bytes_budget_max {
+            copy_ctx.bytes_budget_scheduled = bytes_scheduled;
+            return true;
+        }
+        copy_ctx.is_bytes_budget_reached = true;
         copy_ctx.report_builder.add_warning(format!(
-            "Failed to initialize thread pool (workers={}); fallback to serial copy.",
-            copy_ctx.workers_max
+            "Byte budget of {bytes_budget_max} bytes reached; remaining files are not copied."
         ));
-        let results = file_copy_tasks
-            .into_iter()
-            .map(|_task| execute_copy_task(_task, &copy_ctx.dir_dst_path))
-            .collect::<Vec<_>>();
-        apply_results(results, &mut copy_ctx.report_builder);
-        return;
-    };
-
-    let results = thread_pool.install(|| {
-        let dir_dst_root = copy_ctx.dir_dst_path.clone();
-        file_copy_tasks
-            .into_par_iter()
-            .map(|_task| execute_copy_task(_task, &dir_dst_root))
-            .collect::<Vec<_>>()
-    });
-    apply_results(results, &mut copy_ctx.report_builder);
+    }
+    copy_ctx.report_builder.add_skipped();
+    false
 }
 
-fn walk_directory(path_root: &Path, depth_relative: usize, copy_ctx: &mut CopyContext) {
-    let rule_symlink = copy_ctx.copy_options.rule_symlink;
-    if rule_symlink == CopySymlinkMode::Dereference {
-        if let Ok(stat_root) = fs::metadata(path_root) {
-            #[cfg(unix)]
-            {
-                use std::os::unix::fs::MetadataExt;
-                let dir_identifier = (stat_root.dev(), stat_root.ino());
-                if !copy_ctx.visited_dirs.insert(dir_identifier) {
-                    copy_ctx
-                        .report_builder
-                        .add_warning(format!("Symlink loop detected: {}", path_root.display()));
-                    return;
+/// Create directories, symlinks and special files in plan order and queue
+/// regular files for [`flush_file_copy_tasks`]. Entries below a directory that
+/// failed to be created are dropped.
+fn apply_plan_entries(plan_entries: Vec<CopyPlanEntry>, copy_ctx: &mut CopyContext) {
+    let should_keep_tree = copy_ctx.copy_options.should_keep_tree;
+    let mut dirs_failed: Vec<PathBuf> = Vec::new();
+    for _entry in plan_entries {
+        if should_stop_on_error(copy_ctx) {
+            return;
+        }
+        let CopyPlanEntry {
+            src_path,
+            dst_path,
+            action,
+        } = _entry;
+        if action == CopyPlanAction::SkipConflict
+            || dirs_failed.iter().any(|_dir| dst_path.starts_with(_dir))
+            || should_error_unsafe_destination_path(&dst_path, true, copy_ctx)
+        {
+            continue;
+        }
+
+        if action == CopyPlanAction::CreateDir {
+            match fs::create_dir_all(&dst_path) {
+                Ok(_) => {
+                    copy_ctx.report_builder.add_copied();
+                    if copy_ctx.copy_options.should_preserve_dir_metadata {
+                        copy_ctx.created_dirs.push(CopyTaskDirSpec {
+                            dir_src_path: src_path,
+                            dir_dst_path: dst_path,
+                        });
+                    }
+                }
+                Err(e) => {
+                    copy_ctx.report_builder.add_error(
+                        dst_path.clone(),
+                        CopyErrorKind::CreateDirFailed,
+                        e.to_string(),
+                    );
+                    dirs_failed.push(dst_path);
                 }
             }
-        } else {
-            copy_ctx
-                .report_builder
-                .add_warning(format!("Failed to stat directory: {}", path_root.display()));
-            return;
+            continue;
         }
-    }
 
-    let mut dirs: Vec<DirEntryRecord> = Vec::new();
-    let mut files: Vec<FileEntryRecord> = Vec::new();
+        if should_keep_tree
+            && let Some(path_parent_dst) = dst_path.parent()
+            && let Err(e) = fs::create_dir_all(path_parent_dst)
+        {
+            copy_ctx.report_builder.add_error(
+                dst_path,
+                CopyErrorKind::CreateDirFailed,
+                e.to_string(),
+            );
+            continue;
+        }
 
-    let iter_entries = match fs::read_dir(path_root) {
-        Ok(iter) => iter,
-        Err(e) => {
-            copy_ctx.report_builder.add_warning(format!(
-                "Failed to read directory {} ({e})",
-                path_root.display()
-            ));
-            return;
+        match action {
+            CopyPlanAction::CreateSymlink => create_symbolic_link(
+                &src_path,
+                &dst_path,
+                copy_ctx.symlink_rewrite.as_ref(),
+                &mut copy_ctx.report_builder,
+            ),
+            // Follows a dereferenced symlink; a special file is never a link.
+            CopyPlanAction::CreateSpecialFile => match fs::metadata(&src_path) {
+                Ok(metadata_src) => {
+                    create_special_file(&metadata_src, &dst_path, &mut copy_ctx.report_builder)
+                }
+                Err(e) => copy_ctx.report_builder.add_error(
+                    src_path,
+                    CopyErrorKind::MetadataFailed,
+                    e.to_string(),
+                ),
+            },
+            _ => copy_ctx.file_copy_tasks.push(CopyTaskFileSpec {
+                file_src_path: src_path,
+                file_dst_path: dst_path,
+            }),
+        }
+    }
+}
+
+/// Split off tasks whose source content and permissions match an earlier
+/// task.
+///
+/// Sources are hashed on the worker pool; the first task of each
+/// [`DedupKey`] stays a copy and later ones become links to its destination.
+/// A source that cannot be hashed is simply copied. Only copies count against
+/// `bytes_budget_max`: a link adds no bytes, so duplicates of an admitted copy
+/// are linked until the budget is reached.
+fn split_duplicate_tasks(
+    file_copy_tasks: Vec<CopyTaskFileSpec>,
+    copy_ctx: &mut CopyContext,
+) -> (Vec<CopyTaskFileSpec>, Vec<CopyTaskLinkSpec>) {
+    let derive_key = |_task: &CopyTaskFileSpec| {
+        let metadata_src = fs::metadata(&_task.file_src_path).ok()?;
+        let digest = hash_file_blake3(&_task.file_src_path).ok()?;
+        #[cfg(unix)]
+        let permissions_bits = {
+            use std::os::unix::fs::PermissionsExt;
+            metadata_src.permissions().mode()
+        };
+        #[cfg(not(unix))]
+        let permissions_bits = u32::from(metadata_src.permissions().readonly());
+        Some(DedupKey {
+            bytes_src: metadata_src.len(),
+            digest,
+            permissions_bits,
+        })
+    };
+    let dedup_keys: Vec<Option<DedupKey>> = match &copy_ctx.thread_pool {
+        Some(thread_pool) => {
+            thread_pool.install(|| file_copy_tasks.par_iter().map(derive_key).collect())
         }
+        None => file_copy_tasks.iter().map(derive_key).collect(),
     };
 
-    for _entry_res in iter_entries {
-        let entry = match _entry_res {
-            Ok(v) => v,
-            Err(e) => {
-                copy_ctx.report_builder.add_warning(format!(
-                    "Failed to read directory entry under {} ({e})",
-                    path_root.display()
-                ));
-                continue;
+    let mut file_link_tasks = Vec::new();
+    let mut file_copy_tasks_unique = Vec::with_capacity(file_copy_tasks.len());
+    for (_task, _key) in file_copy_tasks.into_iter().zip(dedup_keys) {
+        if copy_ctx.is_bytes_budget_reached {
+            copy_ctx.report_builder.add_skipped();
+            continue;
+        }
+        let Some(key) = _key else {
+            if reserve_bytes_budget(&_task, copy_ctx) {
+                file_copy_tasks_unique.push(_task);
             }
+            continue;
         };
-
-        let path_entry = entry.path();
+        match copy_ctx.dedup_index.get(&key) {
+            Some(file_existing_path) => file_link_tasks.push(CopyTaskLinkSpec {
+                file_existing_path: file_existing_path.clone(),
+                file_task: _task,
+            }),
+            None => {
+                if !reserve_bytes_budget(&_task, copy_ctx) {
+                    continue;
+                }
+                copy_ctx
+                    .dedup_index
+                    .insert(key, _task.file_dst_path.clone());
+                file_copy_tasks_unique.push(_task);
+            }
+        }
+    }
+    (file_copy_tasks_unique, file_link_tasks)
+}
+
+/// Copy `file_copy_tasks` serially or on the worker pool.
+fn run_file_copy_tasks(
+    file_copy_tasks: Vec<CopyTaskFileSpec>,
+    copy_ctx: &CopyContext,
+) -> Vec<CopyTaskResult> {
+    let dir_dst_root = &copy_ctx.dir_dst_path;
+    let copy_options = &copy_ctx.copy_options;
+    let rate_limiter = copy_ctx.rate_limiter.as_ref();
+    // Under `should_fail_fast`, tasks not yet started when one fails are
+    // dropped without a result.
+    let is_failed = AtomicBool::new(false);
+    let run_task = |_task: CopyTaskFileSpec| {
+        if is_failed.load(Ordering::Relaxed) {
+            return None;
+        }
+        let result = execute_copy_task(_task, dir_dst_root, copy_options, rate_limiter);
+        if copy_options.should_fail_fast && result.2.is_err() {
+            is_failed.store(true, Ordering::Relaxed);
+        }
+        Some(result)
+    };
+    let Some(thread_pool) = &copy_ctx.thread_pool else {
+        return file_copy_tasks.into_iter().filter_map(run_task).collect();
+    };
+    thread_pool.install(|| {
+        file_copy_tasks
+            .into_par_iter()
+            .filter_map(run_task)
+            .collect()
+    })
+}
+
+/// Hard-link duplicates to their copied originals. Returns the tasks that
+/// must be copied after all: the original failed or linking failed.
+fn apply_link_tasks(
+    file_link_tasks: Vec<CopyTaskLinkSpec>,
+    results: &[CopyTaskResult],
+    copy_ctx: &mut CopyContext,
+) -> Vec<CopyTaskFileSpec> {
+    let digests_copied: HashMap<&Path, Option<&String>> = results
+        .iter()
+        .filter_map(|(_, _path_dst, _result)| {
+            let copy_outcome = _result.as_ref().ok()?;
+            Some((_path_dst.as_path(), copy_outcome.digest.as_ref()))
+        })
+        .collect();
+
+    let mut file_copy_tasks_fallback = Vec::new();
+    for _link_task in file_link_tasks {
+        let CopyTaskLinkSpec {
+            file_task,
+            file_existing_path,
+        } = _link_task;
+        let Some(digest) = digests_copied.get(file_existing_path.as_path()) else {
+            file_copy_tasks_fallback.push(file_task);
+            continue;
+        };
+        if let Err(message) =
+            validate_destination_path_safety(&file_task.file_dst_path, &copy_ctx.dir_dst_path)
+        {
+            copy_ctx.report_builder.add_source_error(
+                file_task.file_dst_path,
+                file_task.file_src_path,
+                CopyErrorKind::UnsafeDestination,
+                message,
+            );
+            continue;
+        }
+        if link_file_atomic(&file_existing_path, &file_task.file_dst_path).is_err() {
+            file_copy_tasks_fallback.push(file_task);
+            continue;
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            dst = %file_task.file_dst_path.display(),
+            existing = %file_existing_path.display(),
+            "linked duplicate file"
+        );
+        copy_ctx.report_builder.add_linked();
+        if let Some(digest) = digest {
+            copy_ctx
+                .report_builder
+                .add_manifest_entry(file_task.file_dst_path, (*digest).clone());
+        }
+    }
+    file_copy_tasks_fallback
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(cnt_tasks = copy_ctx.file_copy_tasks.len()))
+)]
+fn flush_file_copy_tasks(copy_ctx: &mut CopyContext) {
+    let file_copy_tasks = std::mem::take(&mut copy_ctx.file_copy_tasks);
+    let should_dedup_destination = copy_ctx.copy_options.should_dedup_destination;
+    // Deduplication reserves the budget itself, so links are not counted.
+    let file_copy_tasks = if should_dedup_destination {
+        file_copy_tasks
+    } else {
+        retain_tasks_within_bytes_budget(file_copy_tasks, copy_ctx)
+    };
+    if file_copy_tasks.is_empty() || should_stop_on_error(copy_ctx) {
+        return;
+    }
+    let should_collect_ext_stats = copy_ctx.copy_options.should_collect_ext_stats;
+
+    if !should_dedup_destination {
+        let results = run_file_copy_tasks(file_copy_tasks, copy_ctx);
+        apply_results(
+            results,
+            should_collect_ext_stats,
+            &mut copy_ctx.report_builder,
+        );
+        return;
+    }
+
+    let (file_copy_tasks, file_link_tasks) = split_duplicate_tasks(file_copy_tasks, copy_ctx);
+    let results = run_file_copy_tasks(file_copy_tasks, copy_ctx);
+    let file_copy_tasks_fallback = apply_link_tasks(file_link_tasks, &results, copy_ctx);
+    apply_results(
+        results,
+        should_collect_ext_stats,
+        &mut copy_ctx.report_builder,
+    );
+    if should_stop_on_error(copy_ctx) {
+        return;
+    }
+    // Duplicates that could not be linked are copied after all, so they now
+    // count against the budget.
+    let file_copy_tasks_fallback =
+        retain_tasks_within_bytes_budget(file_copy_tasks_fallback, copy_ctx);
+    let results = run_file_copy_tasks(file_copy_tasks_fallback, copy_ctx);
+    apply_results(
+        results,
+        should_collect_ext_stats,
+        &mut copy_ctx.report_builder,
+    );
+}
+
+/// Apply source permissions and times to created directories, deepest first,
+/// so later writes into children do not bump an already-restored parent mtime.
+fn apply_created_dir_metadata(copy_ctx: &mut CopyContext) {
+    let mut created_dirs = std::mem::take(&mut copy_ctx.created_dirs);
+    created_dirs.sort_by_key(|_dir| std::cmp::Reverse(_dir.dir_dst_path.components().count()));
+
+    for _dir in created_dirs {
+        if let Err(e) = copy_dir_metadata(&_dir.dir_src_path, &_dir.dir_dst_path) {
+            copy_ctx.report_builder.add_warning(format!(
+                "Failed to preserve directory metadata {} ({e})",
+                _dir.dir_dst_path.display()
+            ));
+        }
+    }
+}
+
+/// Raw entries of one source directory, in `read_dir` order.
+#[derive(Debug, Default)]
+struct DirListing {
+    dirs: Vec<DirEntryRecord>,
+    files: Vec<FileEntryRecord>,
+    /// Per-entry warnings, in encounter order.
+    warnings: Vec<String>,
+}
+
+/// Read one directory without touching the copy context. `Err` carries the
+/// open-failure warning.
+fn read_dir_listing(path_root: &Path, should_skip_hidden: bool) -> Result<DirListing, String> {
+    let iter_entries = fs::read_dir(path_root)
+        .map_err(|e| format!("Failed to read directory {} ({e})", path_root.display()))?;
+
+    let mut dir_listing = DirListing::default();
+    for _entry_res in iter_entries {
+        let entry = match _entry_res {
+            Ok(v) => v,
+            Err(e) => {
+                dir_listing.warnings.push(format!(
+                    "Failed to read directory entry under {} ({e})",
+                    path_root.display()
+                ));
+                continue;
+            }
+        };
+
+        let path_entry = entry.path();
         let entry_name = entry.file_name().to_string_lossy().to_string();
+        if should_skip_hidden && is_hidden_entry(&entry_name, &entry) {
+            continue;
+        }
         let file_type = match entry.file_type() {
             Ok(v) => v,
             Err(e) => {
-                copy_ctx
-                    .report_builder
-                    .add_warning(format!("Failed to inspect {} ({e})", path_entry.display()));
+                dir_listing
+                    .warnings
+                    .push(format!("Failed to inspect {} ({e})", path_entry.display()));
                 continue;
             }
         };
@@ -277,23 +1169,145 @@ fn walk_directory(path_root: &Path, depth_relative: usize, copy_ctx: &mut CopyCo
         let is_symlink = file_type.is_symlink();
         let is_dir = file_type.is_dir() || (is_symlink && path_entry.is_dir());
         if is_dir {
-            dirs.push(DirEntryRecord {
+            dir_listing.dirs.push(DirEntryRecord {
                 dir_src_path: path_entry,
                 dir_name: entry_name,
                 is_symlink,
             });
-        } else if file_type.is_file() || is_symlink {
-            files.push(FileEntryRecord {
+        } else {
+            // Special files go through `handle_file_entry`, which applies
+            // filters and `rule_special_file`.
+            dir_listing.files.push(FileEntryRecord {
                 file_src_path: path_entry,
                 file_name: entry_name,
                 is_symlink,
             });
-        } else {
-            copy_ctx
-                .report_builder
-                .add_warning(format!("Special file skipped: {}", path_entry.display()));
         }
     }
+    Ok(dir_listing)
+}
+
+/// Directory whose subdirectories and files are still being walked.
+#[derive(Debug)]
+struct WalkFrame {
+    depth_relative: usize,
+    /// Dereferenced directory symlinks between the source root and this
+    /// directory; independent of `depth_relative`.
+    symlink_hops: usize,
+    /// Subdirectories not yet walked, in sorted order.
+    iter_dirs: std::vec::IntoIter<DirEntryRecord>,
+    /// Files handled once every subdirectory has been walked.
+    files: Vec<FileEntryRecord>,
+}
+
+/// Scan `path_root` depth first.
+///
+/// Descent uses an explicit stack of [`WalkFrame`]s rather than recursion, so
+/// arbitrarily deep (possibly crafted) source trees cannot overflow the
+/// thread stack. Order matches a recursive pre-order walk: each subdirectory
+/// is fully walked before the next sibling, and a directory's files follow
+/// all of its subdirectories.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(root = %path_root.display()))
+)]
+fn walk_directory(path_root: &Path, copy_ctx: &mut CopyContext) {
+    let mut walk_stack: Vec<WalkFrame> = open_walk_frame(path_root, 0, 0, copy_ctx)
+        .into_iter()
+        .collect();
+    let rule_symlink = copy_ctx.copy_options.rule_symlink;
+    let symlink_follow_max = copy_ctx.copy_options.symlink_follow_max;
+
+    while let Some(frame) = walk_stack.last_mut() {
+        if copy_ctx.is_files_max_reached || should_stop_on_error(copy_ctx) {
+            return;
+        }
+
+        let Some(dir_entry) = frame.iter_dirs.next() else {
+            let Some(frame) = walk_stack.pop() else {
+                break;
+            };
+            for _file_entry in frame.files {
+                if copy_ctx.is_files_max_reached || should_stop_on_error(copy_ctx) {
+                    return;
+                }
+                handle_file_entry(_file_entry, frame.depth_relative + 1, copy_ctx);
+            }
+            continue;
+        };
+
+        let depth_next = frame.depth_relative + 1;
+        let path_next = dir_entry.dir_src_path.clone();
+        let symlink_hops_next = frame.symlink_hops + usize::from(dir_entry.is_symlink);
+        if dir_entry.is_symlink
+            && rule_symlink == CopySymlinkMode::Dereference
+            && symlink_follow_max.is_some_and(|_max| symlink_hops_next > _max)
+        {
+            copy_ctx.report_builder.add_warning(format!(
+                "Symlink follow limit reached; not descending: {}",
+                path_next.display()
+            ));
+            copy_ctx.report_builder.add_skipped();
+            continue;
+        }
+
+        let should_descend = handle_dir_entry(dir_entry, depth_next, copy_ctx);
+        if should_descend
+            && let Some(frame_next) =
+                open_walk_frame(&path_next, depth_next, symlink_hops_next, copy_ctx)
+        {
+            walk_stack.push(frame_next);
+        }
+    }
+}
+
+/// Enter one directory: run the loop guard, read its listing, apply directory
+/// patterns and the depth limit.
+///
+/// Returns `None` when the directory is not walked (loop, stat or read
+/// failure; a warning is recorded).
+fn open_walk_frame(
+    path_root: &Path,
+    depth_relative: usize,
+    symlink_hops: usize,
+    copy_ctx: &mut CopyContext,
+) -> Option<WalkFrame> {
+    // Guard every descent, not only dereferenced ones: a directory reached
+    // twice (symlink or bind mount) would otherwise be walked again.
+    if let Ok(stat_root) = fs::metadata(path_root) {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            let dir_identifier = (stat_root.dev(), stat_root.ino());
+            if !copy_ctx.visited_dirs.insert(dir_identifier) {
+                copy_ctx
+                    .report_builder
+                    .add_warning(format!("Symlink loop detected: {}", path_root.display()));
+                return None;
+            }
+        }
+    } else {
+        copy_ctx
+            .report_builder
+            .add_warning(format!("Failed to stat directory: {}", path_root.display()));
+        return None;
+    }
+
+    let dir_listing = read_dir_listing(path_root, copy_ctx.copy_options.should_skip_hidden);
+    let DirListing {
+        mut dirs,
+        mut files,
+        warnings,
+    } = match dir_listing {
+        Ok(v) => v,
+        Err(warning) => {
+            copy_ctx.report_builder.add_warning(warning);
+            return None;
+        }
+    };
+    for _warning in warnings {
+        copy_ctx.report_builder.add_warning(_warning);
+    }
 
     dirs.sort_by(|a, b| a.dir_name.cmp(&b.dir_name));
     files.sort_by(|a, b| a.file_name.cmp(&b.file_name));
@@ -302,9 +1316,17 @@ fn walk_directory(path_root: &Path, depth_relative: usize, copy_ctx: &mut CopyCo
         || copy_ctx.copy_patterns.patterns_exclude_dirs.is_some()
     {
         let rule_pattern = copy_ctx.copy_options.rule_pattern;
+        let should_match_full_path = copy_ctx.copy_options.should_match_full_path
+            || rule_pattern == CopyPatternMode::Gitignore;
         dirs.retain(|_d| {
             !should_exclude_by_patterns(
-                &_d.dir_name,
+                &derive_pattern_subject(
+                    &_d.dir_src_path,
+                    &_d.dir_name,
+                    &copy_ctx.dir_src_path,
+                    should_match_full_path,
+                ),
+                true,
                 copy_ctx.copy_patterns.patterns_include_dirs.as_ref(),
                 copy_ctx.copy_patterns.patterns_exclude_dirs.as_ref(),
                 rule_pattern,
@@ -317,17 +1339,12 @@ fn walk_directory(path_root: &Path, depth_relative: usize, copy_ctx: &mut CopyCo
         dirs.clear();
     }
 
-    for _dir_entry in dirs {
-        let path_next = _dir_entry.dir_src_path.clone();
-        let should_descend = handle_dir_entry(_dir_entry, depth_relative + 1, copy_ctx);
-        if should_descend {
-            walk_directory(&path_next, depth_relative + 1, copy_ctx);
-        }
-    }
-
-    for _file_entry in files {
-        handle_file_entry(_file_entry, depth_relative + 1, copy_ctx);
-    }
+    Some(WalkFrame {
+        depth_relative,
+        symlink_hops,
+        iter_dirs: dirs.into_iter(),
+        files,
+    })
 }
 
 fn handle_dir_entry(
@@ -356,8 +1373,10 @@ fn handle_dir_entry(
         }
 
         if should_error_broken_symlink(&dir_entry.dir_src_path, rule_symlink) {
-            copy_ctx.report_builder.add_error(
+            copy_ctx.report_builder.add_source_error(
+                dir_entry.dir_src_path.clone(),
                 dir_entry.dir_src_path.clone(),
+                CopyErrorKind::BrokenSymlink,
                 format!("Broken symlink: {}", dir_entry.dir_src_path.display()),
             );
             if should_keep_tree && is_depth_within {
@@ -377,14 +1396,12 @@ fn handle_dir_entry(
                 .add_counts(&["cnt_scanned", "cnt_matched"], 1);
 
             if should_keep_tree {
-                let path_dir_dst_sub = derive_destination_path(
+                let path_dir_dst_sub = derive_entry_destination_path(
                     &dir_entry.dir_src_path,
                     &dir_entry.dir_name,
-                    &copy_ctx.dir_src_path,
-                    &copy_ctx.dir_dst_path,
-                    should_keep_tree,
+                    copy_ctx,
                 );
-                if should_error_unsafe_destination_path(&path_dir_dst_sub, copy_ctx) {
+                if should_error_unsafe_destination_path(&path_dir_dst_sub, false, copy_ctx) {
                     return false;
                 }
 
@@ -393,6 +1410,16 @@ fn handle_dir_entry(
                     rule_conflict_dir,
                     &mut copy_ctx.report_builder,
                 ) {
+                    if rule_conflict_dir == CopyDirectoryConflictMode::Skip
+                        && path_dir_dst_sub.is_dir()
+                    {
+                        record_plan_entry(
+                            copy_ctx,
+                            &dir_entry.dir_src_path,
+                            &path_dir_dst_sub,
+                            CopyPlanAction::SkipConflict,
+                        );
+                    }
                     return false;
                 }
 
@@ -406,20 +1433,31 @@ fn handle_dir_entry(
                 }
 
                 if should_dry_run {
+                    record_plan_entry(
+                        copy_ctx,
+                        &dir_entry.dir_src_path,
+                        &path_dir_dst_sub,
+                        CopyPlanAction::CreateSymlink,
+                    );
                     copy_ctx.report_builder.add_skipped();
                     return false;
                 }
 
-                create_symbolic_link(
-                    &dir_entry.dir_src_path,
-                    &path_dir_dst_sub,
-                    &mut copy_ctx.report_builder,
+                push_plan_entry(
+                    copy_ctx,
+                    dir_entry.dir_src_path,
+                    path_dir_dst_sub,
+                    CopyPlanAction::CreateSymlink,
                 );
                 return false;
             }
 
-            let path_file_dst = copy_ctx.dir_dst_path.join(&dir_entry.dir_name);
-            if should_error_unsafe_destination_path(&path_file_dst, copy_ctx) {
+            let path_file_dst = derive_entry_destination_path(
+                &dir_entry.dir_src_path,
+                &dir_entry.dir_name,
+                copy_ctx,
+            );
+            if should_error_unsafe_destination_path(&path_file_dst, false, copy_ctx) {
                 return false;
             }
             if should_skip_file_conflict(
@@ -427,18 +1465,33 @@ fn handle_dir_entry(
                 rule_conflict_file,
                 &mut copy_ctx.report_builder,
             ) {
+                if rule_conflict_file == CopyFileConflictMode::Skip && !path_file_dst.is_dir() {
+                    record_plan_entry(
+                        copy_ctx,
+                        &dir_entry.dir_src_path,
+                        &path_file_dst,
+                        CopyPlanAction::SkipConflict,
+                    );
+                }
                 return false;
             }
 
             if should_dry_run {
+                record_plan_entry(
+                    copy_ctx,
+                    &dir_entry.dir_src_path,
+                    &path_file_dst,
+                    CopyPlanAction::CreateSymlink,
+                );
                 copy_ctx.report_builder.add_skipped();
                 return false;
             }
 
-            create_symbolic_link(
-                &dir_entry.dir_src_path,
-                &path_file_dst,
-                &mut copy_ctx.report_builder,
+            push_plan_entry(
+                copy_ctx,
+                dir_entry.dir_src_path,
+                path_file_dst,
+                CopyPlanAction::CreateSymlink,
             );
             return false;
         }
@@ -448,14 +1501,9 @@ fn handle_dir_entry(
         copy_ctx
             .report_builder
             .add_counts(&["cnt_scanned", "cnt_matched"], 1);
-        let path_dir_dst_sub = derive_destination_path(
-            &dir_entry.dir_src_path,
-            &dir_entry.dir_name,
-            &copy_ctx.dir_src_path,
-            &copy_ctx.dir_dst_path,
-            should_keep_tree,
-        );
-        if should_error_unsafe_destination_path(&path_dir_dst_sub, copy_ctx) {
+        let path_dir_dst_sub =
+            derive_entry_destination_path(&dir_entry.dir_src_path, &dir_entry.dir_name, copy_ctx);
+        if should_error_unsafe_destination_path(&path_dir_dst_sub, false, copy_ctx) {
             return false;
         }
 
@@ -464,24 +1512,61 @@ fn handle_dir_entry(
             rule_conflict_dir,
             &mut copy_ctx.report_builder,
         ) {
+            if rule_conflict_dir == CopyDirectoryConflictMode::Skip && path_dir_dst_sub.is_dir() {
+                record_plan_entry(
+                    copy_ctx,
+                    &dir_entry.dir_src_path,
+                    &path_dir_dst_sub,
+                    CopyPlanAction::SkipConflict,
+                );
+            }
             return false;
         }
 
         if should_dry_run {
+            if !path_dir_dst_sub.is_dir() {
+                record_plan_entry(
+                    copy_ctx,
+                    &dir_entry.dir_src_path,
+                    &path_dir_dst_sub,
+                    CopyPlanAction::CreateDir,
+                );
+            }
             copy_ctx.report_builder.add_skipped();
-        } else if let Err(e) = fs::create_dir_all(&path_dir_dst_sub) {
-            copy_ctx
-                .report_builder
-                .add_error(path_dir_dst_sub, e.to_string());
-            return false;
         } else {
-            copy_ctx.report_builder.add_copied();
+            push_plan_entry(
+                copy_ctx,
+                dir_entry.dir_src_path,
+                path_dir_dst_sub,
+                CopyPlanAction::CreateDir,
+            );
         }
     }
 
     true
 }
 
+/// Count one matched file against `files_max`.
+///
+/// Returns `true` once the limit is exceeded; the first time, a warning is
+/// recorded and traversal stops so the partial report is returned early.
+fn is_files_max_exceeded(copy_ctx: &mut CopyContext) -> bool {
+    let Some(files_max) = copy_ctx.copy_options.files_max else {
+        return false;
+    };
+    copy_ctx.cnt_files_matched += 1;
+    if copy_ctx.cnt_files_matched <= files_max {
+        return false;
+    }
+    if !copy_ctx.is_files_max_reached {
+        copy_ctx.is_files_max_reached = true;
+        copy_ctx.report_builder.add_warning(format!(
+            "File limit of {files_max} files reached; traversal stopped early."
+        ));
+    }
+    true
+}
+
 fn handle_file_entry(file_entry: FileEntryRecord, depth_value: usize, copy_ctx: &mut CopyContext) {
     let depth_limit = copy_ctx.copy_options.depth_limit;
     let rule_depth_limit = copy_ctx.copy_options.rule_depth_limit;
@@ -492,8 +1577,16 @@ fn handle_file_entry(file_entry: FileEntryRecord, depth_value: usize, copy_ctx:
     copy_ctx.report_builder.add_scanned();
 
     let rule_pattern = copy_ctx.copy_options.rule_pattern;
+    let should_match_full_path =
+        copy_ctx.copy_options.should_match_full_path || rule_pattern == CopyPatternMode::Gitignore;
     if should_exclude_by_patterns(
-        &file_entry.file_name,
+        &derive_pattern_subject(
+            &file_entry.file_src_path,
+            &file_entry.file_name,
+            &copy_ctx.dir_src_path,
+            should_match_full_path,
+        ),
+        false,
         copy_ctx.copy_patterns.patterns_include_files.as_ref(),
         copy_ctx.copy_patterns.patterns_exclude_files.as_ref(),
         rule_pattern,
@@ -501,6 +1594,9 @@ fn handle_file_entry(file_entry: FileEntryRecord, depth_value: usize, copy_ctx:
         return;
     }
     copy_ctx.report_builder.add_matched();
+    if is_files_max_exceeded(copy_ctx) {
+        return;
+    }
 
     let rule_symlink = copy_ctx.copy_options.rule_symlink;
     if file_entry.is_symlink {
@@ -510,48 +1606,123 @@ fn handle_file_entry(file_entry: FileEntryRecord, depth_value: usize, copy_ctx:
         }
 
         if should_error_broken_symlink(&file_entry.file_src_path, rule_symlink) {
-            copy_ctx.report_builder.add_error(
+            copy_ctx.report_builder.add_source_error(
                 file_entry.file_src_path.clone(),
+                file_entry.file_src_path.clone(),
+                CopyErrorKind::BrokenSymlink,
                 format!("Broken symlink: {}", file_entry.file_src_path.display()),
             );
             return;
         }
     }
+    let mut metadata_special = None;
     if !file_entry.is_symlink {
         let metadata_src = match fs::symlink_metadata(&file_entry.file_src_path) {
             Ok(v) => v,
             Err(e) => {
-                copy_ctx
-                    .report_builder
-                    .add_error(file_entry.file_src_path.clone(), e.to_string());
+                copy_ctx.report_builder.add_error(
+                    file_entry.file_src_path.clone(),
+                    CopyErrorKind::MetadataFailed,
+                    e.to_string(),
+                );
                 return;
             }
         };
         if !metadata_src.file_type().is_file() {
-            copy_ctx.report_builder.add_warning(format!(
-                "Special file skipped: {}",
-                file_entry.file_src_path.display()
-            ));
-            copy_ctx.report_builder.add_skipped();
-            return;
+            metadata_special = Some(metadata_src);
         }
     } else if rule_symlink == CopySymlinkMode::Dereference {
         let metadata_target = match fs::metadata(&file_entry.file_src_path) {
             Ok(v) => v,
             Err(e) => {
-                copy_ctx
-                    .report_builder
-                    .add_error(file_entry.file_src_path.clone(), e.to_string());
+                copy_ctx.report_builder.add_error(
+                    file_entry.file_src_path.clone(),
+                    CopyErrorKind::MetadataFailed,
+                    e.to_string(),
+                );
                 return;
             }
         };
         if !metadata_target.file_type().is_file() {
-            copy_ctx.report_builder.add_warning(format!(
-                "Special file target skipped: {}",
-                file_entry.file_src_path.display()
-            ));
-            copy_ctx.report_builder.add_skipped();
-            return;
+            metadata_special = Some(metadata_target);
+        }
+    }
+    if metadata_special.is_some() {
+        let label = if file_entry.is_symlink {
+            "Special file target"
+        } else {
+            "Special file"
+        };
+        match copy_ctx.copy_options.rule_special_file {
+            CopySpecialFileMode::Skip => {
+                copy_ctx.report_builder.add_warning(format!(
+                    "{label} skipped: {}",
+                    file_entry.file_src_path.display()
+                ));
+                copy_ctx.report_builder.add_skipped();
+                return;
+            }
+            CopySpecialFileMode::Error => {
+                copy_ctx.report_builder.add_error(
+                    file_entry.file_src_path.clone(),
+                    CopyErrorKind::SpecialFile,
+                    format!("{label} not copied: {}", file_entry.file_src_path.display()),
+                );
+                return;
+            }
+            CopySpecialFileMode::AttemptCopy => {}
+        }
+    }
+
+    let mtime_after = copy_ctx.copy_options.mtime_after;
+    let mtime_before = copy_ctx.copy_options.mtime_before;
+    if mtime_after.is_some() || mtime_before.is_some() {
+        let metadata_mtime =
+            if file_entry.is_symlink && rule_symlink == CopySymlinkMode::Dereference {
+                fs::metadata(&file_entry.file_src_path)
+            } else {
+                fs::symlink_metadata(&file_entry.file_src_path)
+            };
+        match metadata_mtime.and_then(|_meta| _meta.modified()) {
+            Ok(mtime) => {
+                if !is_mtime_within_window(mtime, mtime_after, mtime_before) {
+                    copy_ctx.report_builder.add_skipped();
+                    return;
+                }
+            }
+            Err(e) => {
+                copy_ctx.report_builder.add_warning(format!(
+                    "Failed to read modification time {} ({e})",
+                    file_entry.file_src_path.display()
+                ));
+                copy_ctx.report_builder.add_skipped();
+                return;
+            }
+        }
+    }
+
+    if let Some(filter) = &copy_ctx.copy_options.filter {
+        let metadata_filter =
+            if file_entry.is_symlink && rule_symlink == CopySymlinkMode::Dereference {
+                fs::metadata(&file_entry.file_src_path)
+            } else {
+                fs::symlink_metadata(&file_entry.file_src_path)
+            };
+        match metadata_filter {
+            Ok(metadata) => {
+                if !filter.is_match(&file_entry.file_src_path, &metadata) {
+                    copy_ctx.report_builder.add_skipped();
+                    return;
+                }
+            }
+            Err(e) => {
+                copy_ctx.report_builder.add_error(
+                    file_entry.file_src_path.clone(),
+                    CopyErrorKind::MetadataFailed,
+                    e.to_string(),
+                );
+                return;
+            }
         }
     }
 
@@ -570,25 +1741,21 @@ fn handle_file_entry(file_entry: FileEntryRecord, depth_value: usize, copy_ctx:
         }
     }
 
-    let should_keep_tree = copy_ctx.copy_options.should_keep_tree;
-    let path_file_dst = derive_destination_path(
-        &file_entry.file_src_path,
-        &file_entry.file_name,
-        &copy_ctx.dir_src_path,
-        &copy_ctx.dir_dst_path,
-        should_keep_tree,
-    );
-    if should_error_unsafe_destination_path(&path_file_dst, copy_ctx) {
+    let path_file_dst =
+        derive_entry_destination_path(&file_entry.file_src_path, &file_entry.file_name, copy_ctx);
+    if should_error_unsafe_destination_path(&path_file_dst, false, copy_ctx) {
         return;
     }
 
-    if should_keep_tree
-        && let Some(path_parent_dst) = path_file_dst.parent()
-        && let Err(e) = fs::create_dir_all(path_parent_dst)
+    if copy_ctx.copy_options.should_copy_incrementally
+        && !(file_entry.is_symlink && rule_symlink == CopySymlinkMode::CopySymlinks)
+        && is_file_unchanged(
+            &file_entry.file_src_path,
+            &path_file_dst,
+            Duration::from_millis(copy_ctx.copy_options.mtime_tolerance_ms),
+        )
     {
-        copy_ctx
-            .report_builder
-            .add_error(path_file_dst, e.to_string());
+        copy_ctx.report_builder.add_unchanged();
         return;
     }
 
@@ -598,27 +1765,31 @@ fn handle_file_entry(file_entry: FileEntryRecord, depth_value: usize, copy_ctx:
         rule_conflict_file,
         &mut copy_ctx.report_builder,
     ) {
+        if rule_conflict_file == CopyFileConflictMode::Skip && !path_file_dst.is_dir() {
+            record_plan_entry(
+                copy_ctx,
+                &file_entry.file_src_path,
+                &path_file_dst,
+                CopyPlanAction::SkipConflict,
+            );
+        }
         return;
     }
 
+    let action = if metadata_special.is_some() {
+        CopyPlanAction::CreateSpecialFile
+    } else if file_entry.is_symlink && rule_symlink == CopySymlinkMode::CopySymlinks {
+        CopyPlanAction::CreateSymlink
+    } else {
+        CopyPlanAction::CopyFile
+    };
     if copy_ctx.copy_options.should_dry_run {
+        record_plan_entry(copy_ctx, &file_entry.file_src_path, &path_file_dst, action);
         copy_ctx.report_builder.add_skipped();
         return;
     }
 
-    if file_entry.is_symlink && rule_symlink == CopySymlinkMode::CopySymlinks {
-        create_symbolic_link(
-            &file_entry.file_src_path,
-            &path_file_dst,
-            &mut copy_ctx.report_builder,
-        );
-        return;
-    }
-
-    copy_ctx.file_copy_tasks.push(CopyTaskFileSpec {
-        file_src_path: file_entry.file_src_path,
-        file_dst_path: path_file_dst,
-    });
+    push_plan_entry(copy_ctx, file_entry.file_src_path, path_file_dst, action);
 }
 
 #[cfg(test)]
@@ -626,10 +1797,12 @@ mod tests {
     use std::path::{Path, PathBuf};
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    use super::copy_tree;
+    use super::{copy_entry, copy_files, copy_tree, execute_plan, plan_copy};
     use crate::spec::{
-        CopyDepthLimitMode, CopyDirectoryConflictMode, CopyFileConflictMode, CopyOptionsSpec,
-        CopyPatternMode, CopySymlinkMode, CopyTreeError,
+        CopyDepthLimitMode, CopyDirectoryConflictMode, CopyDstSymlinkConflictMode, CopyErrorKind,
+        CopyFileConflictMode, CopyFileFilter, CopyHashAlgorithm, CopyOptionsSpec, CopyPatternMode,
+        CopyPlanAction, CopyPlanEntry, CopySpecialFileMode, CopySymlinkMode, CopyTreeError,
+        CopyUnicodeForm,
     };
 
     struct TestDir {
@@ -683,34 +1856,496 @@ mod tests {
     }
 
     #[test]
-    fn copy_tree_flatten_with_include_glob() {
+    fn copy_tree_walks_deep_chain_without_recursion() {
+        // Stays below PATH_MAX while being far deeper than a recursive walk
+        // fits into the small stack below.
+        const DEPTH: usize = 400;
         let tmp = TestDir::new();
         let src = tmp.path().join("src");
         let dst = tmp.path().join("dst");
 
-        write_text(&src.join("root.txt"), "root");
-        write_text(&src.join("a/file1.txt"), "a");
-        write_text(&src.join("a/file1.md"), "a");
+        let mut path_deep = src.clone();
+        std::fs::create_dir(&path_deep).expect("create src");
+        for _ in 0..DEPTH {
+            path_deep.push("d");
+            std::fs::create_dir(&path_deep).expect("create deep dir");
+        }
+        write_text(&path_deep.join("leaf.txt"), "leaf");
 
         let copy_options = CopyOptionsSpec {
-            should_keep_tree: false,
-            patterns_include_files: Some(vec!["*.txt".to_string()]),
+            workers_max: Some(1),
             ..CopyOptionsSpec::default()
         };
+        let (src_walk, dst_walk) = (src.clone(), dst.clone());
+        let report = std::thread::Builder::new()
+            .stack_size(128 * 1024)
+            .spawn(move || copy_tree(&src_walk, &dst_walk, copy_options))
+            .expect("spawn walker")
+            .join()
+            .expect("walker must not overflow its stack")
+            .expect("copy tree");
 
-        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
         assert_eq!(report.error_count(), 0);
-        assert!(dst.join("root.txt").exists());
-        assert!(dst.join("file1.txt").exists());
-        assert!(!dst.join("file1.md").exists());
+        assert_eq!(report.cnt_copied as usize, DEPTH + 1);
+        let path_leaf_dst = dst.join(path_deep.strip_prefix(&src).expect("relative"));
+        assert_eq!(
+            std::fs::read_to_string(path_leaf_dst.join("leaf.txt")).expect("read leaf"),
+            "leaf"
+        );
     }
 
+    #[cfg(windows)]
     #[test]
-    fn copy_tree_depth_exact_works() {
+    fn copy_tree_with_fsync_copies_read_only_files() {
         let tmp = TestDir::new();
         let src = tmp.path().join("src");
         let dst = tmp.path().join("dst");
-
+        let file_src_path = src.join("locked.txt");
+        write_text(&file_src_path, "locked");
+        let mut permissions = std::fs::metadata(&file_src_path)
+            .expect("metadata")
+            .permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&file_src_path, permissions).expect("set readonly");
+
+        for should_copy_atomically in [false, true] {
+            let copy_options = CopyOptionsSpec {
+                should_fsync: true,
+                should_copy_atomically,
+                ..CopyOptionsSpec::default()
+            };
+            let report = copy_tree(
+                &src,
+                dst.join(should_copy_atomically.to_string()),
+                copy_options,
+            )
+            .expect("copy tree");
+            assert_eq!(report.error_count(), 0);
+            let file_dst_path = dst
+                .join(should_copy_atomically.to_string())
+                .join("locked.txt");
+            assert_eq!(
+                std::fs::read_to_string(&file_dst_path).expect("read"),
+                "locked"
+            );
+            assert!(
+                std::fs::metadata(&file_dst_path)
+                    .expect("metadata")
+                    .permissions()
+                    .readonly()
+            );
+        }
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn copy_tree_copies_paths_longer_than_max_path() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+
+        let path_rel: PathBuf = (0..6)
+            .map(|_idx| format!("{_idx}{}", "d".repeat(50)))
+            .collect();
+        write_text(&src.join(&path_rel).join("leaf.txt"), "leaf");
+        let path_leaf_dst = dst.join(&path_rel).join("leaf.txt");
+        assert!(path_leaf_dst.as_os_str().len() > 260);
+
+        let report = copy_tree(&src, &dst, CopyOptionsSpec::default()).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 7);
+        assert_eq!(
+            std::fs::read_to_string(&path_leaf_dst).expect("read leaf"),
+            "leaf"
+        );
+    }
+
+    #[test]
+    fn plan_copy_defers_writes_until_execute_plan() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+
+        write_text(&src.join("root.txt"), "root");
+        write_text(&src.join("a/file1.txt"), "a");
+        write_text(&src.join("a/file2.txt"), "a");
+
+        let mut copy_plan = plan_copy(&src, &dst, CopyOptionsSpec::default()).expect("plan copy");
+        assert!(!dst.exists());
+        assert_eq!(copy_plan.report().cnt_scanned, 4);
+        assert_eq!(copy_plan.report().cnt_copied, 0);
+        assert_eq!(
+            copy_plan
+                .entries
+                .iter()
+                .map(|_entry| (_entry.dst_path.clone(), _entry.action))
+                .collect::<Vec<_>>(),
+            vec![
+                (dst.join("a"), CopyPlanAction::CreateDir),
+                (dst.join("a/file1.txt"), CopyPlanAction::CopyFile),
+                (dst.join("a/file2.txt"), CopyPlanAction::CopyFile),
+                (dst.join("root.txt"), CopyPlanAction::CopyFile),
+            ]
+        );
+
+        copy_plan
+            .entries
+            .retain(|_entry| _entry.dst_path != dst.join("a/file2.txt"));
+        let report = execute_plan(copy_plan);
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 3);
+        assert!(dst.join("a/file1.txt").exists());
+        assert!(!dst.join("a/file2.txt").exists());
+        assert!(dst.join("root.txt").exists());
+    }
+
+    #[test]
+    fn execute_plan_rejects_entries_outside_destination() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        let outside = tmp.path().join("outside");
+
+        write_text(&src.join("a.txt"), "a");
+
+        let mut copy_plan = plan_copy(&src, &dst, CopyOptionsSpec::default()).expect("plan copy");
+        copy_plan.entries[0].dst_path = outside.join("a.txt");
+        let report = execute_plan(copy_plan);
+        assert_eq!(report.cnt_copied, 0);
+        assert_eq!(report.errors[0].kind, CopyErrorKind::UnsafeDestination);
+        assert!(!outside.exists());
+    }
+
+    #[test]
+    fn copy_tree_flatten_with_include_glob() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+
+        write_text(&src.join("root.txt"), "root");
+        write_text(&src.join("a/file1.txt"), "a");
+        write_text(&src.join("a/file1.md"), "a");
+
+        let copy_options = CopyOptionsSpec {
+            should_keep_tree: false,
+            patterns_include_files: Some(vec!["*.txt".to_string()]),
+            ..CopyOptionsSpec::default()
+        };
+
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(dst.join("root.txt").exists());
+        assert!(dst.join("file1.txt").exists());
+        assert!(!dst.join("file1.md").exists());
+    }
+
+    #[test]
+    fn copy_tree_mtime_window_is_inclusive() {
+        use filetime::{FileTime, set_file_mtime};
+        use std::time::Duration;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+
+        for (_name, _secs) in [
+            ("old.txt", 1_700_000_000),
+            ("lower.txt", 1_700_000_100),
+            ("middle.txt", 1_700_000_150),
+            ("upper.txt", 1_700_000_200),
+            ("new.txt", 1_700_000_300),
+        ] {
+            write_text(&src.join(_name), _name);
+            set_file_mtime(src.join(_name), FileTime::from_unix_time(_secs, 0)).expect("set mtime");
+        }
+
+        let copy_options = CopyOptionsSpec {
+            mtime_after: Some(UNIX_EPOCH + Duration::from_secs(1_700_000_100)),
+            mtime_before: Some(UNIX_EPOCH + Duration::from_secs(1_700_000_200)),
+            ..CopyOptionsSpec::default()
+        };
+
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 3);
+        assert_eq!(report.cnt_skipped, 2);
+        assert!(!dst.join("old.txt").exists());
+        assert!(dst.join("lower.txt").exists());
+        assert!(dst.join("middle.txt").exists());
+        assert!(dst.join("upper.txt").exists());
+        assert!(!dst.join("new.txt").exists());
+    }
+
+    #[test]
+    fn copy_tree_filter_predicate_skips_rejected_files() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("keep.log"), "keep");
+        write_text(&src.join("also.log"), "also");
+        write_text(&src.join("drop.txt"), "drop");
+        write_text(&src.join("empty.log"), "");
+
+        let copy_options = CopyOptionsSpec {
+            filter: Some(CopyFileFilter::new(|path, metadata| {
+                path.extension().is_some_and(|ext| ext == "log") && metadata.len() > 0
+            })),
+            ..CopyOptionsSpec::default()
+        };
+
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 2);
+        assert_eq!(report.cnt_skipped, 2);
+        assert!(dst.join("keep.log").exists());
+        assert!(dst.join("also.log").exists());
+        assert!(!dst.join("drop.txt").exists());
+        assert!(!dst.join("empty.log").exists());
+    }
+
+    #[test]
+    fn copy_tree_collects_stats_by_extension() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "abc");
+        write_text(&src.join("nested/B.TXT"), "de");
+        write_text(&src.join("c.csv"), "f");
+        write_text(&src.join("README"), "readme");
+
+        let copy_options = CopyOptionsSpec {
+            should_collect_ext_stats: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(
+            report.stats_by_ext.into_iter().collect::<Vec<_>>(),
+            vec![
+                (String::new(), (1, 6)),
+                ("csv".to_string(), (1, 1)),
+                ("txt".to_string(), (2, 5)),
+            ]
+        );
+
+        let report = copy_tree(&src, tmp.path().join("dst2"), CopyOptionsSpec::default())
+            .expect("copy tree");
+        assert!(report.stats_by_ext.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_dedup_destination_links_identical_files() {
+        use std::os::unix::fs::MetadataExt;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "same");
+        write_text(&src.join("b.txt"), "same");
+        write_text(&src.join("c.txt"), "other");
+        write_text(&src.join("d.txt"), "samf");
+
+        let copy_options = CopyOptionsSpec {
+            should_dedup_destination: true,
+            manifest_algorithm: Some(CopyHashAlgorithm::Sha256),
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 3);
+        assert_eq!(report.cnt_linked, 1);
+        assert_eq!(report.manifest.len(), 4);
+
+        let ino = |_name: &str| std::fs::metadata(dst.join(_name)).expect("stat").ino();
+        assert_eq!(ino("a.txt"), ino("b.txt"));
+        assert_ne!(ino("a.txt"), ino("d.txt"));
+        assert_eq!(
+            std::fs::read_to_string(dst.join("b.txt")).expect("read"),
+            "same"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_dedup_destination_keeps_differing_permissions_apart() {
+        use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "same");
+        write_text(&src.join("b.txt"), "same");
+        std::fs::set_permissions(src.join("a.txt"), std::fs::Permissions::from_mode(0o644))
+            .expect("chmod");
+        std::fs::set_permissions(src.join("b.txt"), std::fs::Permissions::from_mode(0o600))
+            .expect("chmod");
+
+        let copy_options = CopyOptionsSpec {
+            should_dedup_destination: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 2);
+        assert_eq!(report.cnt_linked, 0);
+
+        let stat = |_name: &str| std::fs::metadata(dst.join(_name)).expect("stat");
+        assert_ne!(stat("a.txt").ino(), stat("b.txt").ino());
+        assert_eq!(stat("a.txt").mode() & 0o777, 0o644);
+        assert_eq!(stat("b.txt").mode() & 0o777, 0o600);
+    }
+
+    #[test]
+    fn copy_tree_dedup_destination_links_outside_bytes_budget() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.bin"), &"x".repeat(100));
+        write_text(&src.join("b.bin"), &"x".repeat(100));
+        write_text(&src.join("c.bin"), &"y".repeat(40));
+        write_text(&src.join("d.bin"), &"z".repeat(40));
+
+        let copy_options = CopyOptionsSpec {
+            should_dedup_destination: true,
+            bytes_budget_max: Some(150),
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 2);
+        assert_eq!(report.cnt_linked, 1);
+        assert_eq!(report.cnt_skipped, 1);
+        assert_eq!(report.bytes_copied, 140);
+        assert_eq!(report.warning_count(), 1);
+        assert!(report.warnings[0].contains("Byte budget of 150 bytes reached"));
+        assert!(dst.join("b.bin").exists());
+        assert!(!dst.join("d.bin").exists());
+    }
+
+    #[test]
+    fn copy_tree_full_path_patterns_match_relative_paths() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+
+        write_text(&src.join("logs/app.tmp"), "tmp");
+        write_text(&src.join("logs/app.log"), "log");
+        write_text(&src.join("data/app.tmp"), "tmp");
+        write_text(&src.join("cache/keep.txt"), "keep");
+
+        let copy_options = CopyOptionsSpec {
+            patterns_exclude_files: Some(vec!["logs/*.tmp".to_string()]),
+            patterns_exclude_dirs: Some(vec!["cache".to_string()]),
+            should_match_full_path: true,
+            ..CopyOptionsSpec::default()
+        };
+
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(!dst.join("logs/app.tmp").exists());
+        assert!(dst.join("logs/app.log").exists());
+        assert!(dst.join("data/app.tmp").exists());
+        assert!(!dst.join("cache").exists());
+    }
+
+    #[test]
+    fn copy_tree_full_path_glob_double_star_spans_directories() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst_recursive = tmp.path().join("dst_recursive");
+        let dst_single = tmp.path().join("dst_single");
+
+        write_text(&src.join("a/b/c.txt"), "c");
+        write_text(&src.join("a/d.txt"), "d");
+
+        let copy_options = CopyOptionsSpec {
+            patterns_include_files: Some(vec!["a/**/c.txt".to_string()]),
+            should_match_full_path: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst_recursive, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(dst_recursive.join("a/b/c.txt").exists());
+        assert!(!dst_recursive.join("a/d.txt").exists());
+
+        let copy_options = CopyOptionsSpec {
+            patterns_include_files: Some(vec!["a/*.txt".to_string()]),
+            should_match_full_path: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst_single, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(!dst_single.join("a/b/c.txt").exists());
+        assert!(dst_single.join("a/d.txt").exists());
+    }
+
+    #[test]
+    fn copy_tree_gitignore_mode_follows_gitignore_semantics() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+
+        write_text(&src.join("app.log"), "log");
+        write_text(&src.join("keep.log"), "keep");
+        write_text(&src.join("nested/deep.log"), "log");
+        write_text(&src.join("nested/build/out.bin"), "bin");
+        write_text(&src.join("build/out.bin"), "bin");
+        write_text(&src.join("docs/build"), "file named build");
+        write_text(&src.join("docs/readme.md"), "md");
+        write_text(&src.join("root_only.txt"), "root");
+        write_text(&src.join("docs/root_only.txt"), "nested");
+
+        let rules = vec![
+            "# comment".to_string(),
+            "*.log".to_string(),
+            "!keep.log".to_string(),
+            "build/".to_string(),
+            "/root_only.txt".to_string(),
+        ];
+        let copy_options = CopyOptionsSpec {
+            patterns_exclude_files: Some(rules.clone()),
+            patterns_exclude_dirs: Some(rules),
+            rule_pattern: CopyPatternMode::Gitignore,
+            ..CopyOptionsSpec::default()
+        };
+
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(!dst.join("app.log").exists());
+        assert!(dst.join("keep.log").exists());
+        assert!(!dst.join("nested/deep.log").exists());
+        assert!(!dst.join("nested/build").exists());
+        assert!(!dst.join("build").exists());
+        assert!(dst.join("docs/build").is_file());
+        assert!(dst.join("docs/readme.md").exists());
+        assert!(!dst.join("root_only.txt").exists());
+        assert!(dst.join("docs/root_only.txt").exists());
+    }
+
+    #[test]
+    fn copy_tree_invalid_gitignore_rule_rejected() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "a");
+
+        let copy_options = CopyOptionsSpec {
+            patterns_exclude_files: Some(vec!["!/".to_string()]),
+            rule_pattern: CopyPatternMode::Gitignore,
+            ..CopyOptionsSpec::default()
+        };
+
+        let err = copy_tree(&src, &dst, copy_options).expect_err("invalid rule must fail");
+        assert!(matches!(err, CopyTreeError::InvalidPattern(_)));
+    }
+
+    #[test]
+    fn copy_tree_depth_exact_works() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+
         write_text(&src.join("root.txt"), "root");
         write_text(&src.join("a/file1.txt"), "a");
 
@@ -740,193 +2375,1106 @@ mod tests {
         ));
     }
 
-    #[cfg(unix)]
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_rejects_destination_symlinked_into_source() {
+        use std::os::unix::fs::symlink;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        write_text(&src.join("sub").join("a.txt"), "a");
+        symlink(src.join("sub"), tmp.path().join("dst_link")).expect("create symlink");
+        symlink(src.join("missing"), tmp.path().join("dst_dangling")).expect("create symlink");
+
+        for _dst in [
+            tmp.path().join("dst_link"),
+            tmp.path().join("dst_link").join("new"),
+            tmp.path().join("dst_dangling"),
+            tmp.path().join("dst_dangling").join("new"),
+        ] {
+            let err = copy_tree(&src, &_dst, CopyOptionsSpec::default()).expect_err("must fail");
+            assert!(matches!(
+                err,
+                CopyTreeError::SourceDestinationOverlap { .. }
+            ));
+        }
+        assert!(!src.join("missing").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_symlink_copy_mode() {
+        use std::os::unix::fs::symlink;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("root.txt"), "root");
+        symlink(src.join("root.txt"), src.join("link_root.txt")).expect("create symlink");
+
+        let copy_options = CopyOptionsSpec {
+            rule_symlink: CopySymlinkMode::CopySymlinks,
+            ..CopyOptionsSpec::default()
+        };
+
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 2);
+        assert_eq!(report.cnt_symlinks, 1);
+        assert!(dst.join("link_root.txt").is_symlink());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_does_not_descend_ancestor_symlink_in_non_dereference_modes() {
+        use std::os::unix::fs::symlink;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        write_text(&src.join("a").join("a.txt"), "a");
+        symlink(&src, src.join("a").join("loop")).expect("create symlink");
+
+        let dst_copy = tmp.path().join("dst_copy");
+        let report_copy = copy_tree(
+            &src,
+            &dst_copy,
+            CopyOptionsSpec {
+                rule_symlink: CopySymlinkMode::CopySymlinks,
+                ..CopyOptionsSpec::default()
+            },
+        )
+        .expect("copy tree");
+        assert_eq!(report_copy.error_count(), 0);
+        assert!(dst_copy.join("a").join("a.txt").exists());
+        assert!(dst_copy.join("a").join("loop").is_symlink());
+
+        let dst_skip = tmp.path().join("dst_skip");
+        let report_skip = copy_tree(
+            &src,
+            &dst_skip,
+            CopyOptionsSpec {
+                rule_symlink: CopySymlinkMode::SkipSymlinks,
+                ..CopyOptionsSpec::default()
+            },
+        )
+        .expect("copy tree");
+        assert_eq!(report_skip.error_count(), 0);
+        assert_eq!(report_skip.cnt_skipped, 1);
+        assert!(dst_skip.join("a").join("a.txt").exists());
+        assert!(!dst_skip.join("a").join("loop").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_symlink_follow_max_limits_dereference_hops() {
+        use std::os::unix::fs::symlink;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        let outer = tmp.path().join("outer");
+        let inner = tmp.path().join("inner");
+
+        write_text(&src.join("root.txt"), "root");
+        write_text(&outer.join("outer.txt"), "outer");
+        write_text(&inner.join("inner.txt"), "inner");
+        symlink(&outer, src.join("link_outer")).expect("create outer symlink");
+        symlink(&inner, outer.join("link_inner")).expect("create inner symlink");
+
+        let copy_options = CopyOptionsSpec {
+            rule_symlink: CopySymlinkMode::Dereference,
+            symlink_follow_max: Some(1),
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+        assert_eq!(report.error_count(), 0);
+        assert!(dst.join("root.txt").exists());
+        assert!(dst.join("link_outer/outer.txt").exists());
+        assert!(!dst.join("link_outer/link_inner").exists());
+        assert!(
+            report
+                .warnings
+                .iter()
+                .any(|w| w.contains("Symlink follow limit reached"))
+        );
+    }
+
+    #[test]
+    fn copy_tree_include_regex_works() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+
+        write_text(&src.join("report_01.csv"), "ok");
+        write_text(&src.join("report_02.csv"), "ok");
+        write_text(&src.join("note.txt"), "txt");
+
+        let copy_options = CopyOptionsSpec {
+            patterns_include_files: Some(vec![r"^report_\d+\.csv$".to_string()]),
+            rule_pattern: CopyPatternMode::Regex,
+            ..CopyOptionsSpec::default()
+        };
+
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(dst.join("report_01.csv").exists());
+        assert!(dst.join("report_02.csv").exists());
+        assert!(!dst.join("note.txt").exists());
+    }
+
+    #[test]
+    fn copy_tree_include_exclude_regex_works() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+
+        write_text(&src.join("report_keep.csv"), "ok");
+        write_text(&src.join("report_skip.csv"), "skip");
+        write_text(&src.join("other.csv"), "other");
+
+        let copy_options = CopyOptionsSpec {
+            patterns_include_files: Some(vec![r"^report_.*\.csv$".to_string()]),
+            patterns_exclude_files: Some(vec![r"^report_skip\.csv$".to_string()]),
+            rule_pattern: CopyPatternMode::Regex,
+            ..CopyOptionsSpec::default()
+        };
+
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(dst.join("report_keep.csv").exists());
+        assert!(!dst.join("report_skip.csv").exists());
+        assert!(!dst.join("other.csv").exists());
+    }
+
+    #[test]
+    fn copy_tree_invalid_regex_rejected() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "a");
+
+        let copy_options = CopyOptionsSpec {
+            patterns_include_files: Some(vec!["(".to_string()]),
+            rule_pattern: CopyPatternMode::Regex,
+            ..CopyOptionsSpec::default()
+        };
+
+        let err = copy_tree(&src, &dst, copy_options).expect_err("invalid regex must fail");
+        assert!(matches!(err, CopyTreeError::InvalidPattern(_)));
+    }
+
+    #[test]
+    fn copy_tree_glob_char_class_works() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+
+        write_text(&src.join("file1.txt"), "1");
+        write_text(&src.join("filea.txt"), "a");
+
+        let copy_options = CopyOptionsSpec {
+            patterns_include_files: Some(vec!["file[0-9].txt".to_string()]),
+            rule_pattern: CopyPatternMode::Glob,
+            ..CopyOptionsSpec::default()
+        };
+
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(dst.join("file1.txt").exists());
+        assert!(!dst.join("filea.txt").exists());
+    }
+
+    #[test]
+    fn copy_tree_invalid_glob_rejected() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "a");
+
+        let copy_options = CopyOptionsSpec {
+            patterns_include_files: Some(vec!["[".to_string()]),
+            rule_pattern: CopyPatternMode::Glob,
+            ..CopyOptionsSpec::default()
+        };
+
+        let err = copy_tree(&src, &dst, copy_options).expect_err("invalid glob must fail");
+        assert!(matches!(err, CopyTreeError::InvalidPattern(_)));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn copy_tree_warns_hard_link() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("base.txt"), "base");
+        std::fs::hard_link(src.join("base.txt"), src.join("alias.txt")).expect("hard link");
+
+        let report = copy_tree(&src, &dst, CopyOptionsSpec::default()).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(
+            report
+                .warnings
+                .iter()
+                .any(|w| w.contains("Hard link detected"))
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn copy_tree_preserves_linux_metadata() {
+        use filetime::{FileTime, set_file_times};
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        let path_src_file = src.join("meta.txt");
+        write_text(&path_src_file, "meta");
+
+        std::fs::set_permissions(&path_src_file, std::fs::Permissions::from_mode(0o640))
+            .expect("set permissions");
+        set_file_times(
+            &path_src_file,
+            FileTime::from_unix_time(1_700_000_010, 0),
+            FileTime::from_unix_time(1_700_000_020, 0),
+        )
+        .expect("set times");
+
+        let xattr_name = "user.axiomkit_fs_test";
+        let has_xattr = xattr::set(&path_src_file, xattr_name, b"meta_value").is_ok();
+
+        let report = copy_tree(&src, &dst, CopyOptionsSpec::default()).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+
+        let path_dst_file = dst.join("meta.txt");
+        let stat_src = std::fs::metadata(&path_src_file).expect("src metadata");
+        let stat_dst = std::fs::metadata(&path_dst_file).expect("dst metadata");
+        assert_eq!(
+            stat_src.permissions().mode() & 0o777,
+            stat_dst.permissions().mode() & 0o777
+        );
+        assert_eq!(
+            FileTime::from_last_modification_time(&stat_src),
+            FileTime::from_last_modification_time(&stat_dst)
+        );
+
+        if has_xattr {
+            let raw_value_dst = xattr::get(&path_dst_file, xattr_name)
+                .expect("get dst xattr")
+                .expect("xattr exists");
+            assert_eq!(raw_value_dst, b"meta_value");
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn copy_tree_preserves_macos_xattrs() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        let path_src_file = src.join("meta.txt");
+        write_text(&path_src_file, "meta");
+
+        let xattr_name = "com.axiomkit.fs_test";
+        let has_xattr = xattr::set(&path_src_file, xattr_name, b"meta_value").is_ok();
+
+        for _copy_options in [
+            CopyOptionsSpec::default(),
+            CopyOptionsSpec {
+                copy_buffer_bytes: Some(4096),
+                rule_conflict_file: CopyFileConflictMode::Overwrite,
+                ..CopyOptionsSpec::default()
+            },
+        ] {
+            let report = copy_tree(&src, &dst, _copy_options).expect("copy tree");
+            assert_eq!(report.error_count(), 0);
+
+            if has_xattr {
+                let raw_value_dst = xattr::get(dst.join("meta.txt"), xattr_name)
+                    .expect("get dst xattr")
+                    .expect("xattr exists");
+                assert_eq!(raw_value_dst, b"meta_value");
+            }
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn copy_tree_preserves_linux_acl() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        let path_src_file = src.join("acl.txt");
+        write_text(&path_src_file, "acl");
+
+        // version 2 header + (tag, perm, id) entries: user::rw-, user:1234:r--,
+        // group::r--, mask::r--, other::r--.
+        let mut raw_acl = 2_u32.to_le_bytes().to_vec();
+        for (_tag, _perm, _id) in [
+            (0x01_u16, 6_u16, u32::MAX),
+            (0x02, 4, 1234),
+            (0x04, 4, u32::MAX),
+            (0x10, 4, u32::MAX),
+            (0x20, 4, u32::MAX),
+        ] {
+            raw_acl.extend_from_slice(&_tag.to_le_bytes());
+            raw_acl.extend_from_slice(&_perm.to_le_bytes());
+            raw_acl.extend_from_slice(&_id.to_le_bytes());
+        }
+        if xattr::set(&path_src_file, "system.posix_acl_access", &raw_acl).is_err() {
+            return;
+        }
+
+        let copy_options = CopyOptionsSpec {
+            should_preserve_acl: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+
+        let raw_acl_dst = xattr::get(dst.join("acl.txt"), "system.posix_acl_access")
+            .expect("get dst acl")
+            .expect("acl exists");
+        assert_eq!(raw_acl_dst, raw_acl);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn copy_tree_preserves_linux_ownership_or_warns() {
+        use std::os::unix::fs::MetadataExt;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("owned.txt"), "owned");
+
+        let copy_options = CopyOptionsSpec {
+            should_preserve_ownership: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(dst.join("owned.txt").exists());
+
+        let stat_src = std::fs::metadata(src.join("owned.txt")).expect("src metadata");
+        let stat_dst = std::fs::metadata(dst.join("owned.txt")).expect("dst metadata");
+        let is_owner_preserved =
+            stat_src.uid() == stat_dst.uid() && stat_src.gid() == stat_dst.gid();
+        let has_ownership_warning = report
+            .warnings
+            .iter()
+            .any(|w| w.contains("Failed to preserve ownership"));
+        assert!(is_owner_preserved || has_ownership_warning);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_preserves_directory_metadata() {
+        use filetime::{FileTime, set_file_mtime};
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("outer/inner/file.txt"), "x");
+
+        std::fs::set_permissions(src.join("outer"), std::fs::Permissions::from_mode(0o750))
+            .expect("set permissions");
+        for _dir in ["outer", "outer/inner"] {
+            set_file_mtime(src.join(_dir), FileTime::from_unix_time(1_700_000_000, 0))
+                .expect("set mtime");
+        }
+
+        let copy_options = CopyOptionsSpec {
+            should_preserve_dir_metadata: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert!(dst.join("outer/inner/file.txt").exists());
+
+        for _dir in ["outer", "outer/inner"] {
+            let stat_src = std::fs::metadata(src.join(_dir)).expect("src metadata");
+            let stat_dst = std::fs::metadata(dst.join(_dir)).expect("dst metadata");
+            assert_eq!(
+                stat_src.permissions().mode() & 0o777,
+                stat_dst.permissions().mode() & 0o777
+            );
+            assert_eq!(
+                FileTime::from_last_modification_time(&stat_src),
+                FileTime::from_last_modification_time(&stat_dst)
+            );
+        }
+    }
+
+    #[test]
+    fn copy_tree_atomic_overwrite_leaves_no_temp_files() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "new");
+        write_text(&src.join("sub/b.txt"), "b");
+        write_text(&dst.join("a.txt"), "old");
+
+        let copy_options = CopyOptionsSpec {
+            rule_conflict_file: CopyFileConflictMode::Overwrite,
+            rule_conflict_dir: CopyDirectoryConflictMode::Merge,
+            should_copy_atomically: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 3);
+        assert_eq!(
+            std::fs::read_to_string(dst.join("a.txt")).expect("read"),
+            "new"
+        );
+        assert!(dst.join("sub/b.txt").exists());
+
+        for _dir in [dst.clone(), dst.join("sub")] {
+            let has_temp = std::fs::read_dir(&_dir)
+                .expect("read dir")
+                .filter_map(Result::ok)
+                .any(|_e| _e.file_name().to_string_lossy().ends_with(".axmtmp"));
+            assert!(!has_temp);
+        }
+    }
+
+    #[test]
+    fn copy_tree_with_fsync_works() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "a");
+        write_text(&src.join("sub/b.txt"), "b");
+
+        let copy_options = CopyOptionsSpec {
+            should_fsync: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(
+            std::fs::read_to_string(dst.join("a.txt")).expect("read"),
+            "a"
+        );
+        assert_eq!(
+            std::fs::read_to_string(dst.join("sub/b.txt")).expect("read"),
+            "b"
+        );
+    }
+
+    #[test]
+    fn copy_tree_with_copy_buffer_bytes_works() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        let payload = "0123456789".repeat(1000);
+        write_text(&src.join("big.txt"), &payload);
+        write_text(&src.join("empty.txt"), "");
+
+        let copy_options = CopyOptionsSpec {
+            copy_buffer_bytes: Some(7),
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(
+            std::fs::read_to_string(dst.join("big.txt")).expect("read"),
+            payload
+        );
+        assert_eq!(
+            std::fs::read_to_string(dst.join("empty.txt")).expect("read"),
+            ""
+        );
+
+        let copy_options = CopyOptionsSpec {
+            copy_buffer_bytes: Some(0),
+            ..CopyOptionsSpec::default()
+        };
+        let err = copy_tree(&src, tmp.path().join("dst_zero"), copy_options)
+            .expect_err("zero buffer must fail");
+        assert!(matches!(err, CopyTreeError::InvalidOption(_)));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn copy_tree_preserves_sparse_file_holes() {
+        use std::io::{Seek, SeekFrom, Write};
+        use std::os::unix::fs::MetadataExt;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        std::fs::create_dir_all(&src).expect("create src");
+
+        let len_sparse: u64 = 16 * 1024 * 1024;
+        let path_src_file = src.join("sparse.img");
+        let mut file_src = std::fs::File::create(&path_src_file).expect("create sparse");
+        file_src.write_all(b"head").expect("write head");
+        file_src
+            .seek(SeekFrom::Start(len_sparse - 4))
+            .expect("seek tail");
+        file_src.write_all(b"tail").expect("write tail");
+        drop(file_src);
+
+        let stat_src = std::fs::metadata(&path_src_file).expect("src metadata");
+        let copy_options = CopyOptionsSpec {
+            should_preserve_sparse: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        assert_eq!(report.error_count(), 0);
+
+        let path_dst_file = dst.join("sparse.img");
+        let raw_dst = std::fs::read(&path_dst_file).expect("read dst");
+        assert_eq!(raw_dst.len() as u64, len_sparse);
+        assert_eq!(&raw_dst[..4], b"head");
+        assert_eq!(&raw_dst[raw_dst.len() - 4..], b"tail");
+        assert!(raw_dst[4..raw_dst.len() - 4].iter().all(|&b| b == 0));
+
+        // Only meaningful when the test filesystem itself supports holes.
+        if stat_src.blocks() * 512 < len_sparse {
+            let stat_dst = std::fs::metadata(&path_dst_file).expect("dst metadata");
+            assert!(stat_dst.blocks() * 512 < stat_dst.len());
+        }
+    }
+
+    #[test]
+    fn copy_tree_throughput_limit_throttles_copy() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        let payload = "x".repeat(64 * 1024);
+        write_text(&src.join("a.txt"), &payload);
+        write_text(&src.join("b.txt"), &payload);
+
+        let copy_options = CopyOptionsSpec {
+            throughput_max_bytes_per_sec: Some(512 * 1024),
+            workers_max: Some(2),
+            ..CopyOptionsSpec::default()
+        };
+        let instant_start = std::time::Instant::now();
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+        let secs_elapsed = instant_start.elapsed().as_secs_f64();
+
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(
+            std::fs::read_to_string(dst.join("a.txt")).expect("read"),
+            payload
+        );
+        assert_eq!(
+            std::fs::read_to_string(dst.join("b.txt")).expect("read"),
+            payload
+        );
+        // 128 KiB at 512 KiB/s from an empty bucket needs ~0.25s overall.
+        assert!(secs_elapsed >= 0.2, "elapsed={secs_elapsed}");
+    }
+
+    #[test]
+    fn copy_tree_skip_hidden_ignores_dot_entries() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join(".src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("keep.txt"), "keep");
+        write_text(&src.join(".env"), "secret");
+        write_text(&src.join(".cache").join("blob.bin"), "blob");
+        write_text(&src.join("sub").join(".hidden.txt"), "hidden");
+        write_text(&src.join("sub").join("visible.txt"), "visible");
+
+        let copy_options = CopyOptionsSpec {
+            should_skip_hidden: true,
+            patterns_include_files: Some(vec!["*".to_string()]),
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+        assert_eq!(report.error_count(), 0);
+        assert!(dst.join("keep.txt").exists());
+        assert!(dst.join("sub").join("visible.txt").exists());
+        assert!(!dst.join(".env").exists());
+        assert!(!dst.join(".cache").exists());
+        assert!(!dst.join("sub").join(".hidden.txt").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_rewrites_in_tree_absolute_symlinks_relative() {
+        use std::os::unix::fs::symlink;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("data.txt"), "data");
+        write_text(&src.join("sub").join("peer.txt"), "peer");
+        write_text(&tmp.path().join("outside.txt"), "outside");
+
+        let path_src_abs = std::fs::canonicalize(&src).expect("canonicalize src");
+        let path_outside_abs =
+            std::fs::canonicalize(tmp.path().join("outside.txt")).expect("canonicalize outside");
+        symlink(
+            path_src_abs.join("data.txt"),
+            src.join("sub").join("up.lnk"),
+        )
+        .expect("create up link");
+        symlink(
+            path_src_abs.join("sub").join("peer.txt"),
+            src.join("sub").join("peer.lnk"),
+        )
+        .expect("create peer link");
+        symlink(&path_outside_abs, src.join("outside.lnk")).expect("create outside link");
+
+        let copy_options = CopyOptionsSpec {
+            should_rewrite_symlinks_relative: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(
+            std::fs::read_link(dst.join("sub").join("up.lnk")).expect("read up link"),
+            PathBuf::from("../data.txt")
+        );
+        assert_eq!(
+            std::fs::read_link(dst.join("sub").join("peer.lnk")).expect("read peer link"),
+            PathBuf::from("peer.txt")
+        );
+        assert_eq!(
+            std::fs::read_link(dst.join("outside.lnk")).expect("read outside link"),
+            path_outside_abs
+        );
+        assert_eq!(
+            std::fs::read_to_string(dst.join("sub").join("up.lnk")).expect("read via link"),
+            "data"
+        );
+    }
+
+    #[test]
+    fn copy_tree_dry_run_collects_plan() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "a");
+        write_text(&src.join("sub").join("b.txt"), "b");
+        write_text(&dst.join("a.txt"), "old");
+
+        let copy_options = CopyOptionsSpec {
+            should_dry_run: true,
+            should_collect_plan: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(
+            report.plan,
+            vec![
+                CopyPlanEntry {
+                    src_path: src.join("sub"),
+                    dst_path: dst.join("sub"),
+                    action: CopyPlanAction::CreateDir,
+                },
+                CopyPlanEntry {
+                    src_path: src.join("sub").join("b.txt"),
+                    dst_path: dst.join("sub").join("b.txt"),
+                    action: CopyPlanAction::CopyFile,
+                },
+                CopyPlanEntry {
+                    src_path: src.join("a.txt"),
+                    dst_path: dst.join("a.txt"),
+                    action: CopyPlanAction::SkipConflict,
+                },
+            ]
+        );
+        assert!(!dst.join("sub").exists());
+        assert_eq!(
+            std::fs::read_to_string(dst.join("a.txt")).expect("read"),
+            "old"
+        );
+
+        let report_plain = copy_tree(
+            &src,
+            &dst,
+            CopyOptionsSpec {
+                should_dry_run: true,
+                ..CopyOptionsSpec::default()
+            },
+        )
+        .expect("copy tree");
+        assert!(report_plain.plan.is_empty());
+    }
+
+    #[test]
+    fn copy_tree_verify_counts_verified_files() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "alpha");
+        write_text(&src.join("sub").join("b.txt"), &"b".repeat(300_000));
+
+        let copy_options = CopyOptionsSpec {
+            should_verify: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_verified, 2);
+        assert_eq!(
+            std::fs::read_to_string(dst.join("sub").join("b.txt")).expect("read"),
+            "b".repeat(300_000)
+        );
+
+        let report_plain = copy_tree(
+            &src,
+            tmp.path().join("dst_plain"),
+            CopyOptionsSpec::default(),
+        )
+        .expect("copy tree");
+        assert_eq!(report_plain.cnt_verified, 0);
+    }
+
+    #[test]
+    fn copy_tree_manifest_lists_digests_of_copied_files() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        write_text(&src.join("a.txt"), "abc");
+        write_text(&src.join("sub").join("empty.txt"), "");
+
+        for (_hash_algorithm, _digest_abc, _digest_empty) in [
+            (
+                CopyHashAlgorithm::Sha256,
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ),
+            (
+                CopyHashAlgorithm::Blake3,
+                "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85",
+                "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262",
+            ),
+        ] {
+            let dst = tmp.path().join(format!("dst_{}", _hash_algorithm.as_str()));
+            let copy_options = CopyOptionsSpec {
+                manifest_algorithm: Some(_hash_algorithm),
+                workers_max: Some(2),
+                ..CopyOptionsSpec::default()
+            };
+            let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+            assert_eq!(report.error_count(), 0);
+            let mut manifest = report.manifest.clone();
+            manifest.sort();
+            assert_eq!(
+                manifest,
+                vec![
+                    (dst.join("a.txt"), _digest_abc.to_string()),
+                    (dst.join("sub").join("empty.txt"), _digest_empty.to_string()),
+                ]
+            );
+            assert_eq!(
+                std::fs::read_to_string(dst.join("a.txt")).expect("read"),
+                "abc"
+            );
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn copy_tree_manifest_covers_sparse_file_holes() {
+        use sha2::{Digest, Sha256};
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        std::fs::create_dir_all(&src).expect("create src");
+        let path_src_file = src.join("sparse.bin");
+        {
+            use std::os::unix::fs::FileExt;
+            let file = std::fs::File::create(&path_src_file).expect("create sparse");
+            file.set_len(1 << 20).expect("set len");
+            file.write_all_at(b"middle", 300_000).expect("write middle");
+        }
+
+        let copy_options = CopyOptionsSpec {
+            should_preserve_sparse: true,
+            manifest_algorithm: Some(CopyHashAlgorithm::Sha256),
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+        assert_eq!(report.error_count(), 0);
+        let digest_expected = format!(
+            "{:x}",
+            Sha256::digest(std::fs::read(&path_src_file).expect("read src"))
+        );
+        assert_eq!(
+            report.manifest,
+            vec![(dst.join("sparse.bin"), digest_expected)]
+        );
+    }
+
+    #[cfg(target_os = "linux")]
     #[test]
-    fn copy_tree_symlink_copy_mode() {
-        use std::os::unix::fs::symlink;
-
+    fn copy_tree_incremental_skips_unchanged_files() {
         let tmp = TestDir::new();
         let src = tmp.path().join("src");
         let dst = tmp.path().join("dst");
-        write_text(&src.join("root.txt"), "root");
-        symlink(src.join("root.txt"), src.join("link_root.txt")).expect("create symlink");
+        write_text(&src.join("same.txt"), "same");
+        write_text(&src.join("sub").join("edit.txt"), "old");
 
+        let report_first = copy_tree(&src, &dst, CopyOptionsSpec::default()).expect("copy tree");
+        assert_eq!(report_first.cnt_copied, 3);
+
+        write_text(&src.join("sub").join("edit.txt"), "changed");
         let copy_options = CopyOptionsSpec {
-            rule_symlink: CopySymlinkMode::CopySymlinks,
+            should_copy_incrementally: true,
+            rule_conflict_file: CopyFileConflictMode::Overwrite,
+            rule_conflict_dir: CopyDirectoryConflictMode::Merge,
             ..CopyOptionsSpec::default()
         };
-
         let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
         assert_eq!(report.error_count(), 0);
-        assert!(dst.join("link_root.txt").is_symlink());
+        assert_eq!(report.cnt_unchanged, 1);
+        assert_eq!(report.cnt_skipped, 0);
+        assert_eq!(
+            std::fs::read_to_string(dst.join("sub").join("edit.txt")).expect("read"),
+            "changed"
+        );
     }
 
     #[test]
-    fn copy_tree_include_regex_works() {
+    fn copy_tree_incremental_honours_mtime_tolerance() {
         let tmp = TestDir::new();
         let src = tmp.path().join("src");
-        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "same");
+        let mtime_src = filetime::FileTime::from_last_modification_time(
+            &std::fs::metadata(src.join("a.txt")).expect("metadata"),
+        );
 
-        write_text(&src.join("report_01.csv"), "ok");
-        write_text(&src.join("report_02.csv"), "ok");
-        write_text(&src.join("note.txt"), "txt");
+        for (_tolerance_ms, _cnt_unchanged) in [(0, 0), (1500, 1)] {
+            let dst = tmp.path().join(format!("dst_{_tolerance_ms}"));
+            copy_tree(&src, &dst, CopyOptionsSpec::default()).expect("copy tree");
+            let mtime_dst = filetime::FileTime::from_unix_time(
+                mtime_src.unix_seconds() + 1,
+                mtime_src.nanoseconds(),
+            );
+            filetime::set_file_mtime(dst.join("a.txt"), mtime_dst).expect("set mtime");
+
+            let copy_options = CopyOptionsSpec {
+                should_copy_incrementally: true,
+                mtime_tolerance_ms: _tolerance_ms,
+                rule_conflict_file: CopyFileConflictMode::Overwrite,
+                rule_conflict_dir: CopyDirectoryConflictMode::Merge,
+                ..CopyOptionsSpec::default()
+            };
+            let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+            assert_eq!(report.error_count(), 0);
+            assert_eq!(report.cnt_unchanged, _cnt_unchanged);
+        }
+    }
+
+    #[test]
+    fn copy_tree_fail_fast_returns_first_entry_error() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "a");
+        write_text(&src.join("b.txt"), "b");
+        write_text(&dst.join("a.txt"), "old");
 
         let copy_options = CopyOptionsSpec {
-            patterns_include_files: Some(vec![r"^report_\d+\.csv$".to_string()]),
-            rule_pattern: CopyPatternMode::Regex,
+            rule_conflict_file: CopyFileConflictMode::Error,
+            rule_conflict_dir: CopyDirectoryConflictMode::Merge,
             ..CopyOptionsSpec::default()
         };
+        let report = copy_tree(&src, &dst, copy_options.clone()).expect("copy tree");
+        assert_eq!(report.error_count(), 1);
 
-        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
-        assert_eq!(report.error_count(), 0);
-        assert!(dst.join("report_01.csv").exists());
-        assert!(dst.join("report_02.csv").exists());
-        assert!(!dst.join("note.txt").exists());
+        std::fs::remove_file(dst.join("b.txt")).expect("remove");
+        let copy_options = CopyOptionsSpec {
+            should_fail_fast: true,
+            ..copy_options
+        };
+        let err = copy_tree(&src, &dst, copy_options).expect_err("fail fast");
+        assert!(matches!(
+            err,
+            CopyTreeError::EntryFailed { ref path, .. } if *path == dst.join("a.txt")
+        ));
+        // Planning stopped at the error, so nothing was copied.
+        assert!(!dst.join("b.txt").exists());
+        assert_eq!(
+            std::fs::read_to_string(dst.join("a.txt")).expect("read"),
+            "old"
+        );
     }
 
     #[test]
-    fn copy_tree_include_exclude_regex_works() {
+    fn copy_tree_stops_scheduling_once_bytes_budget_is_reached() {
         let tmp = TestDir::new();
         let src = tmp.path().join("src");
         let dst = tmp.path().join("dst");
-
-        write_text(&src.join("report_keep.csv"), "ok");
-        write_text(&src.join("report_skip.csv"), "skip");
-        write_text(&src.join("other.csv"), "other");
+        for _name in ["a.bin", "b.bin", "c.bin"] {
+            write_text(&src.join(_name), &"x".repeat(100));
+        }
 
         let copy_options = CopyOptionsSpec {
-            patterns_include_files: Some(vec![r"^report_.*\.csv$".to_string()]),
-            patterns_exclude_files: Some(vec![r"^report_skip\.csv$".to_string()]),
-            rule_pattern: CopyPatternMode::Regex,
+            bytes_budget_max: Some(250),
             ..CopyOptionsSpec::default()
         };
-
         let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
         assert_eq!(report.error_count(), 0);
-        assert!(dst.join("report_keep.csv").exists());
-        assert!(!dst.join("report_skip.csv").exists());
-        assert!(!dst.join("other.csv").exists());
+        assert_eq!(report.cnt_copied, 2);
+        assert_eq!(report.cnt_skipped, 1);
+        assert_eq!(report.bytes_copied, 200);
+        assert_eq!(report.warning_count(), 1);
+        assert!(report.warnings[0].contains("Byte budget of 250 bytes reached"));
+        let cnt_dst_files = std::fs::read_dir(&dst).expect("read dst").count();
+        assert_eq!(cnt_dst_files, 2);
     }
 
     #[test]
-    fn copy_tree_invalid_regex_rejected() {
+    fn copy_tree_stops_traversal_once_files_max_is_exceeded() {
         let tmp = TestDir::new();
         let src = tmp.path().join("src");
         let dst = tmp.path().join("dst");
-        write_text(&src.join("a.txt"), "a");
+        for _name in ["a.txt", "b.txt", "c.txt", "d.txt", "e.txt"] {
+            write_text(&src.join(_name), _name);
+        }
 
         let copy_options = CopyOptionsSpec {
-            patterns_include_files: Some(vec!["(".to_string()]),
-            rule_pattern: CopyPatternMode::Regex,
+            files_max: Some(3),
             ..CopyOptionsSpec::default()
         };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
 
-        let err = copy_tree(&src, &dst, copy_options).expect_err("invalid regex must fail");
-        assert!(matches!(err, CopyTreeError::InvalidPattern(_)));
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 3);
+        assert_eq!(report.cnt_matched, 4);
+        assert_eq!(report.warning_count(), 1);
+        assert!(report.warnings[0].contains("File limit of 3 files reached"));
+        assert!(dst.join("c.txt").exists());
+        assert!(!dst.join("d.txt").exists());
     }
 
     #[test]
-    fn copy_tree_glob_char_class_works() {
+    fn copy_tree_normalizes_destination_filenames() {
         let tmp = TestDir::new();
         let src = tmp.path().join("src");
         let dst = tmp.path().join("dst");
-
-        write_text(&src.join("file1.txt"), "1");
-        write_text(&src.join("filea.txt"), "a");
+        write_text(&src.join("cafe\u{301}").join("e\u{301}.txt"), "nfd");
+        write_text(&src.join("plain.txt"), "plain");
 
         let copy_options = CopyOptionsSpec {
-            patterns_include_files: Some(vec!["file[0-9].txt".to_string()]),
-            rule_pattern: CopyPatternMode::Glob,
+            filename_normalization: Some(CopyUnicodeForm::Nfc),
             ..CopyOptionsSpec::default()
         };
-
         let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
         assert_eq!(report.error_count(), 0);
-        assert!(dst.join("file1.txt").exists());
-        assert!(!dst.join("filea.txt").exists());
+        assert_eq!(report.warning_count(), 2);
+        assert!(dst.join("caf\u{e9}").join("\u{e9}.txt").is_file());
+        assert!(!dst.join("cafe\u{301}").exists());
+        assert!(dst.join("plain.txt").is_file());
     }
 
     #[test]
-    fn copy_tree_invalid_glob_rejected() {
+    fn copy_tree_sanitizes_destination_names_for_windows() {
         let tmp = TestDir::new();
         let src = tmp.path().join("src");
         let dst = tmp.path().join("dst");
-        write_text(&src.join("a.txt"), "a");
+        write_text(&src.join("a:b").join("q?.txt"), "q");
+        write_text(&src.join("con.txt"), "con");
+        write_text(&src.join("trailing. "), "trailing");
+        write_text(&src.join("plain.txt"), "plain");
 
         let copy_options = CopyOptionsSpec {
-            patterns_include_files: Some(vec!["[".to_string()]),
-            rule_pattern: CopyPatternMode::Glob,
+            should_sanitize_dest_names: true,
             ..CopyOptionsSpec::default()
         };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
 
-        let err = copy_tree(&src, &dst, copy_options).expect_err("invalid glob must fail");
-        assert!(matches!(err, CopyTreeError::InvalidPattern(_)));
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.warning_count(), 4);
+        assert!(dst.join("a_b").join("q_.txt").is_file());
+        assert!(dst.join("con_.txt").is_file());
+        assert!(dst.join("trailing__").is_file());
+        assert!(dst.join("plain.txt").is_file());
+        assert!(!dst.join("a:b").exists());
     }
 
-    #[cfg(target_os = "linux")]
     #[test]
-    fn copy_tree_warns_hard_link() {
+    fn copy_files_copies_only_listed_paths() {
         let tmp = TestDir::new();
         let src = tmp.path().join("src");
         let dst = tmp.path().join("dst");
-        write_text(&src.join("base.txt"), "base");
-        std::fs::hard_link(src.join("base.txt"), src.join("alias.txt")).expect("hard link");
-
-        let report = copy_tree(&src, &dst, CopyOptionsSpec::default()).expect("copy tree");
-        assert_eq!(report.error_count(), 0);
-        assert!(
-            report
-                .warnings
-                .iter()
-                .any(|w| w.contains("Hard link detected"))
+        write_text(&src.join("a.txt"), "a");
+        write_text(&src.join("sub").join("b.txt"), "b");
+        write_text(&src.join("sub").join("unlisted.txt"), "u");
+        write_text(&tmp.path().join("outside.txt"), "o");
+
+        let rel_paths = [
+            PathBuf::from("a.txt"),
+            PathBuf::from("sub").join("b.txt"),
+            PathBuf::from("sub"),
+            PathBuf::from("missing.txt"),
+            PathBuf::from("..").join("outside.txt"),
+        ];
+        let report =
+            copy_files(&src, &dst, &rel_paths, CopyOptionsSpec::default()).expect("copy files");
+
+        assert_eq!(report.cnt_copied, 2);
+        assert_eq!(report.cnt_skipped, 1);
+        assert_eq!(report.warning_count(), 1);
+        let kinds: Vec<CopyErrorKind> = report.errors.iter().map(|_e| _e.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                CopyErrorKind::MetadataFailed,
+                CopyErrorKind::UnsafeDestination
+            ]
         );
+        assert!(dst.join("a.txt").is_file());
+        assert!(dst.join("sub").join("b.txt").is_file());
+        assert!(!dst.join("sub").join("unlisted.txt").exists());
+        assert!(!dst.join("outside.txt").exists());
     }
 
-    #[cfg(target_os = "linux")]
     #[test]
-    fn copy_tree_preserves_linux_metadata() {
-        use filetime::{FileTime, set_file_times};
-        use std::os::unix::fs::PermissionsExt;
-
+    fn copy_entry_copies_one_file_and_rejects_bad_sources() {
         let tmp = TestDir::new();
         let src = tmp.path().join("src");
         let dst = tmp.path().join("dst");
-        let path_src_file = src.join("meta.txt");
-        write_text(&path_src_file, "meta");
-
-        std::fs::set_permissions(&path_src_file, std::fs::Permissions::from_mode(0o640))
-            .expect("set permissions");
-        set_file_times(
-            &path_src_file,
-            FileTime::from_unix_time(1_700_000_010, 0),
-            FileTime::from_unix_time(1_700_000_020, 0),
-        )
-        .expect("set times");
-
-        let xattr_name = "user.axiomkit_fs_test";
-        let has_xattr = xattr::set(&path_src_file, xattr_name, b"meta_value").is_ok();
+        write_text(&src.join("a.txt"), "a");
+        write_text(&dst.join("a.txt"), "old");
 
-        let report = copy_tree(&src, &dst, CopyOptionsSpec::default()).expect("copy tree");
+        let copy_options = CopyOptionsSpec {
+            rule_conflict_file: CopyFileConflictMode::Overwrite,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_entry(src.join("a.txt"), &dst, copy_options).expect("copy entry");
         assert_eq!(report.error_count(), 0);
-
-        let path_dst_file = dst.join("meta.txt");
-        let stat_src = std::fs::metadata(&path_src_file).expect("src metadata");
-        let stat_dst = std::fs::metadata(&path_dst_file).expect("dst metadata");
-        assert_eq!(
-            stat_src.permissions().mode() & 0o777,
-            stat_dst.permissions().mode() & 0o777
-        );
+        assert_eq!(report.cnt_copied, 1);
         assert_eq!(
-            FileTime::from_last_modification_time(&stat_src),
-            FileTime::from_last_modification_time(&stat_dst)
+            std::fs::read_to_string(dst.join("a.txt")).expect("read"),
+            "a"
         );
 
-        if has_xattr {
-            let raw_value_dst = xattr::get(&path_dst_file, xattr_name)
-                .expect("get dst xattr")
-                .expect("xattr exists");
-            assert_eq!(raw_value_dst, b"meta_value");
-        }
+        let err = copy_entry(&src, &dst, CopyOptionsSpec::default()).expect_err("directory source");
+        assert!(matches!(err, CopyTreeError::SourceNotFile(_)));
+        let err = copy_entry(src.join("missing.txt"), &dst, CopyOptionsSpec::default())
+            .expect_err("missing source");
+        assert!(matches!(err, CopyTreeError::SourceNotFile(_)));
+        let err = copy_entry(src.join("a.txt"), &src, CopyOptionsSpec::default())
+            .expect_err("same directory");
+        assert!(matches!(
+            err,
+            CopyTreeError::SourceDestinationOverlap { .. }
+        ));
     }
 
     #[test]
@@ -969,6 +3517,23 @@ mod tests {
         assert!(dst.join("a.txt").exists());
     }
 
+    #[test]
+    fn plan_copy_rejects_file_destination_root_without_writing() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "a");
+        write_text(&dst, "not a directory");
+
+        let err = plan_copy(&src, &dst, CopyOptionsSpec::default())
+            .expect_err("file destination root must fail");
+        assert!(matches!(err, CopyTreeError::DestinationInitFailed { .. }));
+        assert_eq!(
+            std::fs::read_to_string(&dst).expect("read"),
+            "not a directory"
+        );
+    }
+
     #[cfg(unix)]
     #[test]
     fn copy_tree_rejects_symlink_destination_root() {
@@ -1034,9 +3599,137 @@ mod tests {
         let report = copy_tree(&src, &dst, copy_options).expect("copy tree returns report");
 
         assert!(report.error_count() >= 1);
+        assert!(
+            report
+                .errors
+                .iter()
+                .all(|e| e.kind == CopyErrorKind::UnsafeDestination)
+        );
         assert!(!outside.join("out.txt").exists());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_replaces_existing_destination_symlink_when_requested() {
+        use std::os::unix::fs::symlink;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        let outside = tmp.path().join("outside");
+
+        write_text(&src.join("a.txt"), "safe");
+        write_text(&outside.join("out.txt"), "outside");
+        std::fs::create_dir_all(&dst).expect("create dst");
+        symlink(outside.join("out.txt"), dst.join("a.txt")).expect("create dst symlink");
+
+        let copy_options = CopyOptionsSpec {
+            rule_conflict_file: CopyFileConflictMode::Overwrite,
+            rule_conflict_symlink_dst: CopyDstSymlinkConflictMode::Replace,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 1);
+        assert!(!dst.join("a.txt").is_symlink());
+        assert_eq!(
+            std::fs::read_to_string(dst.join("a.txt")).expect("read"),
+            "safe"
+        );
+        assert_eq!(
+            std::fs::read_to_string(outside.join("out.txt")).expect("read"),
+            "outside"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_classifies_entry_errors() {
+        use std::os::unix::fs::symlink;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "new");
+        write_text(&dst.join("a.txt"), "old");
+        symlink(src.join("missing.txt"), src.join("broken.lnk")).expect("create broken link");
+
+        let copy_options = CopyOptionsSpec {
+            rule_conflict_file: CopyFileConflictMode::Error,
+            rule_symlink: CopySymlinkMode::Dereference,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+        let mut kinds: Vec<(PathBuf, CopyErrorKind)> = report
+            .errors
+            .iter()
+            .map(|e| (e.path.clone(), e.kind))
+            .collect();
+        kinds.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            kinds,
+            vec![
+                (dst.join("a.txt"), CopyErrorKind::ConflictError),
+                (src.join("broken.lnk"), CopyErrorKind::BrokenSymlink),
+            ]
+        );
+        assert_eq!(CopyErrorKind::BrokenSymlink.as_str(), "broken_symlink");
+
+        let error_broken = report
+            .errors
+            .iter()
+            .find(|e| e.kind == CopyErrorKind::BrokenSymlink)
+            .expect("broken symlink error");
+        assert_eq!(error_broken.source_path, Some(src.join("broken.lnk")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_force_overwrites_readonly_destination() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "new");
+        write_text(&dst.join("a.txt"), "old");
+        std::fs::set_permissions(dst.join("a.txt"), std::fs::Permissions::from_mode(0o444))
+            .expect("chmod dst");
+
+        let copy_options = CopyOptionsSpec {
+            rule_conflict_file: CopyFileConflictMode::Overwrite,
+            should_force_overwrite_readonly: true,
+            ..CopyOptionsSpec::default()
+        };
+        let report = copy_tree(&src, &dst, copy_options).expect("copy tree");
+
+        assert_eq!(report.error_count(), 0);
+        assert_eq!(report.cnt_copied, 1);
+        assert_eq!(
+            std::fs::read_to_string(dst.join("a.txt")).expect("read"),
+            "new"
+        );
+    }
+
+    #[test]
+    fn copy_tree_file_copy_errors_carry_source_path() {
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        let dst = tmp.path().join("dst");
+        write_text(&src.join("a.txt"), "a");
+
+        let mut copy_plan = plan_copy(&src, &dst, CopyOptionsSpec::default()).expect("plan copy");
+        copy_plan.entries[0].src_path = src.join("missing.txt");
+        let report = execute_plan(copy_plan);
+
+        assert_eq!(report.error_count(), 1);
+        assert_eq!(report.errors[0].kind, CopyErrorKind::IoCopyFailed);
+        assert_eq!(report.errors[0].path, dst.join("a.txt"));
+        assert_eq!(report.errors[0].source_path, Some(src.join("missing.txt")));
+    }
+
     #[cfg(unix)]
     #[test]
     fn copy_tree_skips_special_target_when_dereference_symlink() {
@@ -1066,6 +3759,51 @@ mod tests {
         assert!(dst.join("normal.txt").exists());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn copy_tree_applies_special_file_rule_to_fifos() {
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+        use std::os::unix::fs::FileTypeExt;
+
+        let tmp = TestDir::new();
+        let src = tmp.path().join("src");
+        write_text(&src.join("normal.txt"), "ok");
+        let path_fifo = CString::new(src.join("pipe").as_os_str().as_bytes()).expect("c path");
+        assert_eq!(unsafe { libc::mkfifo(path_fifo.as_ptr(), 0o644) }, 0);
+
+        let run = |name: &str, rule_special_file: CopySpecialFileMode| {
+            let dst = tmp.path().join(name);
+            let copy_options = CopyOptionsSpec {
+                rule_special_file,
+                ..CopyOptionsSpec::default()
+            };
+            (copy_tree(&src, &dst, copy_options).expect("copy tree"), dst)
+        };
+
+        let (report_skip, dst_skip) = run("dst_skip", CopySpecialFileMode::Skip);
+        assert_eq!(report_skip.error_count(), 0);
+        assert_eq!(report_skip.cnt_skipped, 1);
+        assert!(
+            report_skip
+                .warnings
+                .iter()
+                .any(|w| w.contains("Special file skipped"))
+        );
+        assert!(!dst_skip.join("pipe").exists());
+
+        let (report_error, dst_error) = run("dst_error", CopySpecialFileMode::Error);
+        assert_eq!(report_error.error_count(), 1);
+        assert_eq!(report_error.errors[0].kind, CopyErrorKind::SpecialFile);
+        assert!(dst_error.join("normal.txt").exists());
+
+        let (report_copy, dst_copy) = run("dst_copy", CopySpecialFileMode::AttemptCopy);
+        assert_eq!(report_copy.error_count(), 0);
+        assert_eq!(report_copy.cnt_copied, 2);
+        let meta_fifo = std::fs::symlink_metadata(dst_copy.join("pipe")).expect("stat fifo");
+        assert!(meta_fifo.file_type().is_fifo());
+    }
+
     #[test]
     fn copy_tree_fuzz_like_randomized_inputs_no_panic() {
         fn derive_name(seed: u64, idx: usize) -> String {
@@ -2,7 +2,8 @@
 //! Rust-side filesystem copy engine.
 //!
 //! Architecture mirrors Python `io/fs` modules:
-//! - `copy`   : traversal and copy orchestration
+//! - `copy`   : traversal and copy orchestration (`copy_tree`, `copy_entry`,
+//!   `copy_files`, `plan_copy`/`execute_plan`)
 //! - `spec`   : enums/options/errors
 //! - `report` : run-time report model
 //! - `util`   : shared helper functions
@@ -12,9 +13,11 @@ pub mod report;
 pub mod spec;
 mod util;
 
-pub use copy::copy_tree;
-pub use report::CopyReport;
+pub use copy::{CopyPlan, copy_entry, copy_files, copy_tree, execute_plan, plan_copy};
+pub use report::{CopyReport, SharedCopyReportBuilder};
 pub use spec::{
-    CopyDepthLimitMode, CopyDirectoryConflictMode, CopyErrorRecord, CopyFileConflictMode,
-    CopyOptionsSpec, CopyPatternMode, CopySymlinkMode, CopyTreeError,
+    CopyDepthLimitMode, CopyDirectoryConflictMode, CopyDstSymlinkConflictMode, CopyErrorKind,
+    CopyErrorRecord, CopyFileConflictMode, CopyFileFilter, CopyHashAlgorithm, CopyOptionsSpec,
+    CopyOptionsSpecBuilder, CopyPatternMode, CopyPlanAction, CopyPlanEntry, CopySpecialFileMode,
+    CopySymlinkMode, CopyTreeError, CopyUnicodeForm,
 };
@@ -2,11 +2,17 @@
 
 use std::collections::BTreeMap;
 use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
 
-use crate::spec::CopyErrorRecord;
+use crate::spec::{CopyErrorKind, CopyErrorRecord, CopyPlanEntry};
 
 /// Aggregate counters and diagnostics for one `copy_tree` run.
+///
+/// With the `serde` feature, the report (paths as strings) implements
+/// `Serialize`/`Deserialize` and [`CopyReport::to_json`] is available.
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CopyReport {
     /// Number of scanned entries that matched filters.
     pub cnt_matched: u64,
@@ -16,10 +22,35 @@ pub struct CopyReport {
     pub cnt_copied: u64,
     /// Number of entries skipped by strategy or dry-run.
     pub cnt_skipped: u64,
+    /// Number of copied files whose content hash was verified.
+    pub cnt_verified: u64,
+    /// Number of files skipped by incremental mode because the destination
+    /// already matched (length and mtime).
+    pub cnt_unchanged: u64,
+    /// Number of files hard-linked to an identical earlier destination file
+    /// by `should_dedup_destination` instead of being copied.
+    pub cnt_linked: u64,
+    /// Number of symlinks recreated by `CopySymlinks`; these are also counted
+    /// in `cnt_copied`.
+    pub cnt_symlinks: u64,
+    /// Total source bytes written by successful file copies.
+    pub bytes_copied: u64,
     /// Non-fatal warnings collected during traversal/copy.
     pub warnings: Vec<String>,
     /// Per-entry failures.
     pub errors: Vec<CopyErrorRecord>,
+    /// Planned actions of a dry run with `should_collect_plan`; empty otherwise.
+    pub plan: Vec<CopyPlanEntry>,
+    /// `(destination path, hex digest)` of copied files when
+    /// `manifest_algorithm` is set; empty otherwise.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::util::serde_path::serialize_pairs")
+    )]
+    pub manifest: Vec<(PathBuf, String)>,
+    /// Lowercased file extension (`""` for none) to `(count, bytes)` of copied
+    /// files when `should_collect_ext_stats` is set; empty otherwise.
+    pub stats_by_ext: BTreeMap<String, (u64, u64)>,
 }
 
 impl CopyReport {
@@ -40,11 +71,49 @@ impl CopyReport {
         counts.insert("cnt_scanned".to_string(), self.cnt_scanned);
         counts.insert("cnt_copied".to_string(), self.cnt_copied);
         counts.insert("cnt_skipped".to_string(), self.cnt_skipped);
+        counts.insert("cnt_verified".to_string(), self.cnt_verified);
+        counts.insert("cnt_unchanged".to_string(), self.cnt_unchanged);
+        counts.insert("cnt_linked".to_string(), self.cnt_linked);
+        counts.insert("cnt_symlinks".to_string(), self.cnt_symlinks);
+        counts.insert("bytes_copied".to_string(), self.bytes_copied);
         counts.insert("cnt_errors".to_string(), self.error_count() as u64);
         counts.insert("cnt_warnings".to_string(), self.warning_count() as u64);
         counts
     }
 
+    /// Compact JSON form of the whole report.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        // Paths are written lossily and map keys are strings, so this cannot fail.
+        serde_json::to_string(self).expect("CopyReport serializes to JSON")
+    }
+
+    /// Combine two reports: counters and per-extension stats are summed;
+    /// warnings, errors, plan and manifest entries of `other` are appended
+    /// after those of `self`.
+    pub fn merge(self, other: CopyReport) -> CopyReport {
+        let mut report_builder = CopyReportBuilder { report: self };
+        report_builder.merge_from(&other);
+        report_builder.build()
+    }
+
+    fn add_counters_from(&mut self, other: &CopyReport) {
+        self.cnt_matched += other.cnt_matched;
+        self.cnt_scanned += other.cnt_scanned;
+        self.cnt_copied += other.cnt_copied;
+        self.cnt_skipped += other.cnt_skipped;
+        self.cnt_verified += other.cnt_verified;
+        self.cnt_unchanged += other.cnt_unchanged;
+        self.cnt_linked += other.cnt_linked;
+        self.cnt_symlinks += other.cnt_symlinks;
+        self.bytes_copied += other.bytes_copied;
+        for (_ext, &(_count, _bytes)) in &other.stats_by_ext {
+            let stats = self.stats_by_ext.entry(_ext.clone()).or_default();
+            stats.0 += _count;
+            stats.1 += _bytes;
+        }
+    }
+
     /// Human-readable one-line summary.
     pub fn format(&self, prefix: &str) -> String {
         let counts = self.to_dict();
@@ -83,6 +152,11 @@ impl CopyReportBuilder {
                 "cnt_scanned" => self.report.cnt_scanned += value,
                 "cnt_copied" => self.report.cnt_copied += value,
                 "cnt_skipped" => self.report.cnt_skipped += value,
+                "cnt_verified" => self.report.cnt_verified += value,
+                "cnt_unchanged" => self.report.cnt_unchanged += value,
+                "cnt_linked" => self.report.cnt_linked += value,
+                "cnt_symlinks" => self.report.cnt_symlinks += value,
+                "bytes_copied" => self.report.bytes_copied += value,
                 _ => {}
             }
         }
@@ -108,14 +182,104 @@ impl CopyReportBuilder {
         self.report.cnt_skipped += 1;
     }
 
+    /// Increment verified count by one.
+    pub(crate) fn add_verified(&mut self) {
+        self.report.cnt_verified += 1;
+    }
+
+    /// Increment unchanged count by one.
+    pub(crate) fn add_unchanged(&mut self) {
+        self.report.cnt_unchanged += 1;
+    }
+
+    /// Increment linked count by one.
+    pub(crate) fn add_linked(&mut self) {
+        self.report.cnt_linked += 1;
+    }
+
+    /// Increment symlink count by one.
+    pub(crate) fn add_symlink(&mut self) {
+        self.report.cnt_symlinks += 1;
+    }
+
+    /// Add bytes written by one successful file copy.
+    pub(crate) fn add_bytes_copied(&mut self, bytes_copied: u64) {
+        self.report.bytes_copied += bytes_copied;
+    }
+
     /// Add warning message.
     pub(crate) fn add_warning(&mut self, warning: String) {
+        #[cfg(feature = "tracing")]
+        tracing::warn!("{warning}");
         self.report.warnings.push(warning);
     }
 
     /// Add one path-scoped error.
-    pub(crate) fn add_error(&mut self, path: std::path::PathBuf, exception: String) {
-        self.report.errors.push(CopyErrorRecord { path, exception });
+    pub(crate) fn add_error(&mut self, path: PathBuf, kind: CopyErrorKind, exception: String) {
+        self.push_error(path, None, kind, exception);
+    }
+
+    /// Add one path-scoped error caused by the source entry `source_path`.
+    pub(crate) fn add_source_error(
+        &mut self,
+        path: PathBuf,
+        source_path: PathBuf,
+        kind: CopyErrorKind,
+        exception: String,
+    ) {
+        self.push_error(path, Some(source_path), kind, exception);
+    }
+
+    fn push_error(
+        &mut self,
+        path: PathBuf,
+        source_path: Option<PathBuf>,
+        kind: CopyErrorKind,
+        exception: String,
+    ) {
+        #[cfg(feature = "tracing")]
+        tracing::error!(path = %path.display(), kind = kind.as_str(), "{exception}");
+        self.report.errors.push(CopyErrorRecord {
+            path,
+            source_path,
+            kind,
+            exception,
+        });
+    }
+
+    /// Whether any error has been recorded so far.
+    pub(crate) fn has_errors(&self) -> bool {
+        !self.report.errors.is_empty()
+    }
+
+    /// Add one dry-run plan entry.
+    pub(crate) fn add_plan_entry(&mut self, plan_entry: CopyPlanEntry) {
+        self.report.plan.push(plan_entry);
+    }
+
+    /// Add one manifest entry.
+    pub(crate) fn add_manifest_entry(&mut self, path: PathBuf, digest: String) {
+        self.report.manifest.push((path, digest));
+    }
+
+    /// Count one copied file of `bytes_copied` bytes under its extension.
+    pub(crate) fn add_ext_stats(&mut self, path: &Path, bytes_copied: u64) {
+        let ext = path
+            .extension()
+            .map(|_ext| _ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        let stats = self.report.stats_by_ext.entry(ext).or_default();
+        stats.0 += 1;
+        stats.1 += bytes_copied;
+    }
+
+    /// Add everything recorded in `other`, as [`CopyReport::merge`] does.
+    pub(crate) fn merge_from(&mut self, other: &CopyReport) {
+        self.report.add_counters_from(other);
+        self.report.warnings.extend_from_slice(&other.warnings);
+        self.report.errors.extend_from_slice(&other.errors);
+        self.report.plan.extend_from_slice(&other.plan);
+        self.report.manifest.extend_from_slice(&other.manifest);
     }
 
     /// Finalize builder into immutable report.
@@ -124,8 +288,76 @@ impl CopyReportBuilder {
     }
 }
 
+/// Thread-safe report accumulator: cloned handles share one report behind a
+/// mutex, so concurrent workers (e.g. parallel `copy_tree` runs or directory
+/// scanners) can record counts, warnings and errors without data races.
+///
+/// Every call takes the lock; single-threaded code keeps using the report a
+/// run returns. Records appear in lock-acquisition order.
+#[derive(Debug, Default, Clone)]
+pub struct SharedCopyReportBuilder(Arc<Mutex<CopyReportBuilder>>);
+
+impl SharedCopyReportBuilder {
+    /// Empty shared report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CopyReportBuilder> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Increment matched count by one.
+    pub fn add_matched(&self) {
+        self.lock().add_matched();
+    }
+
+    /// Increment scanned count by one.
+    pub fn add_scanned(&self) {
+        self.lock().add_scanned();
+    }
+
+    /// Increment copied count by one.
+    pub fn add_copied(&self) {
+        self.lock().add_copied();
+    }
+
+    /// Increment skipped count by one.
+    pub fn add_skipped(&self) {
+        self.lock().add_skipped();
+    }
+
+    /// Add bytes written by one successful file copy.
+    pub fn add_bytes_copied(&self, bytes_copied: u64) {
+        self.lock().add_bytes_copied(bytes_copied);
+    }
+
+    /// Add warning message.
+    pub fn add_warning(&self, warning: String) {
+        self.lock().add_warning(warning);
+    }
+
+    /// Add one path-scoped error.
+    pub fn add_error(&self, path: PathBuf, kind: CopyErrorKind, exception: String) {
+        self.lock().add_error(path, kind, exception);
+    }
+
+    /// Add everything recorded in `other`, as [`CopyReport::merge`] does.
+    pub fn merge_from(&self, other: &CopyReport) {
+        self.lock().merge_from(other);
+    }
+
+    /// Copy of the report accumulated so far.
+    pub fn snapshot(&self) -> CopyReport {
+        self.lock().clone().build()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+    use std::path::Path;
+
     use super::CopyReport;
 
     #[test]
@@ -135,8 +367,16 @@ mod tests {
             cnt_scanned: 8,
             cnt_copied: 3,
             cnt_skipped: 2,
+            cnt_verified: 0,
+            cnt_unchanged: 0,
+            cnt_linked: 0,
+            cnt_symlinks: 0,
+            bytes_copied: 0,
             warnings: vec!["w".to_string()],
             errors: vec![],
+            plan: vec![],
+            manifest: vec![],
+            stats_by_ext: BTreeMap::new(),
         };
 
         let counts = report.to_dict();
@@ -144,6 +384,11 @@ mod tests {
         assert_eq!(counts["cnt_scanned"], 8);
         assert_eq!(counts["cnt_copied"], 3);
         assert_eq!(counts["cnt_skipped"], 2);
+        assert_eq!(counts["cnt_verified"], 0);
+        assert_eq!(counts["cnt_unchanged"], 0);
+        assert_eq!(counts["cnt_linked"], 0);
+        assert_eq!(counts["cnt_symlinks"], 0);
+        assert_eq!(counts["bytes_copied"], 0);
         assert_eq!(counts["cnt_errors"], 0);
         assert_eq!(counts["cnt_warnings"], 1);
 
@@ -154,4 +399,112 @@ mod tests {
         );
         assert_eq!(report.to_string(), txt);
     }
+
+    #[test]
+    fn report_copy_merge_sums_counters_and_appends_records() {
+        use std::path::PathBuf;
+
+        use super::CopyReportBuilder;
+        use crate::spec::CopyErrorKind;
+
+        let mut builder_first = CopyReportBuilder::default();
+        builder_first.add_copied();
+        builder_first.add_bytes_copied(10);
+        builder_first.add_ext_stats(Path::new("a.txt"), 10);
+        builder_first.add_warning("w1".to_string());
+        builder_first.add_error(
+            PathBuf::from("e1"),
+            CopyErrorKind::IoCopyFailed,
+            "x".to_string(),
+        );
+        let mut builder_second = CopyReportBuilder::default();
+        builder_second.add_copied();
+        builder_second.add_skipped();
+        builder_second.add_bytes_copied(5);
+        builder_second.add_ext_stats(Path::new("b.TXT"), 5);
+        builder_second.add_warning("w2".to_string());
+        builder_second.add_error(
+            PathBuf::from("e2"),
+            CopyErrorKind::ConflictError,
+            "y".to_string(),
+        );
+        let report_second = builder_second.build();
+
+        let report = builder_first.clone().build().merge(report_second.clone());
+        assert_eq!(report.cnt_copied, 2);
+        assert_eq!(report.cnt_skipped, 1);
+        assert_eq!(report.bytes_copied, 15);
+        assert_eq!(report.stats_by_ext["txt"], (2, 15));
+        assert_eq!(report.warnings, ["w1", "w2"]);
+        let paths_error: Vec<_> = report.errors.iter().map(|_e| _e.path.clone()).collect();
+        assert_eq!(paths_error, [PathBuf::from("e1"), PathBuf::from("e2")]);
+
+        builder_first.merge_from(&report_second);
+        let report_merged = builder_first.build();
+        assert_eq!(report_merged.to_dict(), report.to_dict());
+        assert_eq!(report_merged.errors, report.errors);
+    }
+
+    #[test]
+    fn shared_report_builder_collects_from_threads() {
+        use std::path::PathBuf;
+
+        use super::SharedCopyReportBuilder;
+        use crate::spec::CopyErrorKind;
+
+        let report_builder = SharedCopyReportBuilder::new();
+        std::thread::scope(|_scope| {
+            for _idx in 0..4 {
+                let report_builder = report_builder.clone();
+                _scope.spawn(move || {
+                    for _ in 0..100 {
+                        report_builder.add_scanned();
+                        report_builder.add_copied();
+                    }
+                    report_builder.add_warning(format!("w{_idx}"));
+                    report_builder.add_error(
+                        PathBuf::from(format!("e{_idx}")),
+                        CopyErrorKind::IoCopyFailed,
+                        "x".to_string(),
+                    );
+                });
+            }
+        });
+
+        let report = report_builder.snapshot();
+        assert_eq!(report.cnt_scanned, 400);
+        assert_eq!(report.cnt_copied, 400);
+        assert_eq!(report.warning_count(), 4);
+        assert_eq!(report.error_count(), 4);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn report_copy_round_trips_through_json() {
+        use std::path::PathBuf;
+
+        use crate::spec::{CopyErrorKind, CopyErrorRecord};
+
+        let report = CopyReport {
+            cnt_copied: 1,
+            errors: vec![CopyErrorRecord {
+                path: PathBuf::from("dst/a.txt"),
+                source_path: Some(PathBuf::from("src/a.txt")),
+                kind: CopyErrorKind::IoCopyFailed,
+                exception: "boom".to_string(),
+            }],
+            manifest: vec![(PathBuf::from("dst/b.txt"), "00ff".to_string())],
+            ..CopyReport::default()
+        };
+
+        let json = report.to_json();
+        assert!(json.contains(r#""path":"dst/a.txt""#));
+        assert!(json.contains(r#""kind":"io_copy_failed""#));
+        assert!(json.contains(r#""manifest":[["dst/b.txt","00ff"]]"#));
+
+        let report_back: CopyReport = serde_json::from_str(&json).expect("deserialize");
+        assert_eq!(report_back.cnt_copied, 1);
+        assert_eq!(report_back.errors, report.errors);
+        assert_eq!(report_back.manifest, report.manifest);
+    }
 }
@@ -1,7 +1,10 @@
 //! Copy specification models and top-level error types.
 
 use std::fmt;
-use std::path::PathBuf;
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
 
 ////////////////////////////////////////////////////////////////////////////////
 // #region EnumsInit
@@ -17,6 +20,18 @@ pub enum CopySymlinkMode {
     SkipSymlinks,
 }
 
+/// Handling of FIFOs, sockets and device files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopySpecialFileMode {
+    /// Record a warning and skip the entry.
+    Skip,
+    /// Record a `SpecialFile` error for the entry.
+    Error,
+    /// Unix only: recreate FIFOs and device nodes with `mknod` (best effort;
+    /// device nodes usually need root). Sockets are still skipped.
+    AttemptCopy,
+}
+
 /// Existing destination file conflict policy.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CopyFileConflictMode {
@@ -39,15 +54,152 @@ pub enum CopyDirectoryConflictMode {
     Error,
 }
 
+/// Policy for an existing symlink at a destination entry path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyDstSymlinkConflictMode {
+    /// Record an `UnsafeDestination` error and leave the symlink untouched.
+    Error,
+    /// Remove the symlink (never its target) and write the entry as if the
+    /// destination were absent. Symlinks among parent components still
+    /// error.
+    Replace,
+}
+
+/// Unicode normalization form for destination file names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyUnicodeForm {
+    /// Canonical composition, as typically produced on Linux and Windows.
+    Nfc,
+    /// Canonical decomposition, as stored by older macOS filesystems (HFS+).
+    Nfd,
+}
+
+impl CopyUnicodeForm {
+    /// Stable lowercase name, as exposed to Python.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Nfc => "nfc",
+            Self::Nfd => "nfd",
+        }
+    }
+}
+
+/// Action a copy plan (or dry run) takes for one entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CopyPlanAction {
+    /// Copy one regular file (or a dereferenced symlink target).
+    CopyFile,
+    /// Create one destination directory.
+    CreateDir,
+    /// Create one symbolic link at destination.
+    CreateSymlink,
+    /// Recreate one FIFO or device file (`rule_special_file=AttemptCopy`).
+    CreateSpecialFile,
+    /// Leave an existing destination entry untouched (`Skip` conflict rule).
+    SkipConflict,
+}
+
+impl CopyPlanAction {
+    /// Stable snake_case name, as exposed to Python.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::CopyFile => "copy_file",
+            Self::CreateDir => "create_dir",
+            Self::CreateSymlink => "create_symlink",
+            Self::CreateSpecialFile => "create_special_file",
+            Self::SkipConflict => "skip_conflict",
+        }
+    }
+}
+
+/// Failure class of one per-entry copy error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CopyErrorKind {
+    /// Reading, writing or finalizing file data failed.
+    IoCopyFailed,
+    /// Destination path escapes the destination root or crosses a symlink.
+    UnsafeDestination,
+    /// Symlink target does not exist.
+    BrokenSymlink,
+    /// Destination content hash differs from the source after copy.
+    VerifyMismatch,
+    /// Destination exists and the conflict rule is `Error`, or the existing
+    /// entry has the wrong type.
+    ConflictError,
+    /// Stat of a source entry failed.
+    MetadataFailed,
+    /// Creating a destination directory failed.
+    CreateDirFailed,
+    /// Reading or creating a symbolic link failed.
+    SymlinkFailed,
+    /// Source is a FIFO, socket or device file that was not copied.
+    SpecialFile,
+}
+
+impl CopyErrorKind {
+    /// Stable snake_case name, as exposed to Python.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::IoCopyFailed => "io_copy_failed",
+            Self::UnsafeDestination => "unsafe_destination",
+            Self::BrokenSymlink => "broken_symlink",
+            Self::VerifyMismatch => "verify_mismatch",
+            Self::ConflictError => "conflict_error",
+            Self::MetadataFailed => "metadata_failed",
+            Self::CreateDirFailed => "create_dir_failed",
+            Self::SymlinkFailed => "symlink_failed",
+            Self::SpecialFile => "special_file",
+        }
+    }
+}
+
+/// Digest algorithm for the copy manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyHashAlgorithm {
+    /// SHA-256, widely supported by external verification tools.
+    Sha256,
+    /// BLAKE3, considerably faster on large files.
+    Blake3,
+}
+
+impl CopyHashAlgorithm {
+    /// Stable lowercase name, as exposed to Python.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Blake3 => "blake3",
+        }
+    }
+}
+
 /// Pattern matching mode for include/exclude lists.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CopyPatternMode {
     /// Shell-like wildcards (`*`, `?`, character classes).
+    ///
+    /// `*` and `?` never match `/`; only `**` spans directory boundaries.
+    /// This only matters with `should_match_full_path`, since basenames
+    /// contain no separator.
     Glob,
     /// Regular expression pattern.
     Regex,
     /// Exact string match.
     Literal,
+    /// `.gitignore`-style rules: `!` negation, trailing-`/` directory-only
+    /// rules, and root anchoring for patterns containing `/`.
+    ///
+    /// Always matched against the path relative to the source root.
+    Gitignore,
 }
 
 /// Depth filter mode.
@@ -66,22 +218,38 @@ pub enum CopyDepthLimitMode {
 /// Input options for `copy_tree`.
 #[derive(Debug, Clone)]
 pub struct CopyOptionsSpec {
-    /// Include patterns applied to file basename.
+    /// Include patterns applied to file basename (or relative path).
     pub patterns_include_files: Option<Vec<String>>,
-    /// Exclude patterns applied to file basename.
+    /// Exclude patterns applied to file basename (or relative path).
     pub patterns_exclude_files: Option<Vec<String>>,
-    /// Include patterns applied to directory basename.
+    /// Include patterns applied to directory basename (or relative path).
     pub patterns_include_dirs: Option<Vec<String>>,
-    /// Exclude patterns applied to directory basename.
+    /// Exclude patterns applied to directory basename (or relative path).
     pub patterns_exclude_dirs: Option<Vec<String>>,
     /// Pattern interpretation mode.
     pub rule_pattern: CopyPatternMode,
+    /// Match patterns against the `/`-separated path relative to the source
+    /// root instead of the entry basename.
+    ///
+    /// In glob mode, use `**` to match across directories (`logs/**/*.tmp`);
+    /// a single `*` stays within one path component.
+    pub should_match_full_path: bool,
     /// Conflict behavior for destination files.
     pub rule_conflict_file: CopyFileConflictMode,
+    /// Before overwriting an existing read-only destination file, clear its
+    /// read-only attribute (Windows) or add owner write permission (Unix).
+    ///
+    /// Nothing is restored afterwards; the new file receives source
+    /// permissions. A failure to adjust permissions is recorded as an error
+    /// for that file only.
+    pub should_force_overwrite_readonly: bool,
     /// Conflict behavior for destination directories.
     pub rule_conflict_dir: CopyDirectoryConflictMode,
     /// Symlink handling behavior.
     pub rule_symlink: CopySymlinkMode,
+    /// Maximum number of directory symlinks followed along one path under
+    /// `Dereference`; deeper symlinked directories are skipped with a warning.
+    pub symlink_follow_max: Option<usize>,
     /// Optional maximum/target depth (depends on `rule_depth_limit`).
     pub depth_limit: Option<usize>,
     /// Depth evaluation mode.
@@ -92,6 +260,148 @@ pub struct CopyOptionsSpec {
     pub should_keep_tree: bool,
     /// Do not mutate filesystem; record what would happen.
     pub should_dry_run: bool,
+    /// Linux only: apply the source uid/gid to copied files. Without the
+    /// required privilege this records a warning instead of an error.
+    pub should_preserve_ownership: bool,
+    /// After copying, apply each source directory's permissions and times to
+    /// the directories created for it (deepest first). Failures are warnings.
+    pub should_preserve_dir_metadata: bool,
+    /// Copy each file into a unique sibling temp file, apply metadata, then
+    /// rename it over the destination, so a crash never leaves a truncated file.
+    pub should_copy_atomically: bool,
+    /// `fsync` each copied file (and its parent directory on Unix) before it
+    /// is counted as copied.
+    ///
+    /// Durable but expensive: every file waits for the device to acknowledge
+    /// the write, which can cut throughput by an order of magnitude on
+    /// spinning disks and network filesystems. Forces the streaming copy
+    /// path so the handle that wrote the data is the one synced.
+    pub should_fsync: bool,
+    /// Stream file bytes through buffers of this size instead of `fs::copy`.
+    ///
+    /// Useful to tune throughput on slow network mounts; `None` keeps the
+    /// platform fast path (which may use `copy_file_range`/reflinks).
+    pub copy_buffer_bytes: Option<usize>,
+    /// Linux only: keep holes of sparse files unallocated at the destination
+    /// (via `SEEK_DATA`/`SEEK_HOLE`). Falls back to a regular copy when the
+    /// filesystem cannot report holes.
+    pub should_preserve_sparse: bool,
+    /// Cap aggregate copy throughput across all workers, in bytes per second.
+    ///
+    /// Throttling forces the streaming copy path (chunks of
+    /// `copy_buffer_bytes`, 128 KiB by default) instead of the platform fast
+    /// path. The budget is shared, so raising `workers_max` does not raise the
+    /// cap; it only lets more files progress concurrently. `None` adds no
+    /// overhead.
+    pub throughput_max_bytes_per_sec: Option<u64>,
+    /// Skip hidden entries during traversal, before pattern matching.
+    ///
+    /// An entry is hidden when its basename starts with `.`, or on Windows
+    /// when it carries the hidden attribute. The source root is never
+    /// skipped. Independent of the include/exclude pattern lists.
+    pub should_skip_hidden: bool,
+    /// Preserve POSIX ACLs of copied files (Linux only).
+    ///
+    /// The source access ACL is applied after permission bits, so entries
+    /// granting access beyond the basic mode survive. Failures, such as a
+    /// destination filesystem without ACL support, are reported as warnings.
+    pub should_preserve_acl: bool,
+    /// Under `CopySymlinks`, rewrite absolute symlink targets that point
+    /// inside the source tree to relative targets within the destination
+    /// tree. Targets outside the source tree are left untouched. Only applies
+    /// with `should_keep_tree`, since flattening does not mirror the layout.
+    pub should_rewrite_symlinks_relative: bool,
+    /// During a dry run, record every planned action in
+    /// [`CopyReport::plan`](crate::CopyReport::plan). Ignored otherwise.
+    pub should_collect_plan: bool,
+    /// Re-read source and destination after each file copy and compare their
+    /// xxh3 hashes; a mismatch removes the destination and records an error.
+    ///
+    /// Runs inside the copy workers, but roughly doubles the bytes read per
+    /// file, so expect a substantial slowdown on large trees.
+    pub should_verify: bool,
+    /// Compute a hex digest of every copied file and collect it in
+    /// [`CopyReport::manifest`](crate::CopyReport::manifest).
+    ///
+    /// Digests are computed from the bytes as they are copied (one read), so
+    /// this forces the streaming copy path.
+    pub manifest_algorithm: Option<CopyHashAlgorithm>,
+    /// Record `(count, bytes)` of copied files per lowercased extension in
+    /// [`CopyReport::stats_by_ext`](crate::CopyReport::stats_by_ext).
+    pub should_collect_ext_stats: bool,
+    /// Hard-link a destination file to an earlier destination file of this
+    /// run with the same size, BLAKE3 content hash and permissions instead of
+    /// copying it.
+    ///
+    /// Every queued source file is read once more for hashing, which roughly
+    /// doubles source reads. A linked file is the same inode as the first
+    /// copy: it shows that copy's modification time, owner and extended
+    /// attributes rather than its own source's, and later edits through either
+    /// path change both. Linked files are counted in
+    /// [`CopyReport::cnt_linked`](crate::CopyReport::cnt_linked) and do not
+    /// count against `bytes_budget_max`. Falls back to a regular copy when
+    /// linking fails (for example on filesystems without hard links).
+    pub should_dedup_destination: bool,
+    /// Skip files whose destination already has the same length and
+    /// modification time as the source, regardless of `rule_conflict_file`.
+    ///
+    /// Counted in `cnt_unchanged`. Relies on copies preserving mtime (Linux
+    /// metadata copy) and on equal timestamp precision: filesystems that round
+    /// mtimes (FAT: 2 s, some network mounts: 1 s) only compare equal with a
+    /// matching `mtime_tolerance_ms`.
+    pub should_copy_incrementally: bool,
+    /// Treat source and destination mtimes within this many milliseconds as
+    /// equal in the incremental comparison.
+    ///
+    /// `0` (default) requires exact equality. For cross-filesystem copies use
+    /// `2000` when either side is FAT/exFAT and `1000` for HFS+ or SMB/NFS
+    /// mounts with second precision.
+    pub mtime_tolerance_ms: u64,
+    /// Maximum total source bytes to copy.
+    ///
+    /// Files are admitted in traversal order; once the next file would exceed
+    /// the budget, it and all remaining files are skipped with one warning.
+    /// Files copied before that point are complete. Files hard-linked by
+    /// `should_dedup_destination` add no bytes. `None` means no limit.
+    pub bytes_budget_max: Option<u64>,
+    /// Maximum number of matched files to copy.
+    ///
+    /// Once another file matches past the limit, traversal stops with one
+    /// warning and the partial report is returned. Independent of
+    /// `bytes_budget_max`. `None` means no limit.
+    pub files_max: Option<u64>,
+    /// Stop the run at the first per-entry error and return it as
+    /// [`CopyTreeError::EntryFailed`] instead of a report. Copies committed
+    /// before the error stay on disk; parallel copies already running finish.
+    pub should_fail_fast: bool,
+    /// Handling of FIFOs, sockets and device files (also as dereferenced
+    /// symlink targets).
+    pub rule_special_file: CopySpecialFileMode,
+    /// Policy for an existing symlink at a destination entry path.
+    ///
+    /// `Replace` removes the symlink right before the entry is written, so
+    /// the entry is written fresh. `rule_conflict_file` / `rule_conflict_dir`
+    /// are evaluated at planning time against the existing path. A dry run
+    /// removes nothing.
+    pub rule_conflict_symlink_dst: CopyDstSymlinkConflictMode,
+    /// Normalize destination file and directory names to this Unicode form;
+    /// source paths are read unchanged. Each renamed entry records a warning.
+    /// Names that normalize to the same result fall under the conflict rules.
+    pub filename_normalization: Option<CopyUnicodeForm>,
+    /// Make destination names valid on Windows: characters illegal there
+    /// (`<>:"/\|?*` and control characters) and trailing dots/spaces become
+    /// `_`, and reserved device names (`CON`, `PRN`, `AUX`, `NUL`, `COM1`-`COM9`,
+    /// `LPT1`-`LPT9`, with or without extension) get a `_` suffix on the stem.
+    /// Applied after `filename_normalization`; each renamed entry records a
+    /// warning.
+    pub should_sanitize_dest_names: bool,
+    /// Only copy files modified at or after this instant (inclusive).
+    pub mtime_after: Option<SystemTime>,
+    /// Only copy files modified at or before this instant (inclusive).
+    pub mtime_before: Option<SystemTime>,
+    /// User predicate evaluated after the built-in file filters; files it
+    /// rejects are counted as skipped.
+    pub filter: Option<CopyFileFilter>,
 }
 
 impl Default for CopyOptionsSpec {
@@ -102,27 +412,252 @@ impl Default for CopyOptionsSpec {
             patterns_include_dirs: None,
             patterns_exclude_dirs: None,
             rule_pattern: CopyPatternMode::Glob,
+            should_match_full_path: false,
             rule_conflict_file: CopyFileConflictMode::Skip,
+            should_force_overwrite_readonly: false,
             rule_conflict_dir: CopyDirectoryConflictMode::Skip,
             rule_symlink: CopySymlinkMode::CopySymlinks,
+            symlink_follow_max: None,
             depth_limit: None,
             rule_depth_limit: CopyDepthLimitMode::AtMost,
             workers_max: None,
             should_keep_tree: true,
             should_dry_run: false,
+            should_preserve_ownership: false,
+            should_preserve_dir_metadata: false,
+            should_copy_atomically: false,
+            should_fsync: false,
+            copy_buffer_bytes: None,
+            should_preserve_sparse: false,
+            throughput_max_bytes_per_sec: None,
+            should_skip_hidden: false,
+            should_preserve_acl: false,
+            should_rewrite_symlinks_relative: false,
+            should_collect_plan: false,
+            should_verify: false,
+            manifest_algorithm: None,
+            should_collect_ext_stats: false,
+            should_dedup_destination: false,
+            should_copy_incrementally: false,
+            mtime_tolerance_ms: 0,
+            bytes_budget_max: None,
+            files_max: None,
+            should_fail_fast: false,
+            rule_special_file: CopySpecialFileMode::Skip,
+            rule_conflict_symlink_dst: CopyDstSymlinkConflictMode::Error,
+            filename_normalization: None,
+            should_sanitize_dest_names: false,
+            mtime_after: None,
+            mtime_before: None,
+            filter: None,
         }
     }
 }
 
-/// One copy failure item with path + error text.
+impl CopyOptionsSpec {
+    /// Start a [`CopyOptionsSpecBuilder`] from the defaults.
+    pub fn builder() -> CopyOptionsSpecBuilder {
+        CopyOptionsSpecBuilder::default()
+    }
+}
+
+/// Fluent construction of [`CopyOptionsSpec`], starting from its defaults.
+///
+/// Setter names drop the `should_`, `rule_` and `patterns_` prefixes of the
+/// fields (`rule_depth_limit` becomes `depth_mode`). Setters of optional
+/// fields take the inner value; unset fields keep their defaults.
+///
+/// ```
+/// use axiomkit_io_fs::{CopyFileConflictMode, CopyOptionsSpec};
+///
+/// let copy_options = CopyOptionsSpec::builder()
+///     .include_files(["*.csv"])
+///     .conflict_file(CopyFileConflictMode::Overwrite)
+///     .depth_limit(2)
+///     .dry_run(true)
+///     .build();
+/// assert_eq!(copy_options.depth_limit, Some(2));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CopyOptionsSpecBuilder {
+    copy_options: CopyOptionsSpec,
+}
+
+macro_rules! copy_options_setters {
+    ($($method:ident => $field:ident: $ty:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Set [`CopyOptionsSpec::", stringify!($field), "`].")]
+            pub fn $method(mut self, value: $ty) -> Self {
+                self.copy_options.$field = value;
+                self
+            }
+        )*
+    };
+}
+
+macro_rules! copy_options_setters_some {
+    ($($method:ident => $field:ident: $ty:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Set [`CopyOptionsSpec::", stringify!($field), "`] to `Some(value)`.")]
+            pub fn $method(mut self, value: $ty) -> Self {
+                self.copy_options.$field = Some(value);
+                self
+            }
+        )*
+    };
+}
+
+macro_rules! copy_options_setters_patterns {
+    ($($method:ident => $field:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Set [`CopyOptionsSpec::", stringify!($field), "`].")]
+            pub fn $method(mut self, patterns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+                self.copy_options.$field = Some(patterns.into_iter().map(Into::into).collect());
+                self
+            }
+        )*
+    };
+}
+
+impl CopyOptionsSpecBuilder {
+    copy_options_setters_patterns! {
+        include_files => patterns_include_files,
+        exclude_files => patterns_exclude_files,
+        include_dirs => patterns_include_dirs,
+        exclude_dirs => patterns_exclude_dirs,
+    }
+
+    copy_options_setters! {
+        pattern => rule_pattern: CopyPatternMode,
+        match_full_path => should_match_full_path: bool,
+        conflict_file => rule_conflict_file: CopyFileConflictMode,
+        force_overwrite_readonly => should_force_overwrite_readonly: bool,
+        conflict_dir => rule_conflict_dir: CopyDirectoryConflictMode,
+        symlink => rule_symlink: CopySymlinkMode,
+        depth_mode => rule_depth_limit: CopyDepthLimitMode,
+        keep_tree => should_keep_tree: bool,
+        dry_run => should_dry_run: bool,
+        preserve_ownership => should_preserve_ownership: bool,
+        preserve_dir_metadata => should_preserve_dir_metadata: bool,
+        copy_atomically => should_copy_atomically: bool,
+        fsync => should_fsync: bool,
+        preserve_sparse => should_preserve_sparse: bool,
+        skip_hidden => should_skip_hidden: bool,
+        preserve_acl => should_preserve_acl: bool,
+        rewrite_symlinks_relative => should_rewrite_symlinks_relative: bool,
+        collect_plan => should_collect_plan: bool,
+        verify => should_verify: bool,
+        collect_ext_stats => should_collect_ext_stats: bool,
+        dedup_destination => should_dedup_destination: bool,
+        copy_incrementally => should_copy_incrementally: bool,
+        mtime_tolerance_ms => mtime_tolerance_ms: u64,
+        fail_fast => should_fail_fast: bool,
+        special_file => rule_special_file: CopySpecialFileMode,
+        conflict_symlink_dst => rule_conflict_symlink_dst: CopyDstSymlinkConflictMode,
+        sanitize_dest_names => should_sanitize_dest_names: bool,
+    }
+
+    copy_options_setters_some! {
+        symlink_follow_max => symlink_follow_max: usize,
+        depth_limit => depth_limit: usize,
+        workers_max => workers_max: usize,
+        copy_buffer_bytes => copy_buffer_bytes: usize,
+        throughput_max_bytes_per_sec => throughput_max_bytes_per_sec: u64,
+        manifest_algorithm => manifest_algorithm: CopyHashAlgorithm,
+        bytes_budget_max => bytes_budget_max: u64,
+        files_max => files_max: u64,
+        filename_normalization => filename_normalization: CopyUnicodeForm,
+        mtime_after => mtime_after: SystemTime,
+        mtime_before => mtime_before: SystemTime,
+    }
+
+    /// Set [`CopyOptionsSpec::filter`] to `predicate`.
+    pub fn filter(
+        mut self,
+        predicate: impl Fn(&Path, &Metadata) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.copy_options.filter = Some(CopyFileFilter::new(predicate));
+        self
+    }
+
+    /// Finish with the configured options.
+    pub fn build(self) -> CopyOptionsSpec {
+        self.copy_options
+    }
+}
+
+/// Predicate over `(source path, source metadata)` deciding whether a file is
+/// copied (`true`) or skipped (`false`).
+///
+/// Metadata follows the symlink under `Dereference` and describes the link
+/// itself otherwise. The predicate runs on the traversal thread but must be
+/// `Send + Sync` so options can cross into the worker pool.
+#[derive(Clone)]
+pub struct CopyFileFilter(Arc<CopyFileFilterFn>);
+
+type CopyFileFilterFn = dyn Fn(&Path, &Metadata) -> bool + Send + Sync;
+
+impl CopyFileFilter {
+    /// Wrap a predicate.
+    pub fn new(predicate: impl Fn(&Path, &Metadata) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(predicate))
+    }
+
+    /// Evaluate the predicate for one file.
+    pub fn is_match(&self, path: &Path, metadata: &Metadata) -> bool {
+        (self.0)(path, metadata)
+    }
+}
+
+impl fmt::Debug for CopyFileFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CopyFileFilter(..)")
+    }
+}
+
+/// One copy failure item with path, failure class and error text.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CopyErrorRecord {
     /// Failed source or destination path.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::util::serde_path::serialize")
+    )]
     pub path: PathBuf,
+    /// Source entry that produced the failure, when known (file copies and
+    /// broken symlinks).
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::util::serde_path::serialize_option")
+    )]
+    pub source_path: Option<PathBuf>,
+    /// Failure class for programmatic handling.
+    pub kind: CopyErrorKind,
     /// User-facing error text.
     pub exception: String,
 }
 
+/// One plan item: what happens (or, in a dry run, would happen) to `src_path`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CopyPlanEntry {
+    /// Source entry path.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::util::serde_path::serialize")
+    )]
+    pub src_path: PathBuf,
+    /// Destination path the action targets.
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "crate::util::serde_path::serialize")
+    )]
+    pub dst_path: PathBuf,
+    /// Planned action.
+    pub action: CopyPlanAction,
+}
+
 /// "Top-level call failed" errors (input validation / setup stage).
 #[derive(Debug)]
 pub enum CopyTreeError {
@@ -130,8 +665,12 @@ pub enum CopyTreeError {
     InvalidDepthLimit(String),
     /// Invalid include/exclude pattern.
     InvalidPattern(String),
+    /// Invalid option value.
+    InvalidOption(String),
     /// Source path is not a directory.
     SourceNotDirectory(PathBuf),
+    /// Source path of `copy_entry` is missing or a directory.
+    SourceNotFile(PathBuf),
     /// Source and destination overlap (`src` contains `dst` or vice versa).
     SourceDestinationOverlap {
         /// Normalized source directory.
@@ -146,6 +685,13 @@ pub enum CopyTreeError {
         /// Underlying IO error text.
         message: String,
     },
+    /// First per-entry failure of a run with `should_fail_fast`.
+    EntryFailed {
+        /// Failed source or destination path.
+        path: PathBuf,
+        /// User-facing error text.
+        message: String,
+    },
 }
 
 impl fmt::Display for CopyTreeError {
@@ -153,9 +699,13 @@ impl fmt::Display for CopyTreeError {
         match self {
             Self::InvalidDepthLimit(msg) => write!(f, "{msg}"),
             Self::InvalidPattern(msg) => write!(f, "{msg}"),
+            Self::InvalidOption(msg) => write!(f, "{msg}"),
             Self::SourceNotDirectory(path) => {
                 write!(f, "Source is not a directory: {}", path.display())
             }
+            Self::SourceNotFile(path) => {
+                write!(f, "Source is not a file: {}", path.display())
+            }
             Self::SourceDestinationOverlap {
                 source,
                 destination,
@@ -172,6 +722,9 @@ impl fmt::Display for CopyTreeError {
                     path.display()
                 )
             }
+            Self::EntryFailed { path, message } => {
+                write!(f, "Failed to copy {}: {message}", path.display())
+            }
         }
     }
 }
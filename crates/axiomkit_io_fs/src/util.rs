@@ -1,14 +1,17 @@
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
 
-use globset::{Glob, GlobMatcher};
+use globset::{GlobBuilder, GlobMatcher};
 use regex::Regex;
 
 use crate::report::CopyReportBuilder;
 use crate::spec::{
-    CopyDepthLimitMode, CopyDirectoryConflictMode, CopyFileConflictMode, CopyPatternMode,
-    CopySymlinkMode, CopyTreeError,
+    CopyDepthLimitMode, CopyDirectoryConflictMode, CopyErrorKind, CopyFileConflictMode,
+    CopyHashAlgorithm, CopyOptionsSpec, CopyPatternMode, CopySymlinkMode, CopyTreeError,
+    CopyUnicodeForm,
 };
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -19,6 +22,98 @@ pub(crate) enum TypeCopyPatternSeq {
     Literal(Vec<String>),
     Glob(Vec<GlobMatcher>),
     Regex(Vec<Regex>),
+    Gitignore(GitignoreRules),
+}
+
+/// One compiled `.gitignore`-style rule.
+#[derive(Debug, Clone)]
+pub(crate) struct GitignoreRule {
+    matcher: GlobMatcher,
+    is_negated: bool,
+    is_dir_only: bool,
+}
+
+/// Ordered `.gitignore`-style rules; the last matching rule wins.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct GitignoreRules {
+    rules: Vec<GitignoreRule>,
+}
+
+impl GitignoreRules {
+    /// Compile gitignore lines.
+    ///
+    /// Supported syntax:
+    /// - blank lines and `#` comments are ignored (`\#` / `\!` escape them),
+    /// - a leading `!` re-includes paths matched by an earlier rule,
+    /// - a trailing `/` restricts the rule to directories,
+    /// - a pattern with a `/` before its end is anchored at the source root,
+    ///   otherwise it matches at any depth.
+    pub(crate) fn from_lines(lines: &[String]) -> Result<Self, String> {
+        let mut rules = Vec::with_capacity(lines.len());
+        for _line in lines {
+            let mut line = _line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let is_negated = line.starts_with('!');
+            if is_negated || line.starts_with("\\!") || line.starts_with("\\#") {
+                line = &line[1..];
+            }
+
+            let is_dir_only = line.ends_with('/');
+            let line = line.trim_end_matches('/');
+            if line.is_empty() {
+                return Err(format!("Invalid gitignore rule: `{_line}`"));
+            }
+
+            let glob_text = if line.contains('/') {
+                line.trim_start_matches('/').to_string()
+            } else {
+                format!("**/{line}")
+            };
+            let matcher = GlobBuilder::new(&glob_text)
+                .literal_separator(true)
+                .build()
+                .map_err(|e| format!("Invalid gitignore rule `{_line}`: {e}"))?
+                .compile_matcher();
+            rules.push(GitignoreRule {
+                matcher,
+                is_negated,
+                is_dir_only,
+            });
+        }
+        Ok(Self { rules })
+    }
+
+    fn _is_matching_self(&self, path_rel: &str, is_dir: bool) -> bool {
+        let mut is_matched = false;
+        for _rule in &self.rules {
+            if _rule.is_dir_only && !is_dir {
+                continue;
+            }
+            if _rule.matcher.is_match(path_rel) {
+                is_matched = !_rule.is_negated;
+            }
+        }
+        is_matched
+    }
+
+    /// Whether `path_rel` (`/`-separated, relative to the source root) is
+    /// matched, either directly or through one of its parent directories.
+    ///
+    /// As in git, a path under a matched directory cannot be re-included.
+    pub(crate) fn is_match(&self, path_rel: &str, is_dir: bool) -> bool {
+        let mut idx_search = 0;
+        while let Some(idx_sep) = path_rel[idx_search..].find('/') {
+            let idx_end = idx_search + idx_sep;
+            if self._is_matching_self(&path_rel[..idx_end], true) {
+                return true;
+            }
+            idx_search = idx_end + 1;
+        }
+        self._is_matching_self(path_rel, is_dir)
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -62,7 +157,9 @@ fn _compile(
         CopyPatternMode::Glob => {
             let mut glob_matchers = Vec::with_capacity(patterns.len());
             for _pattern in patterns {
-                let matcher = Glob::new(_pattern)
+                let matcher = GlobBuilder::new(_pattern)
+                    .literal_separator(true)
+                    .build()
                     .map_err(|e| {
                         CopyTreeError::InvalidPattern(format!(
                             "Invalid pattern in include/exclude: {e}"
@@ -85,11 +182,18 @@ fn _compile(
             }
             Ok(Some(TypeCopyPatternSeq::Regex(regexes)))
         }
+        CopyPatternMode::Gitignore => {
+            let rules = GitignoreRules::from_lines(patterns).map_err(|e| {
+                CopyTreeError::InvalidPattern(format!("Invalid pattern in include/exclude: {e}"))
+            })?;
+            Ok(Some(TypeCopyPatternSeq::Gitignore(rules)))
+        }
     }
 }
 
 fn _is_pattern_matching(
     value: &str,
+    is_dir: bool,
     patterns: Option<&TypeCopyPatternSeq>,
     rule_pattern: CopyPatternMode,
 ) -> bool {
@@ -102,50 +206,86 @@ fn _is_pattern_matching(
             TypeCopyPatternSeq::Literal(v) => v.iter().any(|p| value.contains(p)),
             TypeCopyPatternSeq::Glob(_) => false,
             TypeCopyPatternSeq::Regex(_) => false,
+            TypeCopyPatternSeq::Gitignore(_) => false,
         },
         CopyPatternMode::Glob => match patterns {
             TypeCopyPatternSeq::Glob(v) => v.iter().any(|p| p.is_match(value)),
             TypeCopyPatternSeq::Literal(_) => false,
             TypeCopyPatternSeq::Regex(_) => false,
+            TypeCopyPatternSeq::Gitignore(_) => false,
         },
         CopyPatternMode::Regex => match patterns {
             TypeCopyPatternSeq::Regex(v) => v.iter().any(|p| p.is_match(value)),
             TypeCopyPatternSeq::Literal(_) => false,
             TypeCopyPatternSeq::Glob(_) => false,
+            TypeCopyPatternSeq::Gitignore(_) => false,
+        },
+        CopyPatternMode::Gitignore => match patterns {
+            TypeCopyPatternSeq::Gitignore(v) => v.is_match(value, is_dir),
+            TypeCopyPatternSeq::Literal(_) => false,
+            TypeCopyPatternSeq::Glob(_) => false,
+            TypeCopyPatternSeq::Regex(_) => false,
         },
     }
 }
 
 fn _should_include(
     value: &str,
+    is_dir: bool,
     patterns: Option<&TypeCopyPatternSeq>,
     rule_pattern: CopyPatternMode,
 ) -> bool {
     match patterns {
         None => true,
-        Some(_) => _is_pattern_matching(value, patterns, rule_pattern),
+        Some(_) => _is_pattern_matching(value, is_dir, patterns, rule_pattern),
     }
 }
 
 fn _should_exclude(
     value: &str,
+    is_dir: bool,
     patterns: Option<&TypeCopyPatternSeq>,
     rule_pattern: CopyPatternMode,
 ) -> bool {
     match patterns {
         None => false,
-        Some(_) => _is_pattern_matching(value, patterns, rule_pattern),
+        Some(_) => _is_pattern_matching(value, is_dir, patterns, rule_pattern),
     }
 }
 
 pub(crate) fn should_exclude_by_patterns(
     value: &str,
+    is_dir: bool,
     patterns_include: Option<&TypeCopyPatternSeq>,
     patterns_exclude: Option<&TypeCopyPatternSeq>,
     rule_pattern: CopyPatternMode,
 ) -> bool {
-    !_should_include(value, patterns_include, rule_pattern)
-        || _should_exclude(value, patterns_exclude, rule_pattern)
+    !_should_include(value, is_dir, patterns_include, rule_pattern)
+        || _should_exclude(value, is_dir, patterns_exclude, rule_pattern)
+}
+
+/// Derive the string that include/exclude patterns are matched against.
+///
+/// With `should_match_full_path` (always implied by gitignore mode), this is
+/// the path of `path_src` relative to `path_dir_src`, joined with `/` on every
+/// platform; otherwise the basename.
+pub(crate) fn derive_pattern_subject(
+    path_src: &Path,
+    path_item_name: &str,
+    path_dir_src: &Path,
+    should_match_full_path: bool,
+) -> String {
+    if !should_match_full_path {
+        return path_item_name.to_string();
+    }
+    let Ok(path_rel) = path_src.strip_prefix(path_dir_src) else {
+        return path_item_name.to_string();
+    };
+    path_rel
+        .components()
+        .map(|_part| _part.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
 }
 
 // #endregion
@@ -156,16 +296,39 @@ fn _is_relative_to_base(path: &Path, base: &Path) -> bool {
     path.starts_with(base)
 }
 
+/// Dangling symlinks followed by [`_normalize_path`] before giving up.
+const SYMLINK_RESOLVE_HOPS_MAX: usize = 40;
+
+/// Absolute, symlink-free form of `path`, also when it does not exist yet:
+/// the nearest existing ancestor is canonicalized and the missing tail is
+/// appended, and dangling symlinks are followed to their targets.
 fn _normalize_path(path: &Path) -> PathBuf {
+    _resolve_path(&_absolutize_path(path), SYMLINK_RESOLVE_HOPS_MAX)
+}
+
+fn _resolve_path(path: &Path, hops_left: usize) -> PathBuf {
+    use std::path::Component;
+
     if let Ok(resolved) = fs::canonicalize(path) {
         return resolved;
     }
-    if path.is_absolute() {
-        return path.to_path_buf();
+    if hops_left > 0
+        && let Ok(target) = fs::read_link(path)
+    {
+        let path_parent = path.parent().unwrap_or(path);
+        return _resolve_path(&path_parent.join(target), hops_left - 1);
+    }
+    match (path.parent(), path.components().next_back()) {
+        (Some(path_parent), Some(Component::Normal(name))) => {
+            _resolve_path(path_parent, hops_left).join(name)
+        }
+        (Some(path_parent), Some(Component::ParentDir)) => {
+            let mut path_resolved = _resolve_path(path_parent, hops_left);
+            path_resolved.pop();
+            path_resolved
+        }
+        _ => path.to_path_buf(),
     }
-    std::env::current_dir()
-        .unwrap_or_else(|_| PathBuf::from("."))
-        .join(path)
 }
 
 fn _absolutize_path(path: &Path) -> PathBuf {
@@ -177,6 +340,64 @@ fn _absolutize_path(path: &Path) -> PathBuf {
         .join(path)
 }
 
+/// Extended-length (`\\?\` or `\\?\UNC\`) form of `path` on Windows, so
+/// file APIs accept paths longer than `MAX_PATH`.
+///
+/// The path is made absolute and normalized first, because the prefix turns
+/// off Win32 path parsing. Already prefixed paths, device paths and paths that
+/// cannot be resolved are returned unchanged; other platforms never change.
+pub(crate) fn to_extended_length_path(path: &Path) -> PathBuf {
+    #[cfg(windows)]
+    {
+        use std::ffi::OsString;
+        use std::path::{Component, Prefix};
+
+        let Ok(path_abs) = std::path::absolute(path) else {
+            return path.to_path_buf();
+        };
+        let Some(Component::Prefix(prefix)) = path_abs.components().next() else {
+            return path_abs;
+        };
+        match prefix.kind() {
+            Prefix::Disk(_) => {
+                let mut path_extended = OsString::from(r"\\?\");
+                path_extended.push(path_abs.as_os_str());
+                PathBuf::from(path_extended)
+            }
+            Prefix::UNC(_, _) => match path_abs.to_str().and_then(|v| v.strip_prefix(r"\\")) {
+                Some(path_share) => PathBuf::from(format!(r"\\?\UNC\{path_share}")),
+                None => path_abs,
+            },
+            _ => path_abs,
+        }
+    }
+    #[cfg(not(windows))]
+    {
+        path.to_path_buf()
+    }
+}
+
+/// Conventional spelling of an extended-length path (inverse of
+/// [`to_extended_length_path`]); other paths are returned unchanged.
+pub(crate) fn from_extended_length_path(path: &Path) -> PathBuf {
+    #[cfg(windows)]
+    {
+        if let Some(path_str) = path.to_str() {
+            if let Some(path_share) = path_str.strip_prefix(r"\\?\UNC\") {
+                return PathBuf::from(format!(r"\\{path_share}"));
+            }
+            if let Some(path_disk) = path_str.strip_prefix(r"\\?\")
+                && path_disk.as_bytes().get(1) == Some(&b':')
+            {
+                return PathBuf::from(path_disk);
+            }
+        }
+    }
+    path.to_path_buf()
+}
+
+/// Whether `src` contains `dst` or vice versa, after resolving symlinks on
+/// both sides (see [`_normalize_path`]).
 pub(crate) fn is_overlap(src: &Path, dst: &Path) -> bool {
     let src_resolved = _normalize_path(src);
     let dst_resolved = _normalize_path(dst);
@@ -187,6 +408,35 @@ pub(crate) fn is_overlap(src: &Path, dst: &Path) -> bool {
 pub(crate) fn validate_destination_path_safety(
     path_dst_item: &Path,
     path_dir_dst_root: &Path,
+) -> Result<(), String> {
+    validate_destination_parent_safety(path_dst_item, path_dir_dst_root)?;
+
+    match fs::symlink_metadata(path_dst_item) {
+        Ok(meta_dst_item) => {
+            if meta_dst_item.file_type().is_symlink() {
+                return Err(format!(
+                    "Unsafe destination path is an existing symlink: {}",
+                    path_dst_item.display()
+                ));
+            }
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => {
+            return Err(format!(
+                "Failed to inspect destination path {} ({e})",
+                path_dst_item.display()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that `path_dst_item` stays under the destination root and that no
+/// existing parent component is a symlink. The item itself is not inspected.
+pub(crate) fn validate_destination_parent_safety(
+    path_dst_item: &Path,
+    path_dir_dst_root: &Path,
 ) -> Result<(), String> {
     let path_dir_dst_root_abs = _absolutize_path(path_dir_dst_root);
     let path_dst_item_abs = _absolutize_path(path_dst_item);
@@ -244,25 +494,31 @@ pub(crate) fn validate_destination_path_safety(
         }
     }
 
-    match fs::symlink_metadata(&path_dst_item_abs) {
-        Ok(meta_dst_item) => {
-            if meta_dst_item.file_type().is_symlink() {
-                return Err(format!(
-                    "Unsafe destination path is an existing symlink: {}",
-                    path_dst_item.display()
-                ));
-            }
-        }
-        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
-        Err(e) => {
-            return Err(format!(
-                "Failed to inspect destination path {} ({e})",
-                path_dst_item.display()
-            ));
+    Ok(())
+}
+
+/// Remove an existing symlink at `path_dst_item` (not its target), so the
+/// entry is written as if the destination were absent.
+pub(crate) fn remove_destination_symlink(path_dst_item: &Path) -> Result<(), String> {
+    match fs::symlink_metadata(path_dst_item) {
+        Ok(meta_dst_item) if meta_dst_item.file_type().is_symlink() => {
+            // Windows directory symlinks can only be removed as directories.
+            fs::remove_file(path_dst_item)
+                .or_else(|_| fs::remove_dir(path_dst_item))
+                .map_err(|e| {
+                    format!(
+                        "Failed to remove destination symlink {} ({e})",
+                        path_dst_item.display()
+                    )
+                })
         }
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!(
+            "Failed to inspect destination path {} ({e})",
+            path_dst_item.display()
+        )),
     }
-
-    Ok(())
 }
 
 pub(crate) fn should_error_broken_symlink(
@@ -283,6 +539,7 @@ pub(crate) fn should_skip_dir_conflict(
     if path_dst.is_file() {
         report_builder.add_error(
             path_dst.to_path_buf(),
+            CopyErrorKind::ConflictError,
             format!(
                 "Destination is a file, expected directory: {}",
                 path_dst.display()
@@ -299,6 +556,7 @@ pub(crate) fn should_skip_dir_conflict(
         CopyDirectoryConflictMode::Error => {
             report_builder.add_error(
                 path_dst.to_path_buf(),
+                CopyErrorKind::ConflictError,
                 format!("Destination exists: {}", path_dst.display()),
             );
             true
@@ -318,6 +576,7 @@ pub(crate) fn should_skip_file_conflict(
     if path_dst.is_dir() {
         report_builder.add_error(
             path_dst.to_path_buf(),
+            CopyErrorKind::ConflictError,
             format!("Destination is a directory: {}", path_dst.display()),
         );
         return true;
@@ -331,6 +590,7 @@ pub(crate) fn should_skip_file_conflict(
         CopyFileConflictMode::Error => {
             report_builder.add_error(
                 path_dst.to_path_buf(),
+                CopyErrorKind::ConflictError,
                 format!("Destination exists: {}", path_dst.display()),
             );
             true
@@ -339,25 +599,108 @@ pub(crate) fn should_skip_file_conflict(
     }
 }
 
+/// Source/destination roots used to rewrite absolute in-tree symlink targets.
+#[derive(Debug, Clone)]
+pub(crate) struct SymlinkRewriteSpec {
+    /// Spellings of the source root an absolute target may start with
+    /// (absolute and canonical forms).
+    dirs_src_root: Vec<PathBuf>,
+    dir_dst_root: PathBuf,
+}
+
+impl SymlinkRewriteSpec {
+    pub(crate) fn new(dir_src_root: &Path, dir_dst_root: &Path) -> Self {
+        let mut dirs_src_root: Vec<PathBuf> = Vec::new();
+        // Link targets use the conventional spelling even when the roots are
+        // in extended-length form.
+        for _dir in [
+            std::path::absolute(from_extended_length_path(dir_src_root)).ok(),
+            std::path::absolute(dir_src_root).ok(),
+            fs::canonicalize(dir_src_root).ok(),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !dirs_src_root.contains(&_dir) {
+                dirs_src_root.push(_dir);
+            }
+        }
+        Self {
+            dirs_src_root,
+            dir_dst_root: dir_dst_root.to_path_buf(),
+        }
+    }
+
+    /// Relative replacement for `target` of the link created at `path_dst`,
+    /// or `None` when the target is relative or outside the source tree.
+    fn derive_target(&self, target: &Path, path_dst: &Path) -> Option<PathBuf> {
+        if !target.is_absolute() {
+            return None;
+        }
+        let target_rel = self
+            .dirs_src_root
+            .iter()
+            .find_map(|_root| target.strip_prefix(_root).ok())?;
+        let link_parent_rel = path_dst.parent()?.strip_prefix(&self.dir_dst_root).ok()?;
+
+        let parts_target: Vec<_> = target_rel.components().collect();
+        let parts_parent: Vec<_> = link_parent_rel.components().collect();
+        let cnt_common = parts_target
+            .iter()
+            .zip(&parts_parent)
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let mut target_new = PathBuf::new();
+        for _ in cnt_common..parts_parent.len() {
+            target_new.push("..");
+        }
+        for _part in &parts_target[cnt_common..] {
+            target_new.push(_part);
+        }
+        if target_new.as_os_str().is_empty() {
+            target_new.push(".");
+        }
+        Some(target_new)
+    }
+}
+
 pub(crate) fn create_symbolic_link(
     path_src: &Path,
     path_dst: &Path,
+    symlink_rewrite: Option<&SymlinkRewriteSpec>,
     report_builder: &mut CopyReportBuilder,
 ) {
-    let target = match fs::read_link(path_src) {
+    let mut target = match fs::read_link(path_src) {
         Ok(v) => v,
         Err(e) => {
-            report_builder.add_error(path_dst.to_path_buf(), e.to_string());
+            report_builder.add_error(
+                path_dst.to_path_buf(),
+                CopyErrorKind::SymlinkFailed,
+                e.to_string(),
+            );
             return;
         }
     };
+    if let Some(target_new) =
+        symlink_rewrite.and_then(|_spec| _spec.derive_target(&target, path_dst))
+    {
+        target = target_new;
+    }
 
     #[cfg(unix)]
     {
         use std::os::unix::fs::symlink;
         match symlink(&target, path_dst) {
-            Ok(_) => report_builder.add_copied(),
-            Err(e) => report_builder.add_error(path_dst.to_path_buf(), e.to_string()),
+            Ok(_) => {
+                report_builder.add_copied();
+                report_builder.add_symlink();
+            }
+            Err(e) => report_builder.add_error(
+                path_dst.to_path_buf(),
+                CopyErrorKind::SymlinkFailed,
+                e.to_string(),
+            ),
         }
     }
     #[cfg(windows)]
@@ -369,8 +712,15 @@ pub(crate) fn create_symbolic_link(
             symlink_file(&target, path_dst)
         };
         match res {
-            Ok(_) => report_builder.add_copied(),
-            Err(e) => report_builder.add_error(path_dst.to_path_buf(), e.to_string()),
+            Ok(_) => {
+                report_builder.add_copied();
+                report_builder.add_symlink();
+            }
+            Err(e) => report_builder.add_error(
+                path_dst.to_path_buf(),
+                CopyErrorKind::SymlinkFailed,
+                e.to_string(),
+            ),
         }
     }
     #[cfg(not(any(unix, windows)))]
@@ -378,38 +728,640 @@ pub(crate) fn create_symbolic_link(
         let _ = target;
         report_builder.add_error(
             path_dst.to_path_buf(),
+            CopyErrorKind::SymlinkFailed,
             "Symbolic links are unsupported on this platform".to_string(),
         );
     }
 }
 
+/// Recreate a FIFO or device node at `path_dst` with `mknod` (best effort).
+///
+/// Sockets only exist while a process listens on them, so they are skipped
+/// with a warning. Device nodes usually require root; failures are errors.
+pub(crate) fn create_special_file(
+    metadata_src: &fs::Metadata,
+    path_dst: &Path,
+    report_builder: &mut CopyReportBuilder,
+) {
+    #[cfg(unix)]
+    {
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+        use std::os::unix::fs::{FileTypeExt, MetadataExt};
+
+        if metadata_src.file_type().is_socket() {
+            report_builder.add_warning(format!(
+                "Socket cannot be recreated; skipped: {}",
+                path_dst.display()
+            ));
+            report_builder.add_skipped();
+            return;
+        }
+        let path_dst_c = match CString::new(path_dst.as_os_str().as_bytes()) {
+            Ok(v) => v,
+            Err(e) => {
+                report_builder.add_error(
+                    path_dst.to_path_buf(),
+                    CopyErrorKind::SpecialFile,
+                    e.to_string(),
+                );
+                return;
+            }
+        };
+        let rc = unsafe {
+            libc::mknod(
+                path_dst_c.as_ptr(),
+                metadata_src.mode() as libc::mode_t,
+                metadata_src.rdev() as libc::dev_t,
+            )
+        };
+        if rc == 0 {
+            report_builder.add_copied();
+        } else {
+            report_builder.add_error(
+                path_dst.to_path_buf(),
+                CopyErrorKind::SpecialFile,
+                io::Error::last_os_error().to_string(),
+            );
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = metadata_src;
+        report_builder.add_error(
+            path_dst.to_path_buf(),
+            CopyErrorKind::SpecialFile,
+            "Special files are unsupported on this platform".to_string(),
+        );
+    }
+}
+
+/// Non-fatal side results of one file copy, collected by copy workers.
+#[derive(Debug, Default)]
+pub(crate) struct CopyFileOutcome {
+    pub(crate) warnings: Vec<String>,
+    pub(crate) is_verified: bool,
+    /// Number of source bytes written (holes of sparse files included).
+    pub(crate) bytes_copied: u64,
+    /// Hex digest of the copied bytes when a manifest is requested.
+    pub(crate) digest: Option<String>,
+}
+
+/// Incremental digest over the bytes of one file copy.
+pub(crate) enum CopyDigester {
+    Sha256(sha2::Sha256),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl CopyDigester {
+    pub(crate) fn new(hash_algorithm: CopyHashAlgorithm) -> Self {
+        use sha2::Digest;
+
+        match hash_algorithm {
+            CopyHashAlgorithm::Sha256 => Self::Sha256(sha2::Sha256::new()),
+            CopyHashAlgorithm::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        use sha2::Digest;
+
+        match self {
+            Self::Sha256(hasher) => hasher.update(bytes),
+            Self::Blake3(hasher) => {
+                hasher.update(bytes);
+            }
+        }
+    }
+
+    /// Feed `cnt_bytes` zero bytes, as read back from a sparse-file hole.
+    #[cfg(target_os = "linux")]
+    fn update_zeros(&mut self, mut cnt_bytes: u64) {
+        const ZEROS: [u8; 8192] = [0; 8192];
+        while cnt_bytes > 0 {
+            let len_chunk = cnt_bytes.min(ZEROS.len() as u64) as usize;
+            self.update(&ZEROS[..len_chunk]);
+            cnt_bytes -= len_chunk as u64;
+        }
+    }
+
+    pub(crate) fn finalize_hex(self) -> String {
+        use sha2::Digest;
+
+        match self {
+            Self::Sha256(hasher) => format!("{:x}", hasher.finalize()),
+            Self::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+        }
+    }
+}
+
 pub(crate) fn copy_file_with_metadata(
     file_src_path: &Path,
     file_dst_path: &Path,
+    dir_dst_root: &Path,
+    copy_options: &CopyOptionsSpec,
+    rate_limiter: Option<&CopyRateLimiter>,
+) -> Result<CopyFileOutcome, io::Error> {
+    let mut copy_outcome = CopyFileOutcome::default();
+    if !copy_options.should_copy_atomically {
+        _copy_file_body(
+            file_src_path,
+            file_dst_path,
+            copy_options,
+            rate_limiter,
+            &mut copy_outcome,
+        )?;
+        return Ok(copy_outcome);
+    }
+
+    let file_tmp_path = derive_atomic_temp_path(file_dst_path);
+    validate_destination_path_safety(&file_tmp_path, dir_dst_root).map_err(io::Error::other)?;
+    let copy_result = _copy_file_body(
+        file_src_path,
+        &file_tmp_path,
+        copy_options,
+        rate_limiter,
+        &mut copy_outcome,
+    )
+    .and_then(|_| fs::rename(&file_tmp_path, file_dst_path));
+    if let Err(e) = copy_result {
+        let _ = fs::remove_file(&file_tmp_path);
+        return Err(e);
+    }
+    Ok(copy_outcome)
+}
+
+/// Make an existing read-only destination file writable so it can be
+/// overwritten: clear the read-only attribute on Windows, add owner write
+/// permission on Unix. Missing paths and non-files are left alone.
+pub(crate) fn make_destination_writable(file_dst_path: &Path) -> Result<(), io::Error> {
+    let meta_dst = match fs::symlink_metadata(file_dst_path) {
+        Ok(v) => v,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    let mut permissions = meta_dst.permissions();
+    if !meta_dst.is_file() || !permissions.readonly() {
+        return Ok(());
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        permissions.set_mode(permissions.mode() | 0o200);
+    }
+    #[cfg(not(unix))]
+    {
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+    }
+    fs::set_permissions(file_dst_path, permissions)
+}
+
+/// Flush the directory entry of a copied file to stable storage.
+///
+/// The file data itself is synced through the handle that wrote it (see
+/// `_copy_file_body`); on Unix this also syncs the parent directory so the
+/// new entry (create or rename) is durable. Elsewhere it is a no-op.
+pub(crate) fn sync_parent_dir_to_disk(file_dst_path: &Path) -> Result<(), io::Error> {
+    #[cfg(unix)]
+    {
+        if let Some(path_parent_dst) = file_dst_path.parent() {
+            fs::File::open(path_parent_dst)?.sync_all()?;
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = file_dst_path;
+    }
+    Ok(())
+}
+
+/// Derive a unique sibling temp path (`.<name>.<pid>-<seq>.axmtmp`) for atomic copy.
+fn derive_atomic_temp_path(file_dst_path: &Path) -> PathBuf {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static TEMP_SEQ: AtomicU64 = AtomicU64::new(0);
+    let temp_seq = TEMP_SEQ.fetch_add(1, Ordering::Relaxed);
+    let file_name = file_dst_path
+        .file_name()
+        .map(|_name| _name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    file_dst_path.with_file_name(format!(
+        ".{file_name}.{}-{temp_seq}.axmtmp",
+        std::process::id()
+    ))
+}
+
+fn _copy_file_body(
+    file_src_path: &Path,
+    file_dst_path: &Path,
+    copy_options: &CopyOptionsSpec,
+    rate_limiter: Option<&CopyRateLimiter>,
+    copy_outcome: &mut CopyFileOutcome,
 ) -> Result<(), io::Error> {
-    fs::copy(file_src_path, file_dst_path)?;
+    // Throttling and digests need to see every chunk, so they force the
+    // streaming path. So does fsync: the writing handle is synced before
+    // permissions are applied, because a read-only destination cannot be
+    // reopened for writing and Windows only flushes writable handles.
+    let mut digester = copy_options.manifest_algorithm.map(CopyDigester::new);
+    let should_sync = copy_options.should_fsync;
+    let buffer_bytes = copy_options.copy_buffer_bytes.or((rate_limiter.is_some()
+        || digester.is_some()
+        || should_sync)
+        .then_some(STREAM_COPY_BUFFER_BYTES));
+
     #[cfg(target_os = "linux")]
+    let bytes_copied_sparse = if copy_options.should_preserve_sparse {
+        _copy_file_sparse_linux(
+            file_src_path,
+            file_dst_path,
+            buffer_bytes.unwrap_or(STREAM_COPY_BUFFER_BYTES),
+            rate_limiter,
+            digester.as_mut(),
+            should_sync,
+        )?
+    } else {
+        None
+    };
+    #[cfg(not(target_os = "linux"))]
+    let bytes_copied_sparse: Option<u64> = None;
+
+    copy_outcome.bytes_copied = match (bytes_copied_sparse, buffer_bytes) {
+        (Some(bytes_copied), _) => bytes_copied,
+        (None, None) => fs::copy(file_src_path, file_dst_path)?,
+        (None, Some(buffer_bytes)) => {
+            let bytes_copied = _copy_file_buffered(
+                file_src_path,
+                file_dst_path,
+                buffer_bytes,
+                rate_limiter,
+                digester.as_mut(),
+                should_sync,
+            )?;
+            #[cfg(not(target_os = "linux"))]
+            {
+                fs::set_permissions(file_dst_path, fs::metadata(file_src_path)?.permissions())?;
+            }
+            bytes_copied
+        }
+    };
+    copy_outcome.digest = digester.map(CopyDigester::finalize_hex);
+    #[cfg(target_os = "linux")]
+    {
+        apply_metadata_linux(file_src_path, file_dst_path, copy_options, copy_outcome)?;
+    }
+    #[cfg(target_os = "macos")]
     {
-        apply_metadata_linux(file_src_path, file_dst_path)?;
+        copy_xattrs_macos(file_src_path, file_dst_path);
+    }
+    #[cfg(windows)]
+    {
+        apply_metadata_windows(file_src_path, file_dst_path)?;
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = copy_outcome;
     }
     Ok(())
 }
 
+const STREAM_COPY_BUFFER_BYTES: usize = 128 * 1024;
+
+/// Copy only the data segments of `file_src_path`, leaving holes unallocated.
+///
+/// The destination is sized with `ftruncate` and data ranges found via
+/// `SEEK_DATA`/`SEEK_HOLE` are written at their offsets. With `should_sync`
+/// the destination handle is synced before it is closed. Returns `Ok(None)`
+/// when the filesystem does not support hole detection, so the caller can
+/// fall back to a regular copy.
 #[cfg(target_os = "linux")]
-fn apply_metadata_linux(file_src_path: &Path, file_dst_path: &Path) -> Result<(), io::Error> {
+fn _copy_file_sparse_linux(
+    file_src_path: &Path,
+    file_dst_path: &Path,
+    buffer_bytes: usize,
+    rate_limiter: Option<&CopyRateLimiter>,
+    mut digester: Option<&mut CopyDigester>,
+    should_sync: bool,
+) -> Result<Option<u64>, io::Error> {
+    use std::os::unix::fs::FileExt;
+    use std::os::unix::io::AsRawFd;
+
+    let file_src = fs::File::open(file_src_path)?;
+    let len_src = file_src.metadata()?.len();
+    let fd_src = file_src.as_raw_fd();
+
+    // SAFETY: `fd_src` is a valid open descriptor owned by `file_src`.
+    let offset_probe = unsafe { libc::lseek(fd_src, 0, libc::SEEK_DATA) };
+    if offset_probe < 0 {
+        let e = io::Error::last_os_error();
+        match e.raw_os_error() {
+            Some(libc::ENXIO) => {}
+            Some(libc::EINVAL) | Some(libc::EOPNOTSUPP) => return Ok(None),
+            _ => return Err(e),
+        }
+    }
+
+    let file_dst = fs::File::create(file_dst_path)?;
+    file_dst.set_len(len_src)?;
+
+    let mut buffer = vec![0_u8; buffer_bytes.max(1)];
+    let mut offset_cursor: i64 = 0;
+    // Holes read back as zeros, so the digest covers them explicitly.
+    let mut offset_digested: u64 = 0;
+    while (offset_cursor as u64) < len_src {
+        // SAFETY: see above; lseek does not touch memory.
+        let offset_data = unsafe { libc::lseek(fd_src, offset_cursor, libc::SEEK_DATA) };
+        if offset_data < 0 {
+            let e = io::Error::last_os_error();
+            if e.raw_os_error() == Some(libc::ENXIO) {
+                break;
+            }
+            return Err(e);
+        }
+        // SAFETY: see above.
+        let offset_hole = unsafe { libc::lseek(fd_src, offset_data, libc::SEEK_HOLE) };
+        if offset_hole < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut offset_copy = offset_data as u64;
+        if let Some(digester) = digester.as_deref_mut() {
+            digester.update_zeros(offset_copy - offset_digested);
+        }
+        while offset_copy < offset_hole as u64 {
+            let len_chunk = (offset_hole as u64 - offset_copy).min(buffer.len() as u64) as usize;
+            let cnt_read = file_src.read_at(&mut buffer[..len_chunk], offset_copy)?;
+            if cnt_read == 0 {
+                break;
+            }
+            if let Some(rate_limiter) = rate_limiter {
+                rate_limiter.acquire(cnt_read as u64);
+            }
+            if let Some(digester) = digester.as_deref_mut() {
+                digester.update(&buffer[..cnt_read]);
+            }
+            file_dst.write_all_at(&buffer[..cnt_read], offset_copy)?;
+            offset_copy += cnt_read as u64;
+        }
+        offset_digested = offset_copy;
+        offset_cursor = offset_hole;
+    }
+    if let Some(digester) = digester {
+        digester.update_zeros(len_src.saturating_sub(offset_digested));
+    }
+    if should_sync {
+        file_dst.sync_all()?;
+    }
+    Ok(Some(len_src))
+}
+
+/// Stream file bytes through reader/writer buffers of `buffer_bytes` each.
+///
+/// Unlike `fs::copy`, this copies no permissions; callers apply metadata.
+/// With a `rate_limiter`, every chunk waits for its byte budget before write;
+/// with a `digester`, every chunk is hashed as it passes through. With
+/// `should_sync`, the destination handle is synced before it is closed.
+fn _copy_file_buffered(
+    file_src_path: &Path,
+    file_dst_path: &Path,
+    buffer_bytes: usize,
+    rate_limiter: Option<&CopyRateLimiter>,
+    mut digester: Option<&mut CopyDigester>,
+    should_sync: bool,
+) -> Result<u64, io::Error> {
+    use std::io::{Read, Write};
+
+    let mut reader = io::BufReader::with_capacity(buffer_bytes, fs::File::open(file_src_path)?);
+    let mut writer = io::BufWriter::with_capacity(buffer_bytes, fs::File::create(file_dst_path)?);
+    if rate_limiter.is_none() && digester.is_none() {
+        let cnt_bytes = io::copy(&mut reader, &mut writer)?;
+        writer.flush()?;
+        if should_sync {
+            writer.get_ref().sync_all()?;
+        }
+        return Ok(cnt_bytes);
+    }
+
+    let mut buffer = vec![0_u8; buffer_bytes];
+    let mut cnt_bytes = 0_u64;
+    loop {
+        let cnt_read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(v) => v,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        if let Some(rate_limiter) = rate_limiter {
+            rate_limiter.acquire(cnt_read as u64);
+        }
+        if let Some(digester) = digester.as_deref_mut() {
+            digester.update(&buffer[..cnt_read]);
+        }
+        writer.write_all(&buffer[..cnt_read])?;
+        cnt_bytes += cnt_read as u64;
+    }
+    writer.flush()?;
+    if should_sync {
+        writer.get_ref().sync_all()?;
+    }
+    Ok(cnt_bytes)
+}
+
+/// Stream a file through xxh3-64, returning its digest.
+pub(crate) fn hash_file_xxh3(file_path: &Path) -> Result<u64, io::Error> {
+    use std::io::Read;
+
+    let mut reader =
+        io::BufReader::with_capacity(STREAM_COPY_BUFFER_BYTES, fs::File::open(file_path)?);
+    let mut hasher = xxhash_rust::xxh3::Xxh3::new();
+    let mut buffer = vec![0_u8; STREAM_COPY_BUFFER_BYTES];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(cnt_read) => hasher.update(&buffer[..cnt_read]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(hasher.digest())
+}
+
+/// Stream a file through BLAKE3, returning its digest.
+pub(crate) fn hash_file_blake3(file_path: &Path) -> Result<[u8; 32], io::Error> {
+    use std::io::Read;
+
+    let mut reader =
+        io::BufReader::with_capacity(STREAM_COPY_BUFFER_BYTES, fs::File::open(file_path)?);
+    let mut hasher = blake3::Hasher::new();
+    let mut buffer = vec![0_u8; STREAM_COPY_BUFFER_BYTES];
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(cnt_read) => {
+                hasher.update(&buffer[..cnt_read]);
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(*hasher.finalize().as_bytes())
+}
+
+/// Hard-link `file_dst_path` to `file_existing_path`, replacing any existing
+/// destination atomically (link to a sibling temp path, then rename).
+pub(crate) fn link_file_atomic(
+    file_existing_path: &Path,
+    file_dst_path: &Path,
+) -> Result<(), io::Error> {
+    let file_tmp_path = derive_atomic_temp_path(file_dst_path);
+    fs::hard_link(file_existing_path, &file_tmp_path)?;
+    fs::rename(&file_tmp_path, file_dst_path).inspect_err(|_| {
+        let _ = fs::remove_file(&file_tmp_path);
+    })?;
+    // Renaming onto another link of the same file is a no-op that keeps the
+    // temp path.
+    if fs::symlink_metadata(&file_tmp_path).is_ok() {
+        fs::remove_file(&file_tmp_path)?;
+    }
+    Ok(())
+}
+
+/// Token-bucket limiter shared by all copy workers of one run.
+///
+/// Tokens refill continuously at `bytes_per_sec` with a burst capacity of one
+/// second. A caller may overdraw the bucket; it then sleeps off the debt
+/// outside the lock, so the aggregate rate across workers stays bounded.
+#[derive(Debug)]
+pub(crate) struct CopyRateLimiter {
+    bytes_per_sec: f64,
+    state: Mutex<CopyRateLimiterState>,
+}
+
+#[derive(Debug)]
+struct CopyRateLimiterState {
+    tokens: f64,
+    instant_refill: Instant,
+}
+
+impl CopyRateLimiter {
+    pub(crate) fn new(bytes_per_sec: u64) -> Self {
+        Self {
+            bytes_per_sec: bytes_per_sec as f64,
+            state: Mutex::new(CopyRateLimiterState {
+                tokens: 0.0,
+                instant_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Block until `cnt_bytes` fit into the rate budget.
+    pub(crate) fn acquire(&self, cnt_bytes: u64) {
+        let duration_wait = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            let instant_now = Instant::now();
+            let secs_elapsed = instant_now
+                .duration_since(state.instant_refill)
+                .as_secs_f64();
+            state.instant_refill = instant_now;
+            state.tokens =
+                (state.tokens + secs_elapsed * self.bytes_per_sec).min(self.bytes_per_sec);
+            state.tokens -= cnt_bytes as f64;
+            if state.tokens >= 0.0 {
+                return;
+            }
+            Duration::from_secs_f64(-state.tokens / self.bytes_per_sec)
+        };
+        std::thread::sleep(duration_wait);
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn apply_metadata_linux(
+    file_src_path: &Path,
+    file_dst_path: &Path,
+    copy_options: &CopyOptionsSpec,
+    copy_outcome: &mut CopyFileOutcome,
+) -> Result<(), io::Error> {
     use filetime::{FileTime, set_file_times};
 
     let src_metadata = fs::metadata(file_src_path)?;
+    if copy_options.should_preserve_ownership {
+        apply_ownership_linux(file_dst_path, &src_metadata, copy_outcome);
+    }
     fs::set_permissions(file_dst_path, src_metadata.permissions())?;
 
     let file_time_access = FileTime::from_last_access_time(&src_metadata);
     let file_time_modify = FileTime::from_last_modification_time(&src_metadata);
     set_file_times(file_dst_path, file_time_access, file_time_modify)?;
 
+    if copy_options.should_preserve_acl {
+        apply_acl_linux(file_src_path, file_dst_path, copy_outcome);
+    }
     copy_xattrs_linux(file_src_path, file_dst_path);
     Ok(())
 }
 
+/// Kernel xattr holding the POSIX access ACL of an inode.
+#[cfg(target_os = "linux")]
+const XATTR_POSIX_ACL_ACCESS: &str = "system.posix_acl_access";
+
+/// Copy the source access ACL; must run after `set_permissions`, which would
+/// otherwise reset the ACL mask entry.
+#[cfg(target_os = "linux")]
+fn apply_acl_linux(file_src_path: &Path, file_dst_path: &Path, copy_outcome: &mut CopyFileOutcome) {
+    let raw_acl = match xattr::get(file_src_path, XATTR_POSIX_ACL_ACCESS) {
+        Ok(Some(v)) => v,
+        Ok(None) => return,
+        Err(e) => {
+            copy_outcome.warnings.push(format!(
+                "Failed to read ACL {} ({e})",
+                file_src_path.display()
+            ));
+            return;
+        }
+    };
+    if let Err(e) = xattr::set(file_dst_path, XATTR_POSIX_ACL_ACCESS, &raw_acl) {
+        copy_outcome.warnings.push(format!(
+            "Failed to preserve ACL {} ({e})",
+            file_dst_path.display()
+        ));
+    }
+}
+
+/// Apply source uid/gid; lack of privilege (EPERM) is reported as a warning.
+#[cfg(target_os = "linux")]
+fn apply_ownership_linux(
+    file_dst_path: &Path,
+    src_metadata: &fs::Metadata,
+    copy_outcome: &mut CopyFileOutcome,
+) {
+    use std::os::unix::fs::{MetadataExt, chown};
+
+    if let Err(e) = chown(
+        file_dst_path,
+        Some(src_metadata.uid()),
+        Some(src_metadata.gid()),
+    ) {
+        copy_outcome.warnings.push(format!(
+            "Failed to preserve ownership {} ({e})",
+            file_dst_path.display()
+        ));
+    }
+}
+
+/// Copy permissions and access/modification times from one directory to another.
+pub(crate) fn copy_dir_metadata(dir_src_path: &Path, dir_dst_path: &Path) -> Result<(), io::Error> {
+    use filetime::{FileTime, set_file_times};
+
+    let src_metadata = fs::metadata(dir_src_path)?;
+    fs::set_permissions(dir_dst_path, src_metadata.permissions())?;
+    set_file_times(
+        dir_dst_path,
+        FileTime::from_last_access_time(&src_metadata),
+        FileTime::from_last_modification_time(&src_metadata),
+    )
+}
+
 #[cfg(target_os = "linux")]
 fn copy_xattrs_linux(file_src_path: &Path, file_dst_path: &Path) {
     let iter_xattr_names = match xattr::list(file_src_path) {
@@ -425,6 +1377,93 @@ fn copy_xattrs_linux(file_src_path: &Path, file_dst_path: &Path) {
     }
 }
 
+/// Copy extended attributes (quarantine flags, Finder info, resource forks).
+///
+/// Best effort: attributes the OS refuses to read or write are skipped.
+#[cfg(target_os = "macos")]
+fn copy_xattrs_macos(file_src_path: &Path, file_dst_path: &Path) {
+    let iter_xattr_names = match xattr::list(file_src_path) {
+        Ok(v) => v,
+        Err(_) => return,
+    };
+
+    for _name in iter_xattr_names {
+        // ACLs are governed by `should_preserve_acl`.
+        if _name
+            .to_str()
+            .is_some_and(|_n| _n.starts_with("system.posix_acl_"))
+        {
+            continue;
+        }
+        let Some(raw_value) = xattr::get(file_src_path, &_name).ok().flatten() else {
+            continue;
+        };
+        let _ = xattr::set(file_dst_path, &_name, &raw_value);
+    }
+}
+
+/// Apply source creation/access/modification times and the hidden,
+/// read-only, system and archive attributes to the destination.
+///
+/// Times go first: they are written through a handle opened with only
+/// `FILE_WRITE_ATTRIBUTES`, which the read-only attribute does not block.
+#[cfg(windows)]
+fn apply_metadata_windows(file_src_path: &Path, file_dst_path: &Path) -> Result<(), io::Error> {
+    use std::os::windows::ffi::OsStrExt;
+    use std::os::windows::fs::{FileTimesExt, OpenOptionsExt};
+    use windows_sys::Win32::Storage::FileSystem::{
+        FILE_ATTRIBUTE_ARCHIVE, FILE_ATTRIBUTE_HIDDEN, FILE_ATTRIBUTE_NORMAL,
+        FILE_ATTRIBUTE_READONLY, FILE_ATTRIBUTE_SYSTEM, FILE_WRITE_ATTRIBUTES, GetFileAttributesW,
+        INVALID_FILE_ATTRIBUTES, SetFileAttributesW,
+    };
+
+    const ATTRIBUTES_PRESERVED: u32 = FILE_ATTRIBUTE_ARCHIVE
+        | FILE_ATTRIBUTE_HIDDEN
+        | FILE_ATTRIBUTE_READONLY
+        | FILE_ATTRIBUTE_SYSTEM;
+
+    let src_metadata = fs::metadata(file_src_path)?;
+    let mut file_times = fs::FileTimes::new()
+        .set_accessed(src_metadata.accessed()?)
+        .set_modified(src_metadata.modified()?);
+    if let Ok(time_created) = src_metadata.created() {
+        file_times = file_times.set_created(time_created);
+    }
+    fs::OpenOptions::new()
+        .access_mode(FILE_WRITE_ATTRIBUTES)
+        .open(file_dst_path)?
+        .set_times(file_times)?;
+
+    let encode_wide = |path: &Path| -> Vec<u16> {
+        path.as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect()
+    };
+    let path_src_wide = encode_wide(file_src_path);
+    let path_dst_wide = encode_wide(file_dst_path);
+
+    let attributes_src = unsafe { GetFileAttributesW(path_src_wide.as_ptr()) };
+    if attributes_src == INVALID_FILE_ATTRIBUTES {
+        return Err(io::Error::last_os_error());
+    }
+    let attributes_dst = unsafe { GetFileAttributesW(path_dst_wide.as_ptr()) };
+    if attributes_dst == INVALID_FILE_ATTRIBUTES {
+        return Err(io::Error::last_os_error());
+    }
+    let attributes_new =
+        match (attributes_dst & !ATTRIBUTES_PRESERVED) | (attributes_src & ATTRIBUTES_PRESERVED) {
+            0 => FILE_ATTRIBUTE_NORMAL,
+            v => v,
+        };
+    if attributes_new != attributes_dst
+        && unsafe { SetFileAttributesW(path_dst_wide.as_ptr(), attributes_new) } == 0
+    {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
 pub(crate) fn is_depth_within_limit(
     depth_value: usize,
     depth_limit: Option<usize>,
@@ -439,6 +1478,60 @@ pub(crate) fn is_depth_within_limit(
     }
 }
 
+pub(crate) fn is_mtime_within_window(
+    mtime: SystemTime,
+    mtime_after: Option<SystemTime>,
+    mtime_before: Option<SystemTime>,
+) -> bool {
+    mtime_after.is_none_or(|_after| mtime >= _after)
+        && mtime_before.is_none_or(|_before| mtime <= _before)
+}
+
+/// Whether a directory entry is hidden (dot-prefixed name, or the Windows
+/// hidden attribute).
+pub(crate) fn is_hidden_entry(entry_name: &str, entry: &fs::DirEntry) -> bool {
+    if entry_name.starts_with('.') {
+        return true;
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::MetadataExt;
+        const FILE_ATTRIBUTE_HIDDEN: u32 = 0x2;
+        if let Ok(metadata) = entry.metadata() {
+            return metadata.file_attributes() & FILE_ATTRIBUTE_HIDDEN != 0;
+        }
+    }
+    #[cfg(not(windows))]
+    let _ = entry;
+    false
+}
+
+/// Whether `path_dst` is a file with the source's length and an mtime within
+/// `mtime_tolerance` of the source's.
+pub(crate) fn is_file_unchanged(
+    path_src: &Path,
+    path_dst: &Path,
+    mtime_tolerance: Duration,
+) -> bool {
+    let (Ok(metadata_src), Ok(metadata_dst)) =
+        (fs::metadata(path_src), fs::symlink_metadata(path_dst))
+    else {
+        return false;
+    };
+    if !metadata_dst.file_type().is_file() || metadata_src.len() != metadata_dst.len() {
+        return false;
+    }
+    match (metadata_src.modified(), metadata_dst.modified()) {
+        (Ok(mtime_src), Ok(mtime_dst)) => {
+            let mtime_diff = mtime_src
+                .duration_since(mtime_dst)
+                .unwrap_or_else(|e| e.duration());
+            mtime_diff <= mtime_tolerance
+        }
+        _ => false,
+    }
+}
+
 pub(crate) fn calculate_worker_limit(workers_max: Option<usize>) -> usize {
     let cpu_count = std::thread::available_parallelism()
         .map(|v| v.get())
@@ -460,9 +1553,15 @@ pub(crate) fn calculate_worker_limit(workers_max: Option<usize>) -> usize {
 /// - `should_keep_tree`:
 ///   - `true`: Preserve the directory structure relative to `path_dir_src`.
 ///   - `false`: Copy item directly into `path_dir_dst`.
+/// - `filename_normalization`: Unicode form applied to every component of the
+///   relative destination path (so children match their normalized parents).
+/// - `should_sanitize_names`: Apply [`sanitize_filename`] to every component
+///   of the relative destination path, after normalization.
 ///
 /// # Returns
-/// - `PathBuf`: The derived destination path.
+/// - `PathBuf`: The derived destination path. On Windows both roots are
+///   extended-length paths (see [`to_extended_length_path`]), so the result
+///   carries the `\\?\` prefix too.
 ///
 /// # Examples
 /// ```ignore
@@ -473,11 +1572,11 @@ pub(crate) fn calculate_worker_limit(workers_max: Option<usize>) -> usize {
 /// let path_dir_dst = Path::new("/destination/dir");
 ///
 /// // If keeping tree structure
-/// let dest_path = derive_destination_path(path_src, path_item_name, path_dir_src, path_dir_dst, true);
+/// let dest_path = derive_destination_path(path_src, path_item_name, path_dir_src, path_dir_dst, true, None, false);
 /// assert_eq!(dest_path, Path::new("/destination/dir/file.txt"));
 ///
 /// // If not keeping tree structure
-/// let dest_path = derive_destination_path(path_src, path_item_name, path_dir_src, path_dir_dst, false);
+/// let dest_path = derive_destination_path(path_src, path_item_name, path_dir_src, path_dir_dst, false, None, false);
 /// assert_eq!(dest_path, Path::new("/destination/dir/file.txt"));
 /// ```
 pub(crate) fn derive_destination_path(
@@ -486,15 +1585,140 @@ pub(crate) fn derive_destination_path(
     path_dir_src: &Path,
     path_dir_dst: &Path,
     should_keep_tree: bool,
+    filename_normalization: Option<CopyUnicodeForm>,
+    should_sanitize_names: bool,
 ) -> PathBuf {
-    if should_keep_tree {
-        return path_dir_dst.join(
-            path_src
-                .strip_prefix(path_dir_src)
-                .unwrap_or(Path::new(path_item_name)),
-        );
+    let path_rel = if should_keep_tree {
+        path_src
+            .strip_prefix(path_dir_src)
+            .unwrap_or(Path::new(path_item_name))
+    } else {
+        Path::new(path_item_name)
+    };
+    if filename_normalization.is_none() && !should_sanitize_names {
+        return path_dir_dst.join(path_rel);
+    }
+    path_dir_dst.join(
+        path_rel
+            .components()
+            .map(|_part| match _part.as_os_str().to_str() {
+                Some(_name) => PathBuf::from(derive_destination_filename(
+                    _name,
+                    filename_normalization,
+                    should_sanitize_names,
+                )),
+                // Names that are not valid UTF-8 are kept byte-for-byte.
+                None => PathBuf::from(_part.as_os_str()),
+            })
+            .collect::<PathBuf>(),
+    )
+}
+
+/// Destination name of one path component: normalized first, then sanitized.
+fn derive_destination_filename(
+    name: &str,
+    filename_normalization: Option<CopyUnicodeForm>,
+    should_sanitize_names: bool,
+) -> String {
+    let name_dst = match filename_normalization {
+        Some(form) => normalize_filename(name, form),
+        None => name.to_string(),
+    };
+    if should_sanitize_names {
+        sanitize_filename(&name_dst)
+    } else {
+        name_dst
+    }
+}
+
+/// Normalize one file name to the given Unicode form.
+pub(crate) fn normalize_filename(name: &str, form: CopyUnicodeForm) -> String {
+    use unicode_normalization::UnicodeNormalization;
+
+    match form {
+        CopyUnicodeForm::Nfc => name.nfc().collect(),
+        CopyUnicodeForm::Nfd => name.nfd().collect(),
+    }
+}
+
+/// Characters Windows rejects in file names, besides control characters.
+const WINDOWS_ILLEGAL_NAME_CHARS: [char; 9] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+/// Device names Windows reserves regardless of case or extension.
+const WINDOWS_RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Make one file name valid on Windows.
+///
+/// Illegal and control characters become `_`, as do trailing dots and
+/// spaces; a reserved device name (`CON`, `nul.txt`, ...) gets `_` appended
+/// to its stem. Valid names are returned unchanged.
+pub(crate) fn sanitize_filename(name: &str) -> String {
+    let mut name_new: String = name
+        .chars()
+        .map(|_ch| {
+            if _ch.is_control() || WINDOWS_ILLEGAL_NAME_CHARS.contains(&_ch) {
+                '_'
+            } else {
+                _ch
+            }
+        })
+        .collect();
+    let len_trimmed = name_new.trim_end_matches(['.', ' ']).len();
+    if len_trimmed < name_new.len() && name_new != "." && name_new != ".." {
+        let cnt_trailing = name_new[len_trimmed..].chars().count();
+        name_new.truncate(len_trimmed);
+        name_new.extend(std::iter::repeat_n('_', cnt_trailing));
+    }
+    let len_stem = name_new.find('.').unwrap_or(name_new.len());
+    if WINDOWS_RESERVED_NAMES
+        .iter()
+        .any(|_reserved| _reserved.eq_ignore_ascii_case(&name_new[..len_stem]))
+    {
+        name_new.insert(len_stem, '_');
+    }
+    name_new
+}
+
+// #endregion
+////////////////////////////////////////////////////////////////////////////////
+// #region Serde
+
+/// `serialize_with` helpers writing paths as strings; bytes that are not
+/// valid UTF-8 become U+FFFD, so serializing a report never fails.
+#[cfg(feature = "serde")]
+pub(crate) mod serde_path {
+    use std::path::{Path, PathBuf};
+
+    use serde::Serializer;
+    use serde::ser::SerializeSeq;
+
+    pub(crate) fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&path.to_string_lossy())
+    }
+
+    pub(crate) fn serialize_option<S: Serializer>(
+        path: &Option<PathBuf>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match path {
+            Some(_path) => serializer.serialize_some(&_path.to_string_lossy()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn serialize_pairs<S: Serializer>(
+        pairs: &[(PathBuf, String)],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(pairs.len()))?;
+        for (_path, _value) in pairs {
+            seq.serialize_element(&(_path.to_string_lossy(), _value))?;
+        }
+        seq.end()
     }
-    path_dir_dst.join(path_item_name)
 }
 
 // #endregion
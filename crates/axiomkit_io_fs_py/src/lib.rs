@@ -1,10 +1,15 @@
 use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use axiomkit_io_fs::{
-    CopyDepthLimitMode, CopyDirectoryConflictMode, CopyErrorRecord, CopyFileConflictMode,
-    CopyOptionsSpec, CopyPatternMode, CopyReport, CopySymlinkMode, CopyTreeError, copy_tree,
+    CopyDepthLimitMode, CopyDirectoryConflictMode, CopyDstSymlinkConflictMode, CopyErrorRecord,
+    CopyFileConflictMode, CopyHashAlgorithm, CopyOptionsSpec, CopyPatternMode, CopyPlanEntry,
+    CopyReport, CopySpecialFileMode, CopySymlinkMode, CopyTreeError, CopyUnicodeForm, copy_entry,
+    copy_tree,
+};
+use pyo3::exceptions::{
+    PyFileNotFoundError, PyIsADirectoryError, PyNotADirectoryError, PyOSError, PyValueError,
 };
-use pyo3::exceptions::{PyNotADirectoryError, PyOSError, PyValueError};
 use pyo3::prelude::*;
 
 pub const BRIDGE_ABI_VERSION: u64 = 1;
@@ -17,6 +22,10 @@ struct PySpecCopyError {
     #[pyo3(get)]
     path: String,
     #[pyo3(get)]
+    source_path: Option<String>,
+    #[pyo3(get)]
+    kind: String,
+    #[pyo3(get)]
     exception: String,
 }
 
@@ -24,11 +33,36 @@ impl From<CopyErrorRecord> for PySpecCopyError {
     fn from(error_record: CopyErrorRecord) -> Self {
         Self {
             path: error_record.path.to_string_lossy().to_string(),
+            source_path: error_record
+                .source_path
+                .map(|_path| _path.to_string_lossy().to_string()),
+            kind: error_record.kind.as_str().to_string(),
             exception: error_record.exception,
         }
     }
 }
 
+#[pyclass(name = "CopyPlanEntry")]
+#[derive(Debug, Clone)]
+struct PyCopyPlanEntry {
+    #[pyo3(get)]
+    src_path: String,
+    #[pyo3(get)]
+    dst_path: String,
+    #[pyo3(get)]
+    action: String,
+}
+
+impl From<CopyPlanEntry> for PyCopyPlanEntry {
+    fn from(plan_entry: CopyPlanEntry) -> Self {
+        Self {
+            src_path: plan_entry.src_path.to_string_lossy().to_string(),
+            dst_path: plan_entry.dst_path.to_string_lossy().to_string(),
+            action: plan_entry.action.as_str().to_string(),
+        }
+    }
+}
+
 #[pyclass(name = "CopyReport")]
 #[derive(Debug, Clone)]
 struct PyReportCopy {
@@ -41,9 +75,25 @@ struct PyReportCopy {
     #[pyo3(get)]
     cnt_skipped: u64,
     #[pyo3(get)]
+    cnt_verified: u64,
+    #[pyo3(get)]
+    cnt_unchanged: u64,
+    #[pyo3(get)]
+    cnt_linked: u64,
+    #[pyo3(get)]
+    cnt_symlinks: u64,
+    #[pyo3(get)]
+    bytes_copied: u64,
+    #[pyo3(get)]
     warnings: Vec<String>,
     #[pyo3(get)]
     errors: Vec<PySpecCopyError>,
+    #[pyo3(get)]
+    plan: Vec<PyCopyPlanEntry>,
+    #[pyo3(get)]
+    manifest: Vec<(String, String)>,
+    #[pyo3(get)]
+    stats_by_ext: BTreeMap<String, (u64, u64)>,
 }
 
 impl From<CopyReport> for PyReportCopy {
@@ -53,12 +103,28 @@ impl From<CopyReport> for PyReportCopy {
             cnt_scanned: report_copy.cnt_scanned,
             cnt_copied: report_copy.cnt_copied,
             cnt_skipped: report_copy.cnt_skipped,
+            cnt_verified: report_copy.cnt_verified,
+            cnt_unchanged: report_copy.cnt_unchanged,
+            cnt_linked: report_copy.cnt_linked,
+            cnt_symlinks: report_copy.cnt_symlinks,
+            bytes_copied: report_copy.bytes_copied,
             warnings: report_copy.warnings,
             errors: report_copy
                 .errors
                 .into_iter()
                 .map(PySpecCopyError::from)
                 .collect(),
+            plan: report_copy
+                .plan
+                .into_iter()
+                .map(PyCopyPlanEntry::from)
+                .collect(),
+            manifest: report_copy
+                .manifest
+                .into_iter()
+                .map(|(_path, _digest)| (_path.to_string_lossy().to_string(), _digest))
+                .collect(),
+            stats_by_ext: report_copy.stats_by_ext,
         }
     }
 }
@@ -81,6 +147,11 @@ impl PyReportCopy {
         counts.insert("cnt_scanned".to_string(), self.cnt_scanned);
         counts.insert("cnt_copied".to_string(), self.cnt_copied);
         counts.insert("cnt_skipped".to_string(), self.cnt_skipped);
+        counts.insert("cnt_verified".to_string(), self.cnt_verified);
+        counts.insert("cnt_unchanged".to_string(), self.cnt_unchanged);
+        counts.insert("cnt_linked".to_string(), self.cnt_linked);
+        counts.insert("cnt_symlinks".to_string(), self.cnt_symlinks);
+        counts.insert("bytes_copied".to_string(), self.bytes_copied);
         counts.insert("cnt_errors".to_string(), self.error_count() as u64);
         counts.insert("cnt_warnings".to_string(), self.warning_count() as u64);
         counts
@@ -109,8 +180,9 @@ fn parse_rule_pattern(value: &str) -> PyResult<CopyPatternMode> {
         "glob" => Ok(CopyPatternMode::Glob),
         "regex" => Ok(CopyPatternMode::Regex),
         "literal" => Ok(CopyPatternMode::Literal),
+        "gitignore" => Ok(CopyPatternMode::Gitignore),
         _ => Err(PyValueError::new_err(format!(
-            "Invalid pattern strategy: `{value}`. Expected one of: ['glob', 'regex', 'literal']"
+            "Invalid pattern strategy: `{value}`. Expected one of: ['glob', 'regex', 'literal', 'gitignore']"
         ))),
     }
 }
@@ -158,19 +230,83 @@ fn parse_rule_depth_limit(value: &str) -> PyResult<CopyDepthLimitMode> {
     }
 }
 
+fn parse_rule_special_file(value: &str) -> PyResult<CopySpecialFileMode> {
+    match value {
+        "skip" => Ok(CopySpecialFileMode::Skip),
+        "error" => Ok(CopySpecialFileMode::Error),
+        "attempt_copy" => Ok(CopySpecialFileMode::AttemptCopy),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid special file strategy: `{value}`. Expected one of: ['skip', 'error', 'attempt_copy']"
+        ))),
+    }
+}
+
+fn parse_rule_conflict_symlink_dst(value: &str) -> PyResult<CopyDstSymlinkConflictMode> {
+    match value {
+        "error" => Ok(CopyDstSymlinkConflictMode::Error),
+        "replace" => Ok(CopyDstSymlinkConflictMode::Replace),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid destination symlink conflict strategy: `{value}`. Expected one of: ['error', 'replace']"
+        ))),
+    }
+}
+
+fn parse_manifest_algorithm(value: &str) -> PyResult<CopyHashAlgorithm> {
+    match value {
+        "sha256" => Ok(CopyHashAlgorithm::Sha256),
+        "blake3" => Ok(CopyHashAlgorithm::Blake3),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid hash algorithm: `{value}`. Expected one of: ['sha256', 'blake3']"
+        ))),
+    }
+}
+
+fn parse_filename_normalization(value: &str) -> PyResult<CopyUnicodeForm> {
+    match value {
+        "nfc" => Ok(CopyUnicodeForm::Nfc),
+        "nfd" => Ok(CopyUnicodeForm::Nfd),
+        _ => Err(PyValueError::new_err(format!(
+            "Invalid Unicode form: `{value}`. Expected one of: ['nfc', 'nfd']"
+        ))),
+    }
+}
+
+fn derive_system_time_from_epoch_seconds(value: i64) -> PyResult<SystemTime> {
+    let offset = Duration::from_secs(value.unsigned_abs());
+    let system_time = if value >= 0 {
+        UNIX_EPOCH.checked_add(offset)
+    } else {
+        UNIX_EPOCH.checked_sub(offset)
+    };
+    system_time.ok_or_else(|| {
+        PyValueError::new_err(format!(
+            "Epoch seconds out of range for this platform: {value}"
+        ))
+    })
+}
+
 fn map_copy_tree_error(exception: CopyTreeError) -> PyErr {
     match exception {
         CopyTreeError::SourceNotDirectory(path_src) => PyNotADirectoryError::new_err(format!(
             "Source is not a directory: {}",
             path_src.display()
         )),
+        CopyTreeError::SourceNotFile(path_src) if path_src.is_dir() => {
+            PyIsADirectoryError::new_err(format!("Source is not a file: {}", path_src.display()))
+        }
+        CopyTreeError::SourceNotFile(path_src) => {
+            PyFileNotFoundError::new_err(format!("Source is not a file: {}", path_src.display()))
+        }
         CopyTreeError::DestinationInitFailed { path, message } => PyOSError::new_err(format!(
             "Failed to initialize destination {}: {message}",
             path.display()
         )),
-        CopyTreeError::InvalidDepthLimit(message) | CopyTreeError::InvalidPattern(message) => {
-            PyValueError::new_err(message)
+        CopyTreeError::EntryFailed { path, message } => {
+            PyOSError::new_err(format!("Failed to copy {}: {message}", path.display()))
         }
+        CopyTreeError::InvalidDepthLimit(message)
+        | CopyTreeError::InvalidPattern(message)
+        | CopyTreeError::InvalidOption(message) => PyValueError::new_err(message),
         CopyTreeError::SourceDestinationOverlap {
             source,
             destination,
@@ -198,7 +334,36 @@ fn map_copy_tree_error(exception: CopyTreeError) -> PyErr {
     rule_depth_limit = "at_most",
     workers_max = None,
     should_keep_tree = true,
-    should_dry_run = false
+    should_dry_run = false,
+    mtime_after = None,
+    mtime_before = None,
+    should_match_full_path = false,
+    symlink_follow_max = None,
+    should_preserve_ownership = false,
+    should_preserve_dir_metadata = false,
+    should_copy_atomically = false,
+    should_fsync = false,
+    copy_buffer_bytes = None,
+    should_preserve_sparse = false,
+    throughput_max_bytes_per_sec = None,
+    should_skip_hidden = false,
+    should_preserve_acl = false,
+    should_rewrite_symlinks_relative = false,
+    should_collect_plan = false,
+    should_verify = false,
+    manifest_algorithm = None,
+    should_copy_incrementally = false,
+    bytes_budget_max = None,
+    files_max = None,
+    rule_special_file = "skip",
+    rule_conflict_symlink_dst = "error",
+    filename_normalization = None,
+    should_collect_ext_stats = false,
+    should_dedup_destination = false,
+    should_force_overwrite_readonly = false,
+    mtime_tolerance_ms = 0,
+    should_sanitize_dest_names = false,
+    should_fail_fast = false
 ))]
 #[allow(clippy::too_many_arguments)]
 fn copy_tree_py(
@@ -218,6 +383,35 @@ fn copy_tree_py(
     workers_max: Option<usize>,
     should_keep_tree: bool,
     should_dry_run: bool,
+    mtime_after: Option<i64>,
+    mtime_before: Option<i64>,
+    should_match_full_path: bool,
+    symlink_follow_max: Option<usize>,
+    should_preserve_ownership: bool,
+    should_preserve_dir_metadata: bool,
+    should_copy_atomically: bool,
+    should_fsync: bool,
+    copy_buffer_bytes: Option<usize>,
+    should_preserve_sparse: bool,
+    throughput_max_bytes_per_sec: Option<u64>,
+    should_skip_hidden: bool,
+    should_preserve_acl: bool,
+    should_rewrite_symlinks_relative: bool,
+    should_collect_plan: bool,
+    should_verify: bool,
+    manifest_algorithm: Option<&str>,
+    should_copy_incrementally: bool,
+    bytes_budget_max: Option<u64>,
+    files_max: Option<u64>,
+    rule_special_file: &str,
+    rule_conflict_symlink_dst: &str,
+    filename_normalization: Option<&str>,
+    should_collect_ext_stats: bool,
+    should_dedup_destination: bool,
+    should_force_overwrite_readonly: bool,
+    mtime_tolerance_ms: u64,
+    should_sanitize_dest_names: bool,
+    should_fail_fast: bool,
 ) -> PyResult<PyReportCopy> {
     let copy_options = CopyOptionsSpec {
         patterns_include_files,
@@ -233,6 +427,44 @@ fn copy_tree_py(
         workers_max,
         should_keep_tree,
         should_dry_run,
+        mtime_after: mtime_after
+            .map(derive_system_time_from_epoch_seconds)
+            .transpose()?,
+        mtime_before: mtime_before
+            .map(derive_system_time_from_epoch_seconds)
+            .transpose()?,
+        should_match_full_path,
+        symlink_follow_max,
+        should_preserve_ownership,
+        should_preserve_dir_metadata,
+        should_copy_atomically,
+        should_fsync,
+        copy_buffer_bytes,
+        should_preserve_sparse,
+        throughput_max_bytes_per_sec,
+        should_skip_hidden,
+        should_preserve_acl,
+        should_rewrite_symlinks_relative,
+        should_collect_plan,
+        should_verify,
+        manifest_algorithm: manifest_algorithm
+            .map(parse_manifest_algorithm)
+            .transpose()?,
+        should_copy_incrementally,
+        bytes_budget_max,
+        files_max,
+        rule_special_file: parse_rule_special_file(rule_special_file)?,
+        rule_conflict_symlink_dst: parse_rule_conflict_symlink_dst(rule_conflict_symlink_dst)?,
+        filename_normalization: filename_normalization
+            .map(parse_filename_normalization)
+            .transpose()?,
+        filter: None,
+        should_collect_ext_stats,
+        should_dedup_destination,
+        should_force_overwrite_readonly,
+        mtime_tolerance_ms,
+        should_sanitize_dest_names,
+        should_fail_fast,
     };
 
     let report = py.allow_threads(|| copy_tree(dir_source, dir_destination, copy_options));
@@ -240,10 +472,84 @@ fn copy_tree_py(
     Ok(PyReportCopy::from(report))
 }
 
+#[pyfunction(name = "copy_entry")]
+#[pyo3(signature = (
+    file_source,
+    dir_destination,
+    rule_conflict_file = "skip",
+    rule_symlink = "copy_symlinks",
+    should_dry_run = false,
+    should_preserve_ownership = false,
+    should_copy_atomically = false,
+    should_fsync = false,
+    copy_buffer_bytes = None,
+    should_preserve_sparse = false,
+    throughput_max_bytes_per_sec = None,
+    should_preserve_acl = false,
+    should_verify = false,
+    manifest_algorithm = None,
+    should_copy_incrementally = false,
+    rule_special_file = "skip",
+    rule_conflict_symlink_dst = "error",
+    filename_normalization = None
+))]
+#[allow(clippy::too_many_arguments)]
+fn copy_entry_py(
+    py: Python<'_>,
+    file_source: String,
+    dir_destination: String,
+    rule_conflict_file: &str,
+    rule_symlink: &str,
+    should_dry_run: bool,
+    should_preserve_ownership: bool,
+    should_copy_atomically: bool,
+    should_fsync: bool,
+    copy_buffer_bytes: Option<usize>,
+    should_preserve_sparse: bool,
+    throughput_max_bytes_per_sec: Option<u64>,
+    should_preserve_acl: bool,
+    should_verify: bool,
+    manifest_algorithm: Option<&str>,
+    should_copy_incrementally: bool,
+    rule_special_file: &str,
+    rule_conflict_symlink_dst: &str,
+    filename_normalization: Option<&str>,
+) -> PyResult<PyReportCopy> {
+    let copy_options = CopyOptionsSpec {
+        rule_conflict_file: parse_rule_conflict_file(rule_conflict_file)?,
+        rule_symlink: parse_rule_symlink(rule_symlink)?,
+        should_dry_run,
+        should_preserve_ownership,
+        should_copy_atomically,
+        should_fsync,
+        copy_buffer_bytes,
+        should_preserve_sparse,
+        throughput_max_bytes_per_sec,
+        should_preserve_acl,
+        should_verify,
+        manifest_algorithm: manifest_algorithm
+            .map(parse_manifest_algorithm)
+            .transpose()?,
+        should_copy_incrementally,
+        rule_special_file: parse_rule_special_file(rule_special_file)?,
+        rule_conflict_symlink_dst: parse_rule_conflict_symlink_dst(rule_conflict_symlink_dst)?,
+        filename_normalization: filename_normalization
+            .map(parse_filename_normalization)
+            .transpose()?,
+        ..CopyOptionsSpec::default()
+    };
+
+    let report = py.allow_threads(|| copy_entry(file_source, dir_destination, copy_options));
+    let report = report.map_err(map_copy_tree_error)?;
+    Ok(PyReportCopy::from(report))
+}
+
 pub fn register_fs_bindings(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<PySpecCopyError>()?;
+    module.add_class::<PyCopyPlanEntry>()?;
     module.add_class::<PyReportCopy>()?;
     module.add_function(wrap_pyfunction!(copy_tree_py, module)?)?;
+    module.add_function(wrap_pyfunction!(copy_entry_py, module)?)?;
     Ok(())
 }
 
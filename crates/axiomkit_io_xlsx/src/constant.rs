@@ -10,8 +10,38 @@ pub const NROWS_SHEET_MAX: usize = 1_048_576;
 pub const NCOLS_SHEET_MAX: usize = 16_384;
 /// Excel sheet name maximum length.
 pub const LEN_SHEET_NAME_MAX: usize = 31;
+/// Excel page header/footer text maximum length.
+pub const LEN_HEADER_FOOTER_MAX: usize = 255;
 /// Characters not allowed in sheet names.
 pub const SHEET_NAME_ILLEGAL_CHRS: [&str; 7] = ["*", ":", "?", "/", "\\", "[", "]"];
+/// Excel serial date of the Unix epoch (1970-01-01) in the 1900 date system.
+pub const EXCEL_SERIAL_UNIX_EPOCH: f64 = 25_569.0;
+/// First Excel serial date unaffected by the 1900 leap-year bug (1900-03-01).
+pub const EXCEL_SERIAL_MIN: f64 = 61.0;
+/// Excel serial date one day past the last representable date (9999-12-31).
+pub const EXCEL_SERIAL_MAX_EXCLUSIVE: f64 = 2_958_466.0;
+/// Largest integer magnitude (2^53) that an `f64` cell value represents exactly.
+pub const INTEGER_F64_EXACT_MAX: u128 = 1 << 53;
+/// Largest cell indent level Excel supports; larger values are clamped.
+pub const FORMAT_INDENT_MAX: i64 = 15;
+/// Largest fill pattern index (`Gray0625`); larger values are ignored.
+pub const FORMAT_PATTERN_MAX: u8 = 18;
+/// Largest accepted `ScientificPolicy::precision`.
+pub const SCIENTIFIC_PRECISION_MAX: usize = 20;
+/// Currency symbol used when `currency_symbol` is unset.
+pub const CURRENCY_SYMBOL_DEFAULT: &str = "$";
+/// Currency decimal places used when `currency_decimals` is unset.
+pub const CURRENCY_DECIMALS_DEFAULT: usize = 2;
+/// Background color of shaded body rows when `band_color` is unset.
+pub const BAND_COLOR_DEFAULT: &str = "#F2F2F2";
+/// Integer number format used by `XlsxWriteOptions::should_use_integer_thousands_sep`.
+pub const NUM_FORMAT_INTEGER_THOUSANDS_SEP: &str = "#,##0";
+/// Largest accepted `currency_decimals`.
+pub const CURRENCY_DECIMALS_MAX: usize = 20;
+/// Percent decimal places used when `percent_decimals` is unset.
+pub const PERCENT_DECIMALS_DEFAULT: usize = 2;
+/// Largest accepted `percent_decimals`.
+pub const PERCENT_DECIMALS_MAX: usize = 20;
 
 /// Canonical format preset keys.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
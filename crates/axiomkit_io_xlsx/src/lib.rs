@@ -13,13 +13,16 @@ pub mod writer;
 
 pub use constant::{LEN_SHEET_NAME_MAX, NCOLS_SHEET_MAX, NROWS_SHEET_MAX, SHEET_NAME_ILLEGAL_CHRS};
 pub use spec::{
-    AutofitMode, AutofitPolicy, CellBorder, CellFormatPatch, IntegerCoerceMode, ScientificPolicy,
-    ScientificScope, SheetHorizontalMerge, SheetSlice, XlsxReport, XlsxRowChunkPolicy,
-    XlsxValuePolicy, XlsxWriteOptions,
+    AutofitMode, AutofitPolicy, CellBorder, CellFormatPatch, ConditionalFormatKind,
+    ConditionalFormatRule, DocumentProperties, ImagePlacement, ImageSource, IntegerCoerceMode,
+    PageSetup, RichTextRun, RowGroup, ScientificPolicy, ScientificScope, SheetBlock,
+    SheetHorizontalMerge, SheetSlice, SheetSplitMode, SheetVerticalMerge, SummaryAgg, XlsxReport,
+    XlsxRowChunkPolicy, XlsxValuePolicy, XlsxWriteOptions,
 };
 pub use util::{
     apply_vertical_run_text_blankout, calculate_row_chunk_size, create_horizontal_merge_tracker,
-    derive_contiguous_ranges, plan_horizontal_merges, plan_sheet_slices,
+    create_percent_num_format, create_sheet_identifier_from_template, derive_contiguous_ranges,
+    derive_currency_format, plan_horizontal_merges, plan_sheet_slices, plan_vertical_merges,
     plan_vertical_visual_merge_borders, sanitize_sheet_name,
 };
 pub use writer::{XlsxRecordBatch, XlsxRecordBatchResult, XlsxSheetWriteOptions, XlsxWriter};
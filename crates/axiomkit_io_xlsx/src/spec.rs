@@ -1,6 +1,7 @@
 //! Shared XLSX specification models.
 
 use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 ////////////////////////////////////////////////////////////////////////////////
 // #region CellFormatSpecification
@@ -16,6 +17,10 @@ pub struct CellFormatPatch {
     pub bold: Option<bool>,
     /// Italic style.
     pub italic: Option<bool>,
+    /// Single underline.
+    pub underline: Option<bool>,
+    /// Strikethrough; maps to the xlsxwriter `font_strikeout` property.
+    pub strikethrough: Option<bool>,
 
     /// Horizontal alignment.
     pub align: Option<String>,
@@ -25,6 +30,12 @@ pub struct CellFormatPatch {
     pub border: Option<i64>,
     /// Text wrap.
     pub text_wrap: Option<bool>,
+    /// Shrink text to fit the cell width; maps to the xlsxwriter `shrink` property.
+    pub shrink_to_fit: Option<bool>,
+    /// Indent level, clamped to `0..=15`.
+    pub indent: Option<i64>,
+    /// Text rotation in degrees: `-90..=90`, or `270` for stacked vertical text.
+    pub rotation: Option<i16>,
 
     /// Top border override.
     pub top: Option<i64>,
@@ -34,11 +45,22 @@ pub struct CellFormatPatch {
     pub left: Option<i64>,
     /// Right border override.
     pub right: Option<i64>,
+    /// Diagonal border style; maps to the xlsxwriter `diag_border` property.
+    pub diagonal: Option<i64>,
+    /// Diagonal direction: `1` up, `2` down, `3` both; maps to `diag_type`.
+    pub diagonal_type: Option<u8>,
 
     /// Number format code.
     pub num_format: Option<String>,
     /// Background fill color.
     pub bg_color: Option<String>,
+    /// Pattern foreground color; alone it gives a solid fill.
+    pub fg_color: Option<String>,
+    /// Fill pattern index in `0..=18` (xlsxwriter numbering, `1` = solid).
+    /// Larger values are ignored with a report warning.
+    ///
+    /// Gradient fills are not supported: `rust_xlsxwriter` cannot write them.
+    pub pattern: Option<u8>,
     /// Font color.
     pub font_color: Option<String>,
 }
@@ -54,6 +76,9 @@ pub enum CellFormatValue {
     Boolean(bool),
 }
 
+/// One rich text run: a font format patch and its non-empty text.
+pub type RichTextRun = (CellFormatPatch, String);
+
 /// Normalized cell value during conversion/write pipeline.
 #[derive(Debug, Clone, PartialEq)]
 pub enum CellValue {
@@ -63,6 +88,18 @@ pub enum CellValue {
     String(String),
     /// Numeric value.
     Number(f64),
+    /// Integer beyond 2^53 in magnitude; written as exact decimal text.
+    Integer(i128),
+    /// Boolean value.
+    Boolean(bool),
+    /// Calendar date as an Excel serial number.
+    Date(f64),
+    /// Date and time as an Excel serial number.
+    DateTime(f64),
+    /// Text runs, each with its own font format patch.
+    RichText(Vec<RichTextRun>),
+    /// Formula text starting with `=`, written as a live formula.
+    Formula(String),
 }
 
 impl CellFormatPatch {
@@ -78,16 +115,25 @@ impl CellFormatPatch {
             font_size: other.font_size.or(self.font_size),
             bold: other.bold.or(self.bold),
             italic: other.italic.or(self.italic),
+            underline: other.underline.or(self.underline),
+            strikethrough: other.strikethrough.or(self.strikethrough),
             align: other.align.clone().or_else(|| self.align.clone()),
             valign: other.valign.clone().or_else(|| self.valign.clone()),
             border: other.border.or(self.border),
             text_wrap: other.text_wrap.or(self.text_wrap),
+            shrink_to_fit: other.shrink_to_fit.or(self.shrink_to_fit),
+            indent: other.indent.or(self.indent),
+            rotation: other.rotation.or(self.rotation),
             top: other.top.or(self.top),
             bottom: other.bottom.or(self.bottom),
             left: other.left.or(self.left),
             right: other.right.or(self.right),
+            diagonal: other.diagonal.or(self.diagonal),
+            diagonal_type: other.diagonal_type.or(self.diagonal_type),
             num_format: other.num_format.clone().or_else(|| self.num_format.clone()),
             bg_color: other.bg_color.clone().or_else(|| self.bg_color.clone()),
+            fg_color: other.fg_color.clone().or_else(|| self.fg_color.clone()),
+            pattern: other.pattern.or(self.pattern),
             font_color: other.font_color.clone().or_else(|| self.font_color.clone()),
         }
     }
@@ -111,6 +157,15 @@ impl CellFormatPatch {
         if let Some(value) = self.italic {
             format_map.insert("italic".to_string(), CellFormatValue::Boolean(value));
         }
+        if let Some(value) = self.underline {
+            format_map.insert("underline".to_string(), CellFormatValue::Boolean(value));
+        }
+        if let Some(value) = self.strikethrough {
+            format_map.insert(
+                "font_strikeout".to_string(),
+                CellFormatValue::Boolean(value),
+            );
+        }
 
         if let Some(value) = &self.align {
             format_map.insert("align".to_string(), CellFormatValue::String(value.clone()));
@@ -124,6 +179,18 @@ impl CellFormatPatch {
         if let Some(value) = self.text_wrap {
             format_map.insert("text_wrap".to_string(), CellFormatValue::Boolean(value));
         }
+        if let Some(value) = self.shrink_to_fit {
+            format_map.insert("shrink".to_string(), CellFormatValue::Boolean(value));
+        }
+        if let Some(value) = self.indent {
+            format_map.insert("indent".to_string(), CellFormatValue::Integer(value));
+        }
+        if let Some(value) = self.rotation {
+            format_map.insert(
+                "rotation".to_string(),
+                CellFormatValue::Integer(i64::from(value)),
+            );
+        }
 
         if let Some(value) = self.top {
             format_map.insert("top".to_string(), CellFormatValue::Integer(value));
@@ -137,6 +204,15 @@ impl CellFormatPatch {
         if let Some(value) = self.right {
             format_map.insert("right".to_string(), CellFormatValue::Integer(value));
         }
+        if let Some(value) = self.diagonal {
+            format_map.insert("diag_border".to_string(), CellFormatValue::Integer(value));
+        }
+        if let Some(value) = self.diagonal_type {
+            format_map.insert(
+                "diag_type".to_string(),
+                CellFormatValue::Integer(i64::from(value)),
+            );
+        }
 
         if let Some(value) = &self.num_format {
             format_map.insert(
@@ -150,6 +226,18 @@ impl CellFormatPatch {
                 CellFormatValue::String(value.clone()),
             );
         }
+        if let Some(value) = &self.fg_color {
+            format_map.insert(
+                "fg_color".to_string(),
+                CellFormatValue::String(value.clone()),
+            );
+        }
+        if let Some(value) = self.pattern {
+            format_map.insert(
+                "pattern".to_string(),
+                CellFormatValue::Integer(i64::from(value)),
+            );
+        }
         if let Some(value) = &self.font_color {
             format_map.insert(
                 "font_color".to_string(),
@@ -214,6 +302,14 @@ pub struct XlsxValuePolicy {
     pub neginf_str: String,
     /// Integer conversion mode.
     pub integer_coerce: IntegerCoerceMode,
+    /// IANA timezone used to display timezone-aware datetimes; `None` displays UTC.
+    pub datetime_tz: Option<String>,
+    /// Write booleans as Excel `TRUE`/`FALSE` cells instead of text.
+    pub should_write_native_booleans: bool,
+    /// Text written for `true` when booleans are not written natively.
+    pub bool_true_str: String,
+    /// Text written for `false` when booleans are not written natively.
+    pub bool_false_str: String,
 }
 
 impl Default for XlsxValuePolicy {
@@ -224,6 +320,10 @@ impl Default for XlsxValuePolicy {
             posinf_str: "Inf".to_string(),
             neginf_str: "-Inf".to_string(),
             integer_coerce: IntegerCoerceMode::Strict,
+            datetime_tz: None,
+            should_write_native_booleans: false,
+            bool_true_str: "True".to_string(),
+            bool_false_str: "False".to_string(),
         }
     }
 }
@@ -281,6 +381,8 @@ pub struct ScientificPolicy {
     pub thr_min: f64,
     /// Upper absolute bound trigger (inclusive).
     pub thr_max: f64,
+    /// Mantissa decimal places in the scientific number format (`0..=20`).
+    pub precision: usize,
 }
 
 impl Default for ScientificPolicy {
@@ -289,6 +391,7 @@ impl Default for ScientificPolicy {
             scope: ScientificScope::None,
             thr_min: 0.0001,
             thr_max: 1_000_000_000_000.0,
+            precision: 2,
         }
     }
 }
@@ -334,8 +437,69 @@ impl Default for AutofitPolicy {
     }
 }
 
-/// Writer-wide options controlling value conversion and formatting defaults.
+/// Conditional format rule kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConditionalFormatKind {
+    /// Three-color scale across minimum, midpoint, and maximum values.
+    #[default]
+    ColorScale3,
+    /// Data bar proportional to each cell value.
+    DataBar,
+}
+
+/// Conditional format applied to one column's body range.
 #[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionalFormatRule {
+    /// Target column name.
+    pub col: String,
+    /// Rule kind.
+    pub kind: ConditionalFormatKind,
+    /// Color-scale minimum color (`#RRGGBB`).
+    pub color_min: String,
+    /// Color-scale midpoint color (`#RRGGBB`).
+    pub color_mid: String,
+    /// Color-scale maximum color (`#RRGGBB`).
+    pub color_max: String,
+    /// Data-bar fill color (`#RRGGBB`).
+    pub color_bar: String,
+}
+
+impl Default for ConditionalFormatRule {
+    fn default() -> Self {
+        Self {
+            col: String::new(),
+            kind: ConditionalFormatKind::ColorScale3,
+            color_min: "#F8696B".to_string(),
+            color_mid: "#FFEB84".to_string(),
+            color_max: "#63BE7B".to_string(),
+            color_bar: "#638EC6".to_string(),
+        }
+    }
+}
+
+/// Aggregate written into one column of a sheet's summary row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SummaryAgg {
+    /// Sum of numeric body cells (`SUM`).
+    Sum,
+    /// Mean of numeric body cells (`AVERAGE`); blank when there are none.
+    Mean,
+    /// Number of non-blank body cells (`COUNTA`).
+    Count,
+}
+
+/// Nesting order of sheet parts when a table overflows Excel limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SheetSplitMode {
+    /// Number all row parts of the first column block, then the next block (default).
+    #[default]
+    ColumnsFirst,
+    /// Number all column blocks of the first row part, then the next part.
+    RowsFirst,
+}
+
+/// Writer-wide options controlling value conversion and formatting defaults.
+#[derive(Debug, Clone, PartialEq)]
 pub struct XlsxWriteOptions {
     /// Value conversion policy.
     pub value_policy: XlsxValuePolicy,
@@ -349,6 +513,31 @@ pub struct XlsxWriteOptions {
     pub row_chunk_policy: XlsxRowChunkPolicy,
     /// Base patch merged into all per-column formats.
     pub base_format_patch: CellFormatPatch,
+    /// Excel number format applied to date cells.
+    pub date_num_format: String,
+    /// Excel number format applied to datetime cells.
+    pub datetime_num_format: String,
+    /// Flush rows of `write_sheet_from_dataframes` sheets to disk as they are written.
+    ///
+    /// Record-batch writes always use constant memory. Rows must then be written
+    /// top-down, so widths are autofitted from values seen inline while writing.
+    pub should_use_constant_memory: bool,
+    /// Part order for tables split across sheets.
+    ///
+    /// Single-pass writes always split rows-first: their row count is unknown up front.
+    pub split_mode: SheetSplitMode,
+    /// Part name template for split sheets with `{base}` and required `{n}` placeholders.
+    ///
+    /// Falls back to `base_1`, `base_2`, ... when `None`.
+    pub sheet_split_template: Option<String>,
+    /// Group thousands in integer columns (`#,##0` instead of the plain `0` preset).
+    ///
+    /// A custom integer `num_format` or a per-column override is left untouched.
+    pub should_use_integer_thousands_sep: bool,
+    /// Baseline width for every data column; autofit and `cols_width` still override it.
+    pub default_col_width: Option<f64>,
+    /// Default row height in points for every sheet.
+    pub default_row_height: Option<f64>,
 }
 
 impl Default for XlsxWriteOptions {
@@ -367,6 +556,14 @@ impl Default for XlsxWriteOptions {
                 right: Some(0),
                 ..Default::default()
             },
+            date_num_format: "yyyy-mm-dd".to_string(),
+            datetime_num_format: "yyyy-mm-dd hh:mm:ss".to_string(),
+            should_use_constant_memory: false,
+            split_mode: SheetSplitMode::ColumnsFirst,
+            sheet_split_template: None,
+            should_use_integer_thousands_sep: false,
+            default_col_width: None,
+            default_row_height: None,
         }
     }
 }
@@ -390,6 +587,24 @@ pub struct SheetSlice {
     pub col_end_exclusive: usize,
 }
 
+/// One table written into a shared sheet by `XlsxWriter::write_sheets_stacked` or
+/// `XlsxWriter::write_sheets_side_by_side`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SheetBlock {
+    /// Actual unique sheet name in workbook.
+    pub sheet_name: String,
+    /// Zero-based worksheet row of the block's first header row.
+    pub row_header_start: usize,
+    /// Zero-based worksheet row of the block's first body row.
+    pub row_body_start: usize,
+    /// Zero-based worksheet row just past the block's last row, including its summary row.
+    pub row_end_exclusive: usize,
+    /// Zero-based worksheet column of the block's first column.
+    pub col_start_inclusive: usize,
+    /// Zero-based worksheet column just past the block's last column.
+    pub col_end_exclusive: usize,
+}
+
 /// Horizontal merge plan item.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SheetHorizontalMerge {
@@ -403,6 +618,89 @@ pub struct SheetHorizontalMerge {
     pub text: String,
 }
 
+/// Vertical merge plan item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SheetVerticalMerge {
+    /// Column index where merge is applied.
+    pub col_idx: usize,
+    /// Start row index (inclusive).
+    pub row_idx_start: usize,
+    /// End row index (inclusive).
+    pub row_idx_end: usize,
+    /// Merge display text.
+    pub text: String,
+}
+
+/// Image payload accepted by sheet image placement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageSource {
+    /// PNG/JPEG/GIF/BMP file on disk.
+    Path(PathBuf),
+    /// In-memory PNG/JPEG/GIF/BMP bytes.
+    Bytes(Vec<u8>),
+}
+
+/// Image anchored at a zero-based sheet cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImagePlacement {
+    /// Anchor row index (sheet coordinates, not body rows).
+    pub row: usize,
+    /// Anchor column index.
+    pub col: usize,
+    /// Image payload.
+    pub source: ImageSource,
+    /// Horizontal offset in pixels from the anchor cell.
+    pub x_offset: u32,
+    /// Vertical offset in pixels from the anchor cell.
+    pub y_offset: u32,
+}
+
+/// Collapsible outline group over zero-based body rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowGroup {
+    /// First grouped body row (inclusive).
+    pub row_start: usize,
+    /// Last grouped body row (inclusive).
+    pub row_end: usize,
+    /// Outline level in `1..=7`.
+    pub level: u8,
+}
+
+/// Workbook metadata indexed by document-management systems; empty fields stay unset.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DocumentProperties {
+    /// Document title.
+    pub title: Option<String>,
+    /// Document subject.
+    pub subject: Option<String>,
+    /// Document author.
+    pub author: Option<String>,
+    /// Company of the author.
+    pub company: Option<String>,
+    /// Search keywords, conventionally comma separated.
+    pub keywords: Option<String>,
+}
+
+/// Print page setup; unset fields keep Excel defaults.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PageSetup {
+    /// Print in landscape instead of portrait orientation.
+    pub should_use_landscape: bool,
+    /// Excel paper size index (e.g. 1 = Letter, 9 = A4).
+    pub paper_size: Option<u8>,
+    /// Page margins in inches as `(left, right, top, bottom)`.
+    pub margins: Option<(f64, f64, f64, f64)>,
+    /// Printed body cells as inclusive `(row_start, col_start, row_end, col_end)` over
+    /// body rows and data columns; clipped to each sheet part.
+    pub print_area: Option<(usize, usize, usize, usize)>,
+    /// Repeat the header rows at the top of every printed page.
+    pub should_repeat_header_on_print: bool,
+    /// Printed page header; supports Excel field codes such as `&P`, `&N`, and `&D`.
+    pub header_text: Option<String>,
+    /// Printed page footer; supports the same field codes as `header_text`.
+    pub footer_text: Option<String>,
+}
+
 // #endregion
 ////////////////////////////////////////////////////////////////////////////////
 // #region ReportSpecification
@@ -414,6 +712,18 @@ pub struct XlsxReport {
     pub sheets: Vec<SheetSlice>,
     /// Non-fatal warnings.
     pub warnings: Vec<String>,
+    /// Columns written as numbers (inferred, or forced by `cols_currency`).
+    pub cols_numeric: Vec<String>,
+    /// Columns using the integer format.
+    pub cols_integer: Vec<String>,
+    /// Numeric columns eligible for scientific format under `policy_scientific`.
+    pub cols_scientific: Vec<String>,
+    /// Body cells written blank: missing values, plus NaN/Inf when missing values are dropped.
+    pub count_cells_blanked: usize,
+    /// Non-blank body cells whose value the value policy replaced or truncated.
+    pub count_cells_coerced: usize,
+    /// Tables of `write_sheets_stacked` / `write_sheets_side_by_side`; empty for other writes.
+    pub blocks: Vec<SheetBlock>,
 }
 
 impl XlsxReport {
@@ -2,17 +2,26 @@
 
 use std::collections::{BTreeMap, BTreeSet};
 
+use chrono::{DateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+use rust_xlsxwriter::TableStyle;
+
 use crate::constant::{
-    ColumnIdentifier, LEN_SHEET_NAME_MAX, NCOLS_SHEET_MAX, NROWS_SHEET_MAX, SHEET_NAME_ILLEGAL_CHRS,
+    ColumnIdentifier, EXCEL_SERIAL_MAX_EXCLUSIVE, EXCEL_SERIAL_MIN, EXCEL_SERIAL_UNIX_EPOCH,
+    INTEGER_F64_EXACT_MAX, LEN_SHEET_NAME_MAX, NCOLS_SHEET_MAX, NROWS_SHEET_MAX,
+    SHEET_NAME_ILLEGAL_CHRS,
 };
 use crate::spec::{
-    CellBorder, CellValue, IntegerCoerceMode, SheetHorizontalMerge, SheetSlice, XlsxReport,
+    CellBorder, CellValue, ConditionalFormatRule, IntegerCoerceMode, RowGroup,
+    SheetHorizontalMerge, SheetSlice, SheetSplitMode, SheetVerticalMerge, XlsxReport,
     XlsxRowChunkPolicy, XlsxValuePolicy,
 };
 
 ////////////////////////////////////////////////////////////////////////////////
 // #region CellValueConversion
 
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
 /// Convert `NaN`/`Inf` to policy string; return error for finite values.
 fn convert_nan_inf_to_str(x: f64, value_policy: &XlsxValuePolicy) -> Result<String, String> {
     if x.is_nan() {
@@ -28,6 +37,15 @@ fn convert_nan_inf_to_str(x: f64, value_policy: &XlsxValuePolicy) -> Result<Stri
     Err("Input is neither NaN nor Inf.".to_string())
 }
 
+/// Convert an integer to a number cell, keeping values beyond 2^53 as exact integers.
+pub fn convert_integer_to_cell_value(value: i128) -> CellValue {
+    if value.unsigned_abs() > INTEGER_F64_EXACT_MAX {
+        CellValue::Integer(value)
+    } else {
+        CellValue::Number(value as f64)
+    }
+}
+
 fn convert_infinite_number(
     value: f64,
     should_keep_missing_values: bool,
@@ -68,8 +86,8 @@ fn convert_string_cell_to_integer(
     should_keep_missing_values: bool,
     value_policy: &XlsxValuePolicy,
 ) -> CellValue {
-    if let Ok(_val) = value.parse::<i64>() {
-        return CellValue::Number(_val as f64);
+    if let Ok(_val) = value.parse::<i128>() {
+        return convert_integer_to_cell_value(_val);
     }
 
     if value_policy.integer_coerce == IntegerCoerceMode::Strict {
@@ -94,6 +112,15 @@ pub fn convert_cell_value(
     should_keep_missing_values: bool,
     value_policy: &XlsxValuePolicy,
 ) -> CellValue {
+    if let CellValue::Boolean(val) = value {
+        return if value_policy.should_write_native_booleans {
+            CellValue::Boolean(*val)
+        } else if *val {
+            CellValue::String(value_policy.bool_true_str.clone())
+        } else {
+            CellValue::String(value_policy.bool_false_str.clone())
+        };
+    }
     if matches!(value, CellValue::None) {
         return if should_keep_missing_values {
             CellValue::String(value_policy.missing_value_str.clone())
@@ -106,7 +133,13 @@ pub fn convert_cell_value(
         return match value {
             CellValue::String(s) => CellValue::String(s.clone()),
             CellValue::Number(n) => CellValue::String(n.to_string()),
-            CellValue::None => CellValue::None,
+            CellValue::Integer(n) => CellValue::String(n.to_string()),
+            CellValue::None
+            | CellValue::Boolean(_)
+            | CellValue::Date(_)
+            | CellValue::DateTime(_)
+            | CellValue::RichText(_)
+            | CellValue::Formula(_) => value.clone(),
         };
     }
 
@@ -118,7 +151,13 @@ pub fn convert_cell_value(
             CellValue::String(_val) => {
                 convert_string_cell_to_integer(_val, should_keep_missing_values, value_policy)
             }
-            CellValue::None => CellValue::None,
+            CellValue::None
+            | CellValue::Integer(_)
+            | CellValue::Boolean(_)
+            | CellValue::Date(_)
+            | CellValue::DateTime(_)
+            | CellValue::RichText(_)
+            | CellValue::Formula(_) => value.clone(),
         };
     }
 
@@ -141,10 +180,102 @@ pub fn convert_cell_value(
                 CellValue::String(_val.clone())
             }
         }
-        CellValue::None => CellValue::None,
+        CellValue::None
+        | CellValue::Integer(_)
+        | CellValue::Boolean(_)
+        | CellValue::Date(_)
+        | CellValue::DateTime(_)
+        | CellValue::RichText(_)
+        | CellValue::Formula(_) => value.clone(),
     }
 }
 
+/// Check whether `convert_cell_value` changed a cell's value, not just its representation.
+///
+/// Missing/NaN/Inf tokens, truncated integers, and inexactly parsed strings count;
+/// blank results, lossless number parsing, and boolean tokens do not.
+pub fn is_cell_value_coerced(value_raw: &CellValue, value: &CellValue) -> bool {
+    match (value_raw, value) {
+        (_, CellValue::None) => false,
+        (CellValue::None, _) => true,
+        (CellValue::Number(_raw), CellValue::Number(_val)) => _raw != _val,
+        (CellValue::Number(_raw), CellValue::String(_)) => !_raw.is_finite(),
+        (CellValue::String(_raw), CellValue::Number(_val)) => _raw.parse::<f64>() != Ok(*_val),
+        (CellValue::String(_raw), CellValue::String(_val)) => _raw != _val,
+        _ => false,
+    }
+}
+
+/// Build an Excel scientific number format with `precision` mantissa decimals.
+pub fn create_scientific_num_format(precision: usize) -> String {
+    if precision == 0 {
+        return "0E+0".to_string();
+    }
+    format!("0.{}E+0", "0".repeat(precision))
+}
+
+/// Build an Excel percent number format with `decimals` places, e.g. `0.00%`.
+pub fn create_percent_num_format(decimals: usize) -> String {
+    if decimals == 0 {
+        return "0%".to_string();
+    }
+    format!("0.{}%", "0".repeat(decimals))
+}
+
+/// Build an Excel currency number format such as `$#,##0.00`.
+///
+/// Symbols with letters or digits (e.g. `"EUR"`) are quoted as literal text.
+pub fn derive_currency_format(symbol: &str, decimals: usize) -> String {
+    let prefix = if symbol.chars().any(char::is_alphanumeric) {
+        format!("\"{symbol}\"")
+    } else {
+        symbol.to_string()
+    };
+    if decimals == 0 {
+        return format!("{prefix}#,##0");
+    }
+    format!("{prefix}#,##0.{}", "0".repeat(decimals))
+}
+
+/// Convert fractional days since the Unix epoch into an Excel serial date.
+///
+/// Returns `None` when the value falls outside the range Excel can display
+/// reliably (1900-03-01 through 9999-12-31).
+pub fn convert_unix_days_to_excel_serial(days: f64) -> Option<f64> {
+    let serial = EXCEL_SERIAL_UNIX_EPOCH + days;
+    (EXCEL_SERIAL_MIN..EXCEL_SERIAL_MAX_EXCLUSIVE)
+        .contains(&serial)
+        .then_some(serial)
+}
+
+/// Parse an IANA timezone name such as `Asia/Shanghai`.
+pub fn parse_timezone(name: &str) -> Result<Tz, String> {
+    name.parse::<Tz>()
+        .map_err(|_| format!("Unknown timezone: {name:?}."))
+}
+
+/// Convert a Unix timestamp into an Excel serial datetime.
+///
+/// `units_per_second` is the timestamp scale (`1_000` for milliseconds, ...).
+/// When `tz_display` is set the UTC instant is shifted to that timezone's
+/// wall-clock time; otherwise the timestamp is taken as wall-clock time.
+pub fn convert_unix_timestamp_to_excel_serial(
+    value: i64,
+    units_per_second: i64,
+    tz_display: Option<Tz>,
+) -> Option<f64> {
+    let mut days = value as f64 / (units_per_second as f64 * SECONDS_PER_DAY);
+    if let Some(tz) = tz_display {
+        let datetime_utc = DateTime::from_timestamp(value.div_euclid(units_per_second), 0)?;
+        let offset_seconds = tz
+            .offset_from_utc_datetime(&datetime_utc.naive_utc())
+            .fix()
+            .local_minus_utc();
+        days += offset_seconds as f64 / SECONDS_PER_DAY;
+    }
+    convert_unix_days_to_excel_serial(days)
+}
+
 // #endregion
 ////////////////////////////////////////////////////////////////////////////////
 // #region DataFrameLikeUtils
@@ -205,6 +336,80 @@ pub fn select_sorted_indices_from_refs(
     Ok(indices.into_iter().collect())
 }
 
+/// Resolve hyperlink columns to `link column index -> display-text column index`.
+///
+/// Columns listed in `text_cols_by_link_col` are hyperlink columns even when
+/// they are absent from `refs`.
+pub fn select_hyperlink_column_indices(
+    columns: &[&str],
+    refs: Option<&[ColumnIdentifier]>,
+    text_cols_by_link_col: Option<&BTreeMap<String, String>>,
+) -> Result<BTreeMap<usize, Option<usize>>, String> {
+    let mut text_idx_by_link_idx = select_sorted_indices_from_refs(columns, refs)?
+        .into_iter()
+        .map(|_idx| (_idx, None))
+        .collect::<BTreeMap<_, _>>();
+    for (_idx_link, _idx_text) in select_column_index_pairs(columns, text_cols_by_link_col)? {
+        text_idx_by_link_idx.insert(_idx_link, Some(_idx_text));
+    }
+
+    Ok(text_idx_by_link_idx)
+}
+
+/// Resolve a `target column name -> source column name` mapping to indices.
+pub fn select_column_index_pairs(
+    columns: &[&str],
+    source_cols_by_target_col: Option<&BTreeMap<String, String>>,
+) -> Result<BTreeMap<usize, usize>, String> {
+    let find_column = |name: &str| {
+        columns
+            .iter()
+            .position(|_colname| *_colname == name)
+            .ok_or_else(|| format!("Column not found: {name:?}"))
+    };
+    source_cols_by_target_col
+        .into_iter()
+        .flatten()
+        .map(|(_col_target, _col_source)| {
+            Ok((find_column(_col_target)?, find_column(_col_source)?))
+        })
+        .collect()
+}
+
+/// Resolve `column name -> value` mapping keys to indices, keeping their values.
+pub fn select_mapped_column_indices<T: Clone>(
+    columns: &[&str],
+    values_by_col: Option<&BTreeMap<String, T>>,
+) -> Result<BTreeMap<usize, T>, String> {
+    values_by_col
+        .into_iter()
+        .flatten()
+        .map(|(_col, _values)| {
+            let col_idx = columns
+                .iter()
+                .position(|_colname| *_colname == _col)
+                .ok_or_else(|| format!("Column not found: {_col:?}"))?;
+            Ok((col_idx, _values.clone()))
+        })
+        .collect()
+}
+
+/// Resolve each conditional format rule's target column to an index.
+pub fn select_conditional_format_column_indices(
+    columns: &[&str],
+    rules: &[ConditionalFormatRule],
+) -> Result<Vec<usize>, String> {
+    rules
+        .iter()
+        .map(|_rule| {
+            columns
+                .iter()
+                .position(|_colname| *_colname == _rule.col)
+                .ok_or_else(|| format!("Column not found: {:?}", _rule.col))
+        })
+        .collect()
+}
+
 // #endregion
 ////////////////////////////////////////////////////////////////////////////////
 // #region RowChunking
@@ -254,11 +459,16 @@ pub fn sanitize_sheet_name(name: &str, replace_to: &str) -> String {
 }
 
 /// Split logical dataframe range into Excel-compliant sheet slices.
+///
+/// `split_mode` sets the nesting order of the slice loops, and thus the part numbering;
+/// `split_template` overrides the `base_N` part names.
 pub fn plan_sheet_slices(
     height_df: usize,
     width_df: usize,
     height_header: usize,
     sheet_name: &str,
+    split_mode: SheetSplitMode,
+    split_template: Option<&str>,
     report: &mut XlsxReport,
 ) -> Result<Vec<SheetSlice>, String> {
     if height_header == 0 {
@@ -297,32 +507,52 @@ pub fn plan_sheet_slices(
 
     let parts_total = col_slices.len() * row_slices.len();
 
+    let slice_pairs = match split_mode {
+        SheetSplitMode::ColumnsFirst => col_slices
+            .iter()
+            .flat_map(|_col_slice| {
+                row_slices
+                    .iter()
+                    .map(move |_row_slice| (_row_slice, _col_slice))
+            })
+            .collect::<Vec<_>>(),
+        SheetSplitMode::RowsFirst => row_slices
+            .iter()
+            .flat_map(|_row_slice| {
+                col_slices
+                    .iter()
+                    .map(move |_col_slice| (_row_slice, _col_slice))
+            })
+            .collect::<Vec<_>>(),
+    };
+
     let mut sheet_slices = Vec::new();
-    let mut part_idx = 1;
-    for _col_slice in &col_slices {
-        let (col_start, col_end) = _col_slice;
-        for _row_slice in &row_slices {
-            let (row_start, row_end) = _row_slice;
-            let part_sheet_name = if parts_total == 1 {
-                sheet_name.to_string()
-            } else {
-                create_sheet_identifier(sheet_name, part_idx)
-            };
-
-            sheet_slices.push(SheetSlice {
-                sheet_name: part_sheet_name,
-                row_start_inclusive: *row_start,
-                row_end_exclusive: *row_end,
-                col_start_inclusive: *col_start,
-                col_end_exclusive: *col_end,
-            });
-            part_idx += 1;
-        }
+    for (_part_idx, _slice_pair) in slice_pairs.into_iter().enumerate() {
+        let ((row_start, row_end), (col_start, col_end)) = _slice_pair;
+        let part_sheet_name = match split_template {
+            _ if parts_total == 1 => sheet_name.to_string(),
+            Some(template) => {
+                create_sheet_identifier_from_template(sheet_name, _part_idx + 1, template)
+            }
+            None => create_sheet_identifier(sheet_name, _part_idx + 1),
+        };
+
+        sheet_slices.push(SheetSlice {
+            sheet_name: part_sheet_name,
+            row_start_inclusive: *row_start,
+            row_end_exclusive: *row_end,
+            col_start_inclusive: *col_start,
+            col_end_exclusive: *col_end,
+        });
     }
 
     if parts_total > 1 {
+        let split_order = match split_mode {
+            SheetSplitMode::ColumnsFirst => "columns-first, then rows",
+            SheetSplitMode::RowsFirst => "rows-first, then columns",
+        };
         report.warn(format!(
-            "Excel limit overflow: split into {} sheets (columns-first, then rows).",
+            "Excel limit overflow: split into {} sheets ({split_order}).",
             sheet_slices.len()
         ));
     }
@@ -343,6 +573,73 @@ fn create_sheet_identifier(base_name: &str, part_idx_1based: usize) -> String {
     format!("{sheet_name_base}{sheet_name_suffix}")
 }
 
+/// Render a split-part sheet name from `template`'s `{base}` and `{n}` placeholders.
+///
+/// `base_name` is shortened first so the rendered name fits the sheet-name length cap.
+pub fn create_sheet_identifier_from_template(
+    base_name: &str,
+    part_idx_1based: usize,
+    template: &str,
+) -> String {
+    let template_numbered = template.replace("{n}", &part_idx_1based.to_string());
+    let base_name_max_len =
+        LEN_SHEET_NAME_MAX.saturating_sub(template_numbered.replace("{base}", "").chars().count());
+    let sheet_name_base: String = base_name.chars().take(base_name_max_len).collect();
+
+    template_numbered
+        .replace("{base}", sheet_name_base.trim_end())
+        .chars()
+        .take(LEN_SHEET_NAME_MAX)
+        .collect()
+}
+
+/// Parse an Excel table style name such as `Table Style Medium 9` or `medium9`.
+pub fn parse_table_style(name: &str) -> Result<TableStyle, String> {
+    macro_rules! table_styles {
+        ($($variant:ident),* $(,)?) => {
+            [$(TableStyle::$variant),*]
+        };
+    }
+    let styles = table_styles![
+        None, Light1, Light2, Light3, Light4, Light5, Light6, Light7, Light8, Light9, Light10,
+        Light11, Light12, Light13, Light14, Light15, Light16, Light17, Light18, Light19, Light20,
+        Light21, Medium1, Medium2, Medium3, Medium4, Medium5, Medium6, Medium7, Medium8, Medium9,
+        Medium10, Medium11, Medium12, Medium13, Medium14, Medium15, Medium16, Medium17, Medium18,
+        Medium19, Medium20, Medium21, Medium22, Medium23, Medium24, Medium25, Medium26, Medium27,
+        Medium28, Dark1, Dark2, Dark3, Dark4, Dark5, Dark6, Dark7, Dark8, Dark9, Dark10, Dark11,
+    ];
+
+    let name_normalized = name
+        .chars()
+        .filter(|_chr| !_chr.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    let name_normalized = name_normalized
+        .strip_prefix("tablestyle")
+        .unwrap_or(&name_normalized);
+    styles
+        .into_iter()
+        .find(|_style| {
+            let style_name = _style.to_string().to_lowercase();
+            style_name.strip_prefix("tablestyle").unwrap_or(&style_name) == name_normalized
+        })
+        .ok_or_else(|| format!("Unknown table style: {name:?}."))
+}
+
+/// Resolve row groups to `body row index -> outline level`.
+///
+/// Overlapping groups keep the highest level for each row.
+pub fn calculate_row_group_levels(row_groups: &[RowGroup]) -> BTreeMap<usize, u8> {
+    let mut levels_by_row = BTreeMap::new();
+    for _group in row_groups {
+        for _row_idx in _group.row_start..=_group.row_end {
+            let level = levels_by_row.entry(_row_idx).or_insert(0);
+            *level = u8::max(*level, _group.level);
+        }
+    }
+    levels_by_row
+}
+
 // #endregion
 ////////////////////////////////////////////////////////////////////////////////
 // #region HeaderMergeUtils
@@ -489,6 +786,40 @@ pub fn plan_vertical_visual_merge_borders(
     vertical_merge_border_plan
 }
 
+/// Plan true vertical merges for contiguous repeated non-empty header text per column.
+pub fn plan_vertical_merges(header_grid: &[Vec<String>]) -> Vec<SheetVerticalMerge> {
+    _generate_vertical_runs(header_grid)
+        .into_iter()
+        .map(
+            |(col_idx, row_idx_start, row_idx_end, text)| SheetVerticalMerge {
+                col_idx,
+                row_idx_start,
+                row_idx_end,
+                text,
+            },
+        )
+        .collect()
+}
+
+/// Clear every cell covered by a vertical merge so horizontal planning skips it.
+///
+/// Vertical merges win over horizontal ones; clearing their cells guarantees
+/// the horizontal plan built afterwards never overlaps them.
+pub fn apply_vertical_merge_text_clear(
+    header_grid: &mut [Vec<String>],
+    vertical_merges: &[SheetVerticalMerge],
+) {
+    for _merge in vertical_merges {
+        for _row in header_grid
+            .iter_mut()
+            .take(_merge.row_idx_end + 1)
+            .skip(_merge.row_idx_start)
+        {
+            _row[_merge.col_idx].clear();
+        }
+    }
+}
+
 /// Clear repeated text in vertical runs, keeping only first row text.
 pub fn apply_vertical_run_text_blankout(header_grid: &mut [Vec<String>]) {
     for (col_idx, row_start, row_end, _) in _generate_vertical_runs(header_grid) {
@@ -526,6 +857,14 @@ mod tests {
     use super::*;
     use crate::constant::ColumnIdentifier;
 
+    #[test]
+    fn test_parse_table_style_accepts_excel_and_short_names() {
+        assert!(parse_table_style("Table Style Medium 9").unwrap() == TableStyle::Medium9);
+        assert!(parse_table_style("light21").unwrap() == TableStyle::Light21);
+        assert!(parse_table_style("TableStyleDark11").unwrap() == TableStyle::Dark11);
+        assert!(parse_table_style("medium29").is_err());
+    }
+
     #[test]
     fn test_select_sorted_indices_from_refs_respects_typed_selectors() {
         let columns: Vec<&str> = vec!["x", "0", "y"];
@@ -556,6 +895,86 @@ mod tests {
         assert!(err.contains("Column not found"));
     }
 
+    #[test]
+    fn test_plan_sheet_slices_orders_parts_by_split_mode() {
+        let height_df = NROWS_SHEET_MAX;
+        let width_df = NCOLS_SHEET_MAX + 1;
+        let bounds_of = |split_mode| {
+            let mut report = XlsxReport::default();
+            let sheet_slices =
+                plan_sheet_slices(height_df, width_df, 1, "S", split_mode, None, &mut report)
+                    .unwrap();
+            assert_eq!(report.warnings.len(), 1);
+            sheet_slices
+                .into_iter()
+                .map(|_slice| {
+                    (
+                        _slice.sheet_name,
+                        _slice.row_start_inclusive,
+                        _slice.col_start_inclusive,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let row_split = NROWS_SHEET_MAX - 1;
+
+        assert_eq!(
+            bounds_of(SheetSplitMode::ColumnsFirst),
+            vec![
+                ("S_1".to_string(), 0, 0),
+                ("S_2".to_string(), row_split, 0),
+                ("S_3".to_string(), 0, NCOLS_SHEET_MAX),
+                ("S_4".to_string(), row_split, NCOLS_SHEET_MAX),
+            ]
+        );
+        assert_eq!(
+            bounds_of(SheetSplitMode::RowsFirst),
+            vec![
+                ("S_1".to_string(), 0, 0),
+                ("S_2".to_string(), 0, NCOLS_SHEET_MAX),
+                ("S_3".to_string(), row_split, 0),
+                ("S_4".to_string(), row_split, NCOLS_SHEET_MAX),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_create_sheet_identifier_from_template_truncates_base_to_fit() {
+        assert_eq!(
+            create_sheet_identifier_from_template("Sales", 2, "{base} (part {n})"),
+            "Sales (part 2)"
+        );
+        let sheet_name = create_sheet_identifier_from_template(
+            "Quarterly revenue by region",
+            12,
+            "{base} (part {n})",
+        );
+        assert_eq!(sheet_name, "Quarterly revenue by (part 12)");
+        assert!(sheet_name.chars().count() <= LEN_SHEET_NAME_MAX);
+    }
+
+    #[test]
+    fn test_calculate_row_group_levels_keeps_highest_level_for_overlaps() {
+        let row_groups = vec![
+            RowGroup {
+                row_start: 0,
+                row_end: 4,
+                level: 1,
+            },
+            RowGroup {
+                row_start: 1,
+                row_end: 2,
+                level: 2,
+            },
+        ];
+
+        let levels_by_row = calculate_row_group_levels(&row_groups);
+        assert_eq!(
+            levels_by_row.into_iter().collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (2, 2), (3, 1), (4, 1)]
+        );
+    }
+
     #[test]
     fn test_generate_vertical_runs_detects_only_contiguous_non_empty_runs() {
         let grid = vec![
@@ -577,6 +996,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_vertical_merges_take_precedence_over_horizontal_merges() {
+        let mut grid = vec![
+            vec!["X".to_string(), "X".to_string(), "X".to_string()],
+            vec!["X".to_string(), "A".to_string(), "B".to_string()],
+        ];
+
+        let vertical_merges = plan_vertical_merges(&grid);
+        assert_eq!(
+            vertical_merges,
+            vec![SheetVerticalMerge {
+                col_idx: 0,
+                row_idx_start: 0,
+                row_idx_end: 1,
+                text: "X".to_string(),
+            }]
+        );
+
+        apply_vertical_merge_text_clear(&mut grid, &vertical_merges);
+        assert_eq!(grid[0][0], "");
+        assert_eq!(grid[1][0], "");
+
+        let horizontal_merges_by_row = plan_horizontal_merges(&grid);
+        assert_eq!(
+            horizontal_merges_by_row.get(&0),
+            Some(&vec![SheetHorizontalMerge {
+                row_idx_start: 0,
+                col_idx_start: 1,
+                col_idx_end: 2,
+                text: "X".to_string(),
+            }])
+        );
+        assert!(!horizontal_merges_by_row.contains_key(&1));
+    }
+
     #[test]
     fn test_apply_vertical_run_text_blankout() {
         let mut grid = vec![
@@ -599,4 +1053,254 @@ mod tests {
         assert_eq!(grid[2][1], "");
         assert_eq!(grid[3][1], "");
     }
+
+    #[test]
+    fn test_convert_unix_days_to_excel_serial() {
+        assert_eq!(convert_unix_days_to_excel_serial(0.0), Some(25_569.0));
+        assert_eq!(convert_unix_days_to_excel_serial(19_723.5), Some(45_292.5));
+        assert_eq!(convert_unix_days_to_excel_serial(-25_508.0), Some(61.0));
+        assert_eq!(convert_unix_days_to_excel_serial(-25_509.0), None);
+        assert_eq!(convert_unix_days_to_excel_serial(2_932_897.0), None);
+
+        let value = CellValue::Date(45_292.0);
+        assert_eq!(
+            convert_cell_value(&value, true, true, false, &XlsxValuePolicy::default()),
+            value
+        );
+    }
+
+    #[test]
+    fn test_convert_unix_timestamp_to_excel_serial_across_units() {
+        let seconds = 1_704_110_400_i64;
+        for units_per_second in [1, 1_000, 1_000_000, 1_000_000_000] {
+            assert_eq!(
+                convert_unix_timestamp_to_excel_serial(
+                    seconds * units_per_second,
+                    units_per_second,
+                    None,
+                ),
+                Some(45_292.5),
+                "units_per_second={units_per_second}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_unix_timestamp_to_excel_serial_shifts_to_display_tz() {
+        let tz = parse_timezone("Asia/Shanghai").unwrap();
+        let serial =
+            convert_unix_timestamp_to_excel_serial(1_704_110_400_000, 1_000, Some(tz)).unwrap();
+        assert!((serial - (45_292.5 + 8.0 / 24.0)).abs() < 1e-9);
+
+        assert!(parse_timezone("Mars/Olympus").is_err());
+    }
+
+    #[test]
+    fn test_convert_cell_value_booleans_follow_value_policy() {
+        let value_policy = XlsxValuePolicy {
+            bool_true_str: "yes".to_string(),
+            ..XlsxValuePolicy::default()
+        };
+        assert_eq!(
+            convert_cell_value(
+                &CellValue::Boolean(true),
+                false,
+                false,
+                false,
+                &value_policy
+            ),
+            CellValue::String("yes".to_string())
+        );
+        assert_eq!(
+            convert_cell_value(
+                &CellValue::Boolean(false),
+                false,
+                false,
+                false,
+                &value_policy
+            ),
+            CellValue::String("False".to_string())
+        );
+
+        let value_policy = XlsxValuePolicy {
+            should_write_native_booleans: true,
+            ..value_policy
+        };
+        assert_eq!(
+            convert_cell_value(
+                &CellValue::Boolean(true),
+                false,
+                false,
+                false,
+                &value_policy
+            ),
+            CellValue::Boolean(true)
+        );
+    }
+
+    #[test]
+    fn test_is_cell_value_coerced() {
+        let policy = XlsxValuePolicy {
+            integer_coerce: IntegerCoerceMode::Coerce,
+            ..Default::default()
+        };
+        let convert = |value: &CellValue, is_integer_col: bool, should_keep: bool| {
+            convert_cell_value(value, true, is_integer_col, should_keep, &policy)
+        };
+        let nan = CellValue::Number(f64::NAN);
+        assert!(!is_cell_value_coerced(&nan, &convert(&nan, false, false)));
+        assert!(is_cell_value_coerced(&nan, &convert(&nan, false, true)));
+        assert!(is_cell_value_coerced(
+            &CellValue::None,
+            &convert(&CellValue::None, false, true)
+        ));
+        let fraction = CellValue::Number(2.5);
+        assert!(is_cell_value_coerced(
+            &fraction,
+            &convert(&fraction, true, false)
+        ));
+        assert!(!is_cell_value_coerced(
+            &fraction,
+            &convert(&fraction, false, false)
+        ));
+        let text = CellValue::String("12".to_string());
+        assert!(!is_cell_value_coerced(&text, &convert(&text, true, false)));
+    }
+
+    #[test]
+    fn test_create_percent_num_format() {
+        assert_eq!(create_percent_num_format(0), "0%");
+        assert_eq!(create_percent_num_format(2), "0.00%");
+    }
+
+    #[test]
+    fn test_derive_currency_format() {
+        assert_eq!(derive_currency_format("$", 2), "$#,##0.00");
+        assert_eq!(derive_currency_format("€", 0), "€#,##0");
+        assert_eq!(derive_currency_format("EUR ", 1), "\"EUR \"#,##0.0");
+    }
+
+    #[test]
+    fn test_create_scientific_num_format() {
+        assert_eq!(create_scientific_num_format(0), "0E+0");
+        assert_eq!(create_scientific_num_format(2), "0.00E+0");
+        assert_eq!(create_scientific_num_format(5), "0.00000E+0");
+    }
+
+    #[test]
+    fn test_convert_integer_to_cell_value_keeps_large_integers_exact() {
+        assert_eq!(
+            convert_integer_to_cell_value(1 << 53),
+            CellValue::Number(9_007_199_254_740_992.0)
+        );
+        assert_eq!(
+            convert_integer_to_cell_value(i64::MAX as i128),
+            CellValue::Integer(i64::MAX as i128)
+        );
+        assert_eq!(
+            convert_integer_to_cell_value(u64::MAX as i128),
+            CellValue::Integer(u64::MAX as i128)
+        );
+
+        let value_policy = XlsxValuePolicy::default();
+        assert_eq!(
+            convert_cell_value(
+                &CellValue::String("18446744073709551615".to_string()),
+                true,
+                true,
+                false,
+                &value_policy
+            ),
+            CellValue::Integer(u64::MAX as i128)
+        );
+    }
+
+    #[test]
+    fn test_select_hyperlink_column_indices_merges_text_mapping() {
+        let columns = vec!["url", "label", "doc"];
+        let text_cols_by_link_col = BTreeMap::from([("doc".to_string(), "label".to_string())]);
+
+        let selected = select_hyperlink_column_indices(
+            &columns,
+            Some(&[ColumnIdentifier::Index(0)]),
+            Some(&text_cols_by_link_col),
+        )
+        .unwrap();
+        assert_eq!(selected, BTreeMap::from([(0, None), (2, Some(1))]));
+
+        let missing = BTreeMap::from([("doc".to_string(), "title".to_string())]);
+        assert!(select_hyperlink_column_indices(&columns, None, Some(&missing)).is_err());
+    }
+
+    #[test]
+    fn test_select_mapped_column_indices_keeps_values() {
+        let columns = vec!["id", "status"];
+        let values_by_col = BTreeMap::from([(
+            "status".to_string(),
+            vec!["open".to_string(), "done".to_string()],
+        )]);
+
+        let selected = select_mapped_column_indices(&columns, Some(&values_by_col)).unwrap();
+        assert_eq!(
+            selected,
+            BTreeMap::from([(1, vec!["open".to_string(), "done".to_string()])])
+        );
+        assert!(
+            select_mapped_column_indices::<f64>(&columns, None)
+                .unwrap()
+                .is_empty()
+        );
+
+        let widths_by_col = BTreeMap::from([("id".to_string(), 12.5)]);
+        assert_eq!(
+            select_mapped_column_indices(&columns, Some(&widths_by_col)).unwrap(),
+            BTreeMap::from([(0, 12.5)])
+        );
+        assert!(
+            select_mapped_column_indices(
+                &columns,
+                Some(&BTreeMap::from([("missing".to_string(), 1.0)]))
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_select_conditional_format_column_indices_keeps_rule_order() {
+        let columns = vec!["a", "b", "c"];
+        let rules = vec![
+            ConditionalFormatRule {
+                col: "c".to_string(),
+                ..ConditionalFormatRule::default()
+            },
+            ConditionalFormatRule {
+                col: "a".to_string(),
+                ..ConditionalFormatRule::default()
+            },
+        ];
+
+        let selected = select_conditional_format_column_indices(&columns, &rules).unwrap();
+        assert_eq!(selected, vec![2, 0]);
+
+        let missing = vec![ConditionalFormatRule {
+            col: "z".to_string(),
+            ..ConditionalFormatRule::default()
+        }];
+        assert!(select_conditional_format_column_indices(&columns, &missing).is_err());
+    }
+
+    #[test]
+    fn test_select_column_index_pairs_resolves_names() {
+        let columns = vec!["value", "note"];
+        let source_cols_by_target_col = BTreeMap::from([("value".to_string(), "note".to_string())]);
+
+        let selected =
+            select_column_index_pairs(&columns, Some(&source_cols_by_target_col)).unwrap();
+        assert_eq!(selected, BTreeMap::from([(0, 1)]));
+        assert!(
+            select_column_index_pairs(&columns, None)
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
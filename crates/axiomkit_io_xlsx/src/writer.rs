@@ -1,26 +1,46 @@
 //! XLSX writer kernel that converts DataFrame IPC into workbook output.
 
 use std::collections::{BTreeMap, BTreeSet};
-use std::io::Cursor;
+use std::io::{Cursor, Seek, Write};
 use std::path::PathBuf;
 
 use arrow::array::{
     Array as ArrowArray, BooleanArray, PrimitiveArray, TryExtend, Utf8Array, Utf8ViewArray,
 };
-use arrow::datatypes::{ArrowDataType, ArrowSchema};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, TimeUnit as ArrowTimeUnit};
 use arrow::record_batch::RecordBatchT;
-use polars::prelude::{AnyValue, DataFrame, IpcReader, SerReader};
-use rust_xlsxwriter::{Format, FormatAlign, FormatBorder, Workbook, Worksheet, XlsxError};
+use polars::prelude::{AnyValue, Column, DataFrame, IpcReader, SerReader, TimeUnit};
+use rust_xlsxwriter::utility::cell_range;
+use rust_xlsxwriter::{
+    ConditionalFormat3ColorScale, ConditionalFormatDataBar, ConditionalFormatFormula,
+    DataValidation, DocProperties, ExcelDateTime, Format, FormatAlign, FormatBorder,
+    FormatDiagonalBorder, FormatPattern, FormatUnderline, Formula, Image, Note, Table, TableColumn,
+    Url, Workbook, Worksheet, XlsxError,
+};
 
-use crate::constant::{ColumnIdentifier, LEN_SHEET_NAME_MAX, NCOLS_SHEET_MAX, NROWS_SHEET_MAX};
+use crate::constant::{
+    BAND_COLOR_DEFAULT, CURRENCY_DECIMALS_DEFAULT, CURRENCY_DECIMALS_MAX, CURRENCY_SYMBOL_DEFAULT,
+    ColumnIdentifier, FORMAT_INDENT_MAX, FORMAT_PATTERN_MAX, LEN_HEADER_FOOTER_MAX,
+    LEN_SHEET_NAME_MAX, NCOLS_SHEET_MAX, NROWS_SHEET_MAX, NUM_FORMAT_INTEGER_THOUSANDS_SEP,
+    PERCENT_DECIMALS_DEFAULT, PERCENT_DECIMALS_MAX, SCIENTIFIC_PRECISION_MAX,
+    SHEET_NAME_ILLEGAL_CHRS,
+};
 use crate::spec::{
-    AutofitMode, AutofitPolicy, CellFormatPatch, CellValue, ColumnFormatPlan, ScientificPolicy,
-    ScientificScope, SheetSlice, XlsxReport, XlsxValuePolicy, XlsxWriteOptions,
+    AutofitMode, AutofitPolicy, CellBorder, CellFormatPatch, CellValue, ColumnFormatPlan,
+    ConditionalFormatKind, ConditionalFormatRule, DocumentProperties, ImagePlacement, ImageSource,
+    PageSetup, RichTextRun, RowGroup, ScientificPolicy, ScientificScope, SheetBlock, SheetSlice,
+    SummaryAgg, XlsxReport, XlsxValuePolicy, XlsxWriteOptions,
 };
 use crate::util::{
-    apply_vertical_run_text_blankout, calculate_row_chunk_size, convert_cell_value,
-    create_horizontal_merge_tracker, generate_row_chunks, plan_horizontal_merges,
-    plan_sheet_slices, sanitize_sheet_name, select_sorted_indices_from_refs,
+    apply_vertical_merge_text_clear, apply_vertical_run_text_blankout, calculate_row_chunk_size,
+    calculate_row_group_levels, convert_cell_value, convert_integer_to_cell_value,
+    convert_unix_days_to_excel_serial, convert_unix_timestamp_to_excel_serial,
+    create_horizontal_merge_tracker, create_percent_num_format, create_scientific_num_format,
+    create_sheet_identifier_from_template, derive_currency_format, generate_row_chunks,
+    is_cell_value_coerced, parse_table_style, parse_timezone, plan_horizontal_merges,
+    plan_sheet_slices, plan_vertical_merges, plan_vertical_visual_merge_borders,
+    sanitize_sheet_name, select_column_index_pairs, select_conditional_format_column_indices,
+    select_hyperlink_column_indices, select_mapped_column_indices, select_sorted_indices_from_refs,
     validate_unique_columns,
 };
 
@@ -31,12 +51,88 @@ pub struct XlsxSheetWriteOptions {
     pub cols_integer: Option<Vec<ColumnIdentifier>>,
     /// Decimal columns by typed name or zero-based index.
     pub cols_decimal: Option<Vec<ColumnIdentifier>>,
+    /// Hyperlink columns by typed name or zero-based index.
+    pub cols_hyperlink: Option<Vec<ColumnIdentifier>>,
+    /// Display-text column name keyed by hyperlink column name.
+    pub cols_hyperlink_text: Option<BTreeMap<String, String>>,
+    /// Comment-source column name keyed by data column name.
+    pub cols_comment_text: Option<BTreeMap<String, String>>,
     /// Number of frozen columns.
     pub num_frozen_cols: usize,
-    /// Number of frozen top rows; defaults to header height when `None`.
+    /// Number of frozen top rows; defaults to reserved rows plus header height when `None`.
+    ///
+    /// Panes are always frozen: `rust_xlsxwriter` cannot write unfrozen "Split" panes.
     pub num_frozen_rows: Option<usize>,
+    /// Number of blank rows kept above the header (e.g. for a logo image).
+    pub num_reserved_top_rows: usize,
     /// Enable merged multi-row header behavior.
     pub should_merge_header: bool,
+    /// Truly merge vertical header runs instead of simulating them with borders.
+    pub should_merge_header_vertical: bool,
+    /// Enable an auto-filter on the last header row.
+    pub should_autofilter: bool,
+    /// Wrap each sheet part's data region in a native Excel table.
+    pub should_write_table: bool,
+    /// Excel table style name; uses the Excel default style when `None`.
+    pub table_style: Option<String>,
+    /// Conditional formats applied to column body ranges.
+    pub conditional_formats: Vec<ConditionalFormatRule>,
+    /// Shade every second body row (the 2nd, 4th, ...) of each sheet part.
+    pub should_band_rows: bool,
+    /// RGB hex background of banded rows like `"#F2F2F2"`; defaults to light gray.
+    pub band_color: Option<String>,
+    /// Allowed dropdown values keyed by column name.
+    pub cols_dropdown: Option<BTreeMap<String, Vec<String>>>,
+    /// Explicit column widths keyed by column name; take precedence over autofit.
+    pub cols_width: Option<BTreeMap<String, f64>>,
+    /// Cell format patches keyed by column name; merged onto the column's base format.
+    pub cols_fmt_overrides: Option<BTreeMap<String, CellFormatPatch>>,
+    /// Hidden columns by typed name or zero-based index; data is still written.
+    pub cols_hidden: Option<Vec<ColumnIdentifier>>,
+    /// Currency columns by typed name or zero-based index; written as numbers.
+    ///
+    /// Their currency `num_format` replaces the decimal one, while a `cols_fmt_overrides`
+    /// number format still wins.
+    pub cols_currency: Option<Vec<ColumnIdentifier>>,
+    /// Currency symbol for `cols_currency`; defaults to `"$"`.
+    pub currency_symbol: Option<String>,
+    /// Currency decimal places for `cols_currency` (`0..=20`); defaults to 2.
+    pub currency_decimals: Option<usize>,
+    /// Percent columns by typed name or zero-based index; `0.25` displays as `25.00%`.
+    pub cols_percent: Option<Vec<ColumnIdentifier>>,
+    /// Percent decimal places for `cols_percent` (`0..=20`); defaults to 2.
+    pub percent_decimals: Option<usize>,
+    /// Aggregates keyed by column name, written in a bold total row below the body.
+    ///
+    /// Each sheet part gets its own total row over the body rows it holds.
+    pub summary_row: Option<BTreeMap<String, SummaryAgg>>,
+    /// Write summary cells as live formulas over the body range instead of plain values.
+    pub should_use_summary_formulas: bool,
+    /// Sheet zoom percentage in `10..=400`; uses Excel's 100% when `None`.
+    pub zoom: Option<u16>,
+    /// Hide on-screen cell gridlines.
+    pub should_hide_gridlines: bool,
+    /// Worksheet tab color as RGB hex like `"#FF0000"`; invalid values warn and are ignored.
+    pub tab_color: Option<String>,
+    /// Print orientation, paper size, and margins; leaves Excel defaults when `None`.
+    pub page_setup: Option<PageSetup>,
+    /// Rich text runs keyed by zero-based `(body row, data column)`; replace the cell value.
+    ///
+    /// Cells outside the written body are ignored.
+    pub rich_text_cells: BTreeMap<(usize, usize), Vec<RichTextRun>>,
+    /// Formulas like `"=B2*C2"` keyed by zero-based `(body row, data column)`; replace the
+    /// cell value.
+    ///
+    /// References are plain worksheet A1 cells, so they must account for reserved and
+    /// header rows and for the sheet part the target cell lands in. Cells outside the
+    /// written body are ignored with one report warning.
+    pub formula_cells: BTreeMap<(usize, usize), String>,
+    /// Images inserted into every sheet part.
+    pub images: Vec<ImagePlacement>,
+    /// Collapsible outline groups over body rows.
+    ///
+    /// Sheets with row groups are buffered in memory: constant-memory rows lose outline levels.
+    pub row_groups: Vec<RowGroup>,
     /// Override writer-level keep-missing behavior.
     pub should_keep_missing_values: Option<bool>,
     /// Column autofit policy.
@@ -54,6 +150,10 @@ struct ColumnFormatPlanOptions<'a> {
     pub cols_idx_integer: &'a [usize],
     /// Slice-local explicit decimal column indices.
     pub cols_idx_decimal: Option<&'a [usize]>,
+    /// Slice-local percent column indices.
+    pub cols_idx_percent: &'a [usize],
+    /// Percent decimal places.
+    pub percent_decimals: usize,
     /// Optional per-column format overrides.
     pub cols_fmt_overrides: &'a BTreeMap<usize, CellFormatPatch>,
     /// Base text format.
@@ -76,9 +176,19 @@ pub struct XlsxSheetPlan {
     col_names: Vec<String>,
     height_body: usize,
     header_grid: Vec<Vec<String>>,
+    body_row_start: usize,
     cols_idx_numeric: Vec<usize>,
     cols_idx_integer: Vec<usize>,
     cols_idx_decimal_specified: Vec<usize>,
+    cols_idx_percent: Vec<usize>,
+    cols_idx_hyperlink: BTreeMap<usize, Option<usize>>,
+    cols_idx_comment_text: BTreeMap<usize, usize>,
+    cols_idx_conditional_format: Vec<usize>,
+    cols_idx_dropdown: BTreeMap<usize, Vec<String>>,
+    cols_idx_summary: BTreeMap<usize, SummaryAgg>,
+    cols_idx_width: BTreeMap<usize, f64>,
+    cols_idx_fmt_override: BTreeMap<usize, CellFormatPatch>,
+    cols_idx_hidden: Vec<usize>,
     header_widths_by_col: Vec<usize>,
     body_widths_by_col: Vec<usize>,
     sheet_slices: Vec<SheetSlice>,
@@ -91,18 +201,45 @@ struct XlsxSheetRuntime {
     sheet_slice: SheetSlice,
     data_formats_by_col: Vec<Format>,
     fmt_scientific: Format,
+    fmt_date: Format,
+    fmt_datetime: Format,
     numeric_cols_idx: BTreeSet<usize>,
     integer_cols_idx: BTreeSet<usize>,
     decimal_cols_idx: BTreeSet<usize>,
     is_decimal_explicit: bool,
+    cols_idx_hyperlink: BTreeMap<usize, Option<usize>>,
+    cols_idx_comment_text: BTreeMap<usize, usize>,
+    rich_text_cells: BTreeMap<(usize, usize), Vec<RichTextRun>>,
+    formula_cells: BTreeMap<(usize, usize), String>,
+    summary_accumulators: BTreeMap<usize, SummaryAccumulator>,
+}
+
+/// Running aggregate of one summary column within a sheet part.
+#[derive(Debug, Clone)]
+struct SummaryAccumulator {
+    agg: SummaryAgg,
+    sum: f64,
+    count_numeric: usize,
+    count_non_blank: usize,
 }
 
 struct XlsxSinglePassPlan {
     col_names: Vec<String>,
     header_grid: Vec<Vec<String>>,
+    body_row_start: usize,
     cols_idx_numeric: Vec<usize>,
     cols_idx_integer: Vec<usize>,
     cols_idx_decimal_specified: Vec<usize>,
+    cols_idx_percent: Vec<usize>,
+    cols_idx_hyperlink: BTreeMap<usize, Option<usize>>,
+    cols_idx_comment_text: BTreeMap<usize, usize>,
+    cols_idx_conditional_format: Vec<usize>,
+    cols_idx_dropdown: BTreeMap<usize, Vec<String>>,
+    cols_idx_summary: BTreeMap<usize, SummaryAgg>,
+    cols_idx_width: BTreeMap<usize, f64>,
+    cols_idx_fmt_override: BTreeMap<usize, CellFormatPatch>,
+    cols_idx_hidden: Vec<usize>,
+    row_group_levels: BTreeMap<usize, u8>,
     header_widths_by_col: Vec<usize>,
     body_widths_by_col: Vec<usize>,
     num_frozen_rows: usize,
@@ -126,15 +263,52 @@ struct XlsxSheetPlanBuilder<'a> {
     cols_idx_numeric: Vec<usize>,
     cols_idx_integer: Vec<usize>,
     cols_idx_decimal_specified: Vec<usize>,
+    cols_idx_percent: Vec<usize>,
+    cols_idx_hyperlink: BTreeMap<usize, Option<usize>>,
+    cols_idx_comment_text: BTreeMap<usize, usize>,
+    cols_idx_conditional_format: Vec<usize>,
+    cols_idx_dropdown: BTreeMap<usize, Vec<String>>,
+    cols_idx_summary: BTreeMap<usize, SummaryAgg>,
+    cols_idx_width: BTreeMap<usize, f64>,
+    cols_idx_fmt_override: BTreeMap<usize, CellFormatPatch>,
+    cols_idx_hidden: Vec<usize>,
     header_widths_by_col: Vec<usize>,
     body_widths_by_col: Vec<usize>,
     rows_seen_for_autofit: usize,
     should_keep_missing_values: bool,
 }
 
+/// Direction in which `write_sheet_blocks` places consecutive blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SheetBlockLayout {
+    Stacked,
+    SideBySide,
+}
+
+impl SheetBlockLayout {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Stacked => "Stacked",
+            Self::SideBySide => "Side-by-side",
+        }
+    }
+}
+
+/// Worksheet shared by the blocks of one stacked or side-by-side write.
+struct SharedSheetTarget {
+    worksheet_index: usize,
+    sheet_name: String,
+    is_first_block: bool,
+    /// Worksheet column of the current block's first column.
+    col_offset: usize,
+    /// Autofit widths by worksheet column, kept across blocks.
+    header_widths_by_col: Vec<usize>,
+    body_widths_by_col: Vec<usize>,
+}
+
 /// Stateful workbook writer.
 pub struct XlsxWriter {
-    path_file_out: PathBuf,
+    path_file_out: Option<PathBuf>,
     workbook: Workbook,
     fmt_text: CellFormatPatch,
     fmt_integer: CellFormatPatch,
@@ -150,10 +324,11 @@ pub struct XlsxWriter {
 impl XlsxWriter {
     /// Create writer bound to output path and format/options presets.
     ///
-    /// The workbook is buffered in memory until [`Self::close`] is called.
+    /// The workbook is buffered in memory until [`Self::close`] is called. Pass `None`
+    /// as path to defer the sink to [`Self::close_to_writer`] or [`Self::close_to_bytes`].
     #[allow(clippy::too_many_arguments)]
     pub fn new(
-        path_file_out: PathBuf,
+        path_file_out: impl Into<Option<PathBuf>>,
         fmt_text: CellFormatPatch,
         fmt_integer: CellFormatPatch,
         fmt_decimal: CellFormatPatch,
@@ -162,7 +337,7 @@ impl XlsxWriter {
         options_write: XlsxWriteOptions,
     ) -> Self {
         Self {
-            path_file_out,
+            path_file_out: path_file_out.into(),
             workbook: Workbook::new(),
             fmt_text,
             fmt_integer,
@@ -176,9 +351,11 @@ impl XlsxWriter {
         }
     }
 
-    /// Return output file path as string.
-    pub fn file_out(&self) -> String {
-        self.path_file_out.to_string_lossy().to_string()
+    /// Return output file path as string, if one was configured.
+    pub fn file_out(&self) -> Option<String> {
+        self.path_file_out
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string())
     }
 
     /// Return immutable snapshot of per-sheet write reports.
@@ -186,18 +363,90 @@ impl XlsxWriter {
         self.reports.clone()
     }
 
+    /// Insert an image into a sheet that was already written by this writer.
+    pub fn insert_image(
+        &mut self,
+        sheet_name: &str,
+        placement: &ImagePlacement,
+    ) -> Result<(), String> {
+        if self.is_closed {
+            return Err("Cannot write after close().".to_string());
+        }
+        let worksheet = self
+            .workbook
+            .worksheet_from_name(sheet_name)
+            .map_err(|_| format!("Sheet not found: {sheet_name:?}"))?;
+        insert_image_placement(worksheet, placement)
+    }
+
     /// Flush workbook to disk. Idempotent.
     pub fn close(&mut self) -> Result<(), String> {
         if self.is_closed {
             return Ok(());
         }
+        let Some(path_file_out) = &self.path_file_out else {
+            return Err(
+                "No output path configured; use close_to_writer() or close_to_bytes().".to_string(),
+            );
+        };
+        self.workbook
+            .save(path_file_out)
+            .map_err(format_xlsx_error_text)?;
+        self.is_closed = true;
+        Ok(())
+    }
+
+    /// Set workbook title, subject, author, company, and keywords saved on close.
+    ///
+    /// Blank fields are left unset; a later call replaces all earlier properties.
+    pub fn set_properties(&mut self, properties: &DocumentProperties) -> Result<(), String> {
+        if self.is_closed {
+            return Err("Cannot set properties after close().".to_string());
+        }
+        self.workbook
+            .set_properties(&create_doc_properties(properties));
+        Ok(())
+    }
+
+    /// Reopen a closed writer so more sheets can be appended to the same workbook.
+    ///
+    /// Sheets written before the previous close are kept and emitted again by the next
+    /// close. Only workbooks created by this writer can be extended; reading arbitrary
+    /// `.xlsx` files is not supported.
+    pub fn reopen(&mut self) {
+        self.is_closed = false;
+    }
+
+    /// Serialize the workbook into an arbitrary seekable sink instead of `path_file_out`.
+    pub fn close_to_writer<W>(&mut self, writer: W) -> Result<(), String>
+    where
+        W: Write + Seek + Send,
+    {
+        if self.is_closed {
+            return Err("Cannot serialize after close().".to_string());
+        }
         self.workbook
-            .save(&self.path_file_out)
+            .save_to_writer(writer)
             .map_err(format_xlsx_error_text)?;
         self.is_closed = true;
         Ok(())
     }
 
+    /// Serialize the workbook to an in-memory XLSX buffer instead of disk.
+    ///
+    /// The writer is closed afterwards, so a later [`Self::close`] does not touch disk.
+    pub fn close_to_bytes(&mut self) -> Result<Vec<u8>, String> {
+        if self.is_closed {
+            return Err("Cannot serialize after close().".to_string());
+        }
+        let buffer = self
+            .workbook
+            .save_to_buffer()
+            .map_err(format_xlsx_error_text)?;
+        self.is_closed = true;
+        Ok(buffer)
+    }
+
     /// Write one sheet from in-memory dataframes.
     pub fn write_sheet_from_dataframes(
         &mut self,
@@ -209,7 +458,143 @@ impl XlsxWriter {
         if self.is_closed {
             return Err("Cannot write after close().".to_string());
         }
-        self.write_sheet(body, sheet_name, header, options)
+        self.write_sheet(body, sheet_name, header, options, None)
+    }
+
+    /// Write several dataframes top-down into one sheet, `gap_rows` blank rows apart.
+    ///
+    /// Each `(body, header)` block gets its own header and resolves column options by
+    /// its own column names. Freeze panes, sheet view, page setup, images, and the
+    /// autofilter apply once (the autofilter to the first block); rows are not frozen
+    /// unless `num_frozen_rows` is set. Autofit keeps the widest cell of each column
+    /// across blocks. Every block must fit in the sheet without splitting, and the
+    /// report lists the worksheet rows of each block.
+    pub fn write_sheets_stacked(
+        &mut self,
+        sheet_name: &str,
+        blocks: &[(&DataFrame, Option<&DataFrame>)],
+        gap_rows: usize,
+        options: &XlsxSheetWriteOptions,
+    ) -> Result<(), String> {
+        if self.is_closed {
+            return Err("Cannot write after close().".to_string());
+        }
+        self.write_sheet_blocks(
+            sheet_name,
+            blocks,
+            SheetBlockLayout::Stacked,
+            gap_rows,
+            options,
+        )
+    }
+
+    /// Write several dataframes left to right into one sheet, `gap_cols` blank columns apart.
+    ///
+    /// All blocks start below `num_reserved_top_rows`; sheet-level setup and column
+    /// options behave as in [`XlsxWriter::write_sheets_stacked`]. The blocks and gaps
+    /// must fit within the sheet's column limit.
+    pub fn write_sheets_side_by_side(
+        &mut self,
+        sheet_name: &str,
+        blocks: &[(&DataFrame, Option<&DataFrame>)],
+        gap_cols: usize,
+        options: &XlsxSheetWriteOptions,
+    ) -> Result<(), String> {
+        if self.is_closed {
+            return Err("Cannot write after close().".to_string());
+        }
+        let width_total = blocks.iter().map(|(_body, _)| _body.width()).sum::<usize>()
+            + gap_cols * blocks.len().saturating_sub(1);
+        if width_total > NCOLS_SHEET_MAX {
+            return Err(format!(
+                "Side-by-side blocks need {width_total} columns; a sheet holds at most {NCOLS_SHEET_MAX}."
+            ));
+        }
+        self.write_sheet_blocks(
+            sheet_name,
+            blocks,
+            SheetBlockLayout::SideBySide,
+            gap_cols,
+            options,
+        )
+    }
+
+    fn write_sheet_blocks(
+        &mut self,
+        sheet_name: &str,
+        blocks: &[(&DataFrame, Option<&DataFrame>)],
+        layout: SheetBlockLayout,
+        gap: usize,
+        options: &XlsxSheetWriteOptions,
+    ) -> Result<(), String> {
+        if blocks.is_empty() {
+            return Err("Sheet blocks need >= 1 block.".to_string());
+        }
+        self.validate_sheet_write_options(options)?;
+
+        let sheet_name_unique =
+            self.ensure_unique_sheet_name(&sanitize_sheet_name(sheet_name, "_"));
+        let worksheet_index = self.workbook.worksheets().len();
+        let worksheet =
+            if self.options_write.should_use_constant_memory && options.row_groups.is_empty() {
+                self.workbook.add_worksheet_with_constant_memory()
+            } else {
+                self.workbook.add_worksheet()
+            };
+        worksheet
+            .set_name(&sheet_name_unique)
+            .map_err(format_xlsx_error_text)?;
+
+        let mut target = SharedSheetTarget {
+            worksheet_index,
+            sheet_name: sheet_name_unique,
+            is_first_block: true,
+            col_offset: 0,
+            header_widths_by_col: vec![],
+            body_widths_by_col: vec![],
+        };
+        let mut options_block = options.clone();
+        if layout == SheetBlockLayout::Stacked {
+            options_block.num_frozen_rows = Some(options.num_frozen_rows.unwrap_or(0));
+        }
+        let mut report = XlsxReport::default();
+        let mut row_top = options.num_reserved_top_rows;
+        for (_idx, &(_body, _header)) in blocks.iter().enumerate() {
+            options_block.num_reserved_top_rows = row_top;
+            self.write_sheet(
+                _body,
+                sheet_name,
+                _header,
+                &options_block,
+                Some(&mut target),
+            )
+            .map_err(|err| format!("{} block {_idx}: {err}", layout.label()))?;
+            let report_block = self
+                .reports
+                .pop()
+                .ok_or_else(|| "Missing report of sheet block.".to_string())?;
+
+            let row_body_start = row_top + _header.map_or(1, DataFrame::height);
+            let block = SheetBlock {
+                sheet_name: target.sheet_name.clone(),
+                row_header_start: row_top,
+                row_body_start,
+                row_end_exclusive: row_body_start
+                    + _body.height()
+                    + calculate_summary_row_height(&options_block),
+                col_start_inclusive: target.col_offset,
+                col_end_exclusive: target.col_offset + _body.width(),
+            };
+            match layout {
+                SheetBlockLayout::Stacked => row_top = block.row_end_exclusive + gap,
+                SheetBlockLayout::SideBySide => target.col_offset = block.col_end_exclusive + gap,
+            }
+            report.blocks.push(block);
+            merge_report(&mut report, report_block);
+            target.is_first_block = false;
+        }
+        self.reports.push(report);
+        Ok(())
     }
 
     /// Plan one sheet from record batches without materializing the full body.
@@ -245,8 +630,7 @@ impl XlsxWriter {
         if self.is_closed {
             return Err("Cannot write after close().".to_string());
         }
-        validate_policy_autofit(&options.policy_autofit)?;
-        validate_policy_scientific(&options.policy_scientific)?;
+        self.validate_sheet_write_options(options)?;
 
         let mut builder =
             XlsxSheetPlanBuilder::new(sheet_name, header_grid, options, &self.options_write);
@@ -301,8 +685,7 @@ impl XlsxWriter {
         if self.is_closed {
             return Err("Cannot write after close().".to_string());
         }
-        validate_policy_autofit(&options.policy_autofit)?;
-        validate_policy_scientific(&options.policy_scientific)?;
+        self.validate_sheet_write_options(options)?;
         if matches!(
             options.policy_autofit.mode,
             AutofitMode::Body | AutofitMode::All
@@ -312,6 +695,11 @@ impl XlsxWriter {
                     .to_string(),
             );
         }
+        if options.should_write_table {
+            return Err(
+                "single-pass XLSX writing does not support should_write_table.".to_string(),
+            );
+        }
         self.write_sheet_record_batches_single_pass(batches, sheet_name, header_grid, options)
     }
 
@@ -351,19 +739,38 @@ impl XlsxWriter {
             .iter()
             .map(String::as_str)
             .collect::<Vec<_>>();
-        let header_row_count = plan.header_grid.len();
+        let body_row_start = plan.body_row_start;
         let value_policy = self.options_write.value_policy.clone();
+        let row_group_levels = calculate_row_group_levels(&options.row_groups);
 
-        let mut report = XlsxReport {
-            sheets: vec![],
-            warnings: vec![],
-        };
+        let mut report = XlsxReport::default();
+        record_column_kinds(
+            &mut report,
+            &col_names_ref,
+            &plan.cols_idx_numeric,
+            &plan.cols_idx_integer,
+            &plan.cols_idx_decimal_specified,
+            &options.policy_scientific,
+        );
+        self.warn_invalid_format_values(options, &mut report);
+        warn_out_of_range_formula_cells(
+            &mut report,
+            options,
+            plan.height_body,
+            col_names_ref.len(),
+        );
         let mut runtime_sheets = Vec::with_capacity(plan.sheet_slices.len());
+        let mut count_integer_text = 0usize;
 
         for sheet_slice in &plan.sheet_slices {
             let sheet_name_unique = self.ensure_unique_sheet_name(&sheet_slice.sheet_name);
             let worksheet_index = self.workbook.worksheets().len();
-            let worksheet = self.workbook.add_worksheet_with_constant_memory();
+            // Constant-memory rows are flushed without outline levels.
+            let worksheet = if options.row_groups.is_empty() {
+                self.workbook.add_worksheet_with_constant_memory()
+            } else {
+                self.workbook.add_worksheet()
+            };
             worksheet
                 .set_name(&sheet_name_unique)
                 .map_err(format_xlsx_error_text)?;
@@ -383,6 +790,16 @@ impl XlsxWriter {
                 sheet_slice.col_start_inclusive,
                 sheet_slice.col_end_exclusive,
             );
+            let cols_idx_percent_slice = calculate_slice_indices(
+                &plan.cols_idx_percent,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+            );
+            let cols_fmt_overrides_slice = calculate_slice_mapped_indices(
+                &plan.cols_idx_fmt_override,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+            );
 
             let column_format_plan = plan_column_formats(ColumnFormatPlanOptions {
                 width_data: sheet_slice.col_end_exclusive - sheet_slice.col_start_inclusive,
@@ -393,7 +810,9 @@ impl XlsxWriter {
                 } else {
                     Some(&cols_idx_decimal_slice)
                 },
-                cols_fmt_overrides: &BTreeMap::new(),
+                cols_idx_percent: &cols_idx_percent_slice,
+                percent_decimals: options.percent_decimals.unwrap_or(PERCENT_DECIMALS_DEFAULT),
+                cols_fmt_overrides: &cols_fmt_overrides_slice,
                 fmt_text: &self.fmt_text,
                 fmt_integer: &self.fmt_integer,
                 fmt_decimal: &self.fmt_decimal,
@@ -405,10 +824,12 @@ impl XlsxWriter {
                 .iter()
                 .map(create_rust_xlsx_format)
                 .collect();
-            let fmt_scientific_patch = self
-                .fmt_scientific
-                .merge(&self.options_write.base_format_patch);
-            let fmt_scientific = create_rust_xlsx_format(&fmt_scientific_patch);
+            let fmt_scientific = create_scientific_format(
+                &self.fmt_scientific,
+                &self.options_write,
+                &options.policy_scientific,
+            );
+            let (fmt_date, fmt_datetime) = create_date_formats(&self.fmt_text, &self.options_write);
             let fmt_header = create_rust_xlsx_format(&self.fmt_header);
 
             let header_grid_slice = plan
@@ -422,8 +843,11 @@ impl XlsxWriter {
             write_header(
                 worksheet,
                 header_grid_slice,
+                options.num_reserved_top_rows,
                 options.should_merge_header,
+                options.should_merge_header_vertical,
                 &fmt_header,
+                0,
             )?;
 
             worksheet
@@ -432,7 +856,85 @@ impl XlsxWriter {
                     cast_col_num(options.num_frozen_cols)?,
                 )
                 .map_err(format_xlsx_error_text)?;
+            apply_sheet_view(worksheet, options, &mut report);
+            apply_default_layout(
+                worksheet,
+                &self.options_write,
+                sheet_slice.col_end_exclusive - sheet_slice.col_start_inclusive,
+                0,
+            )?;
+            apply_page_setup(worksheet, options.page_setup.as_ref());
+            apply_print_layout(
+                worksheet,
+                options.page_setup.as_ref(),
+                options.num_reserved_top_rows,
+                body_row_start,
+                sheet_slice,
+            )?;
+            for _placement in &options.images {
+                insert_image_placement(worksheet, _placement)?;
+            }
+            apply_row_groups(
+                worksheet,
+                &row_group_levels,
+                body_row_start,
+                sheet_slice.row_start_inclusive,
+                sheet_slice.row_start_inclusive,
+                sheet_slice.row_end_exclusive,
+            )?;
+            if options.should_autofilter {
+                apply_autofilter(
+                    worksheet,
+                    body_row_start,
+                    sheet_slice.row_end_exclusive - sheet_slice.row_start_inclusive,
+                    sheet_slice.col_end_exclusive - sheet_slice.col_start_inclusive,
+                )?;
+            }
+            if options.should_write_table {
+                add_data_table(
+                    worksheet,
+                    body_row_start,
+                    sheet_slice.row_end_exclusive - sheet_slice.row_start_inclusive,
+                    &plan.header_grid[plan.header_grid.len() - 1]
+                        [sheet_slice.col_start_inclusive..sheet_slice.col_end_exclusive],
+                    &fmt_header,
+                    options.table_style.as_deref(),
+                    0,
+                )?;
+            }
+            apply_conditional_formats(
+                worksheet,
+                &options.conditional_formats,
+                &plan.cols_idx_conditional_format,
+                body_row_start,
+                sheet_slice.row_end_exclusive - sheet_slice.row_start_inclusive,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+                0,
+            )?;
+            apply_banded_rows(
+                worksheet,
+                options,
+                body_row_start,
+                sheet_slice.row_end_exclusive - sheet_slice.row_start_inclusive,
+                sheet_slice.col_end_exclusive - sheet_slice.col_start_inclusive,
+                0,
+            )?;
+            apply_dropdown_validations(
+                worksheet,
+                &plan.cols_idx_dropdown,
+                body_row_start,
+                sheet_slice.row_end_exclusive - sheet_slice.row_start_inclusive,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+                0,
+            )?;
 
+            let cols_idx_hidden_slice = calculate_slice_indices(
+                &plan.cols_idx_hidden,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+            );
             apply_column_widths(
                 worksheet,
                 &options.policy_autofit,
@@ -440,17 +942,37 @@ impl XlsxWriter {
                     [sheet_slice.col_start_inclusive..sheet_slice.col_end_exclusive],
                 &plan.body_widths_by_col
                     [sheet_slice.col_start_inclusive..sheet_slice.col_end_exclusive],
+                &cols_idx_hidden_slice,
+            )?;
+            apply_explicit_column_widths(
+                worksheet,
+                &plan.cols_idx_width,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+                0,
             )?;
+            apply_hidden_columns(worksheet, &cols_idx_hidden_slice, 0)?;
 
             runtime_sheets.push(XlsxSheetRuntime {
                 worksheet_index,
                 sheet_slice: sheet_slice.clone(),
                 data_formats_by_col,
                 fmt_scientific,
+                fmt_date,
+                fmt_datetime,
                 numeric_cols_idx: cols_idx_numeric_slice.iter().copied().collect(),
                 integer_cols_idx: cols_idx_integer_slice.iter().copied().collect(),
                 decimal_cols_idx: cols_idx_decimal_slice.iter().copied().collect(),
                 is_decimal_explicit: !cols_idx_decimal_slice.is_empty(),
+                cols_idx_hyperlink: plan.cols_idx_hyperlink.clone(),
+                cols_idx_comment_text: plan.cols_idx_comment_text.clone(),
+                rich_text_cells: options.rich_text_cells.clone(),
+                formula_cells: options.formula_cells.clone(),
+                summary_accumulators: create_summary_accumulators(
+                    &plan.cols_idx_summary,
+                    sheet_slice.col_start_inclusive,
+                    sheet_slice.col_end_exclusive,
+                ),
             });
 
             report.sheets.push(SheetSlice {
@@ -471,16 +993,17 @@ impl XlsxWriter {
                 return Err("All record batches must have identical column names.".to_string());
             }
 
-            for runtime in &runtime_sheets {
-                write_record_batch_to_runtime_sheet(
+            for runtime in &mut runtime_sheets {
+                count_integer_text += write_record_batch_to_runtime_sheet(
                     &mut self.workbook,
                     runtime,
                     &df_batch,
                     row_offset,
-                    header_row_count,
+                    body_row_start,
                     plan.should_keep_missing_values,
                     &value_policy,
                     &options.policy_scientific,
+                    &mut report,
                 )?;
             }
             row_offset += df_batch.height();
@@ -492,7 +1015,21 @@ impl XlsxWriter {
                 plan.height_body
             ));
         }
+        for runtime in &runtime_sheets {
+            write_summary_row(
+                self.workbook
+                    .worksheet_from_index(runtime.worksheet_index)
+                    .map_err(format_xlsx_error_text)?,
+                &runtime.summary_accumulators,
+                body_row_start,
+                runtime.sheet_slice.row_end_exclusive - runtime.sheet_slice.row_start_inclusive,
+                &runtime.data_formats_by_col,
+                options.should_use_summary_formulas,
+                0,
+            )?;
+        }
 
+        warn_integer_text_cells(&mut report, count_integer_text);
         self.reports.push(report);
         Ok(())
     }
@@ -521,28 +1058,35 @@ impl XlsxWriter {
             .iter()
             .map(String::as_str)
             .collect::<Vec<_>>();
-        let header_row_count = plan.header_grid.len();
+        let body_row_start = plan.body_row_start;
         let max_data_rows = NROWS_SHEET_MAX
-            .checked_sub(header_row_count)
+            .checked_sub(body_row_start + calculate_summary_row_height(options))
             .ok_or_else(|| {
-                format!("Header too tall: height_header={header_row_count} exceeds Excel limit.")
+                format!("Header too tall: height_header={body_row_start} exceeds Excel limit.")
             })?;
         if max_data_rows == 0 {
             return Err(format!(
-                "Header too tall: height_header={header_row_count} exceeds Excel limit."
+                "Header too tall: height_header={body_row_start} exceeds Excel limit."
             ));
         }
 
-        let mut report = XlsxReport {
-            sheets: vec![],
-            warnings: vec![],
-        };
+        let mut report = XlsxReport::default();
+        record_column_kinds(
+            &mut report,
+            &col_names_ref,
+            &plan.cols_idx_numeric,
+            &plan.cols_idx_integer,
+            &plan.cols_idx_decimal_specified,
+            &options.policy_scientific,
+        );
+        self.warn_invalid_format_values(options, &mut report);
         let mut runtime_sheets: Vec<XlsxSinglePassRuntimeSheet> = vec![];
         let mut active_row_start: Option<usize> = None;
         let mut next_part_idx = 1usize;
         let mut rows_written = 0usize;
+        let mut count_integer_text = 0usize;
 
-        self.write_single_pass_batch(
+        count_integer_text += self.write_single_pass_batch(
             &plan,
             options,
             sheet_name,
@@ -559,7 +1103,7 @@ impl XlsxWriter {
 
         for batch in iter_batches {
             let batch = batch?;
-            self.write_single_pass_batch(
+            count_integer_text += self.write_single_pass_batch(
                 &plan,
                 options,
                 sheet_name,
@@ -588,7 +1132,10 @@ impl XlsxWriter {
                 &mut report,
             )?;
         }
+        self.finish_single_pass_runtime_sheets(&plan, options, &runtime_sheets, &report)?;
 
+        warn_out_of_range_formula_cells(&mut report, options, rows_written, col_names_ref.len());
+        warn_integer_text_cells(&mut report, count_integer_text);
         self.reports.push(report);
         Ok(())
     }
@@ -624,7 +1171,7 @@ impl XlsxWriter {
             None => vec![col_names.clone()],
         };
 
-        let cols_idx_numeric = if self.options_write.should_infer_numeric_cols {
+        let mut cols_idx_numeric = if self.options_write.should_infer_numeric_cols {
             select_numeric_column_indices_from_arrow_schema(schema)
         } else {
             vec![]
@@ -636,13 +1183,42 @@ impl XlsxWriter {
         };
         let cols_idx_integer_specified =
             select_sorted_indices_from_refs(&col_names_ref, options.cols_integer.as_deref())?;
-        let cols_idx_decimal_specified =
+        let mut cols_idx_decimal_specified =
             select_sorted_indices_from_refs(&col_names_ref, options.cols_decimal.as_deref())?;
-        let cols_idx_integer = if cols_idx_integer_specified.is_empty() {
+        let cols_idx_percent =
+            select_sorted_indices_from_refs(&col_names_ref, options.cols_percent.as_deref())?;
+        let cols_idx_hyperlink = select_hyperlink_column_indices(
+            &col_names_ref,
+            options.cols_hyperlink.as_deref(),
+            options.cols_hyperlink_text.as_ref(),
+        )?;
+        let cols_idx_comment_text =
+            select_column_index_pairs(&col_names_ref, options.cols_comment_text.as_ref())?;
+        let cols_idx_conditional_format =
+            select_conditional_format_column_indices(&col_names_ref, &options.conditional_formats)?;
+        let cols_idx_dropdown =
+            select_mapped_column_indices(&col_names_ref, options.cols_dropdown.as_ref())?;
+        let cols_idx_summary =
+            select_mapped_column_indices(&col_names_ref, options.summary_row.as_ref())?;
+        let cols_idx_width =
+            select_mapped_column_indices(&col_names_ref, options.cols_width.as_ref())?;
+        let mut cols_idx_fmt_override =
+            select_mapped_column_indices(&col_names_ref, options.cols_fmt_overrides.as_ref())?;
+        let cols_idx_hidden =
+            select_sorted_indices_from_refs(&col_names_ref, options.cols_hidden.as_deref())?;
+        let mut cols_idx_integer = if cols_idx_integer_specified.is_empty() {
             cols_idx_integer_inferred
         } else {
             cols_idx_integer_specified
         };
+        apply_currency_columns(
+            &select_sorted_indices_from_refs(&col_names_ref, options.cols_currency.as_deref())?,
+            options,
+            &mut cols_idx_numeric,
+            &mut cols_idx_integer,
+            &mut cols_idx_decimal_specified,
+            &mut cols_idx_fmt_override,
+        );
 
         let rows_chunk = calculate_row_chunk_size(width_body, &self.options_write.row_chunk_policy);
         if rows_chunk == 0 {
@@ -676,17 +1252,28 @@ impl XlsxWriter {
                 }
             }
         }
-        let header_row_count = header_grid.len();
+        let body_row_start = options.num_reserved_top_rows + header_grid.len();
 
         Ok(XlsxSinglePassPlan {
             col_names,
             header_grid,
+            body_row_start,
             cols_idx_numeric,
             cols_idx_integer,
             cols_idx_decimal_specified,
+            cols_idx_percent,
+            cols_idx_hyperlink,
+            cols_idx_comment_text,
+            cols_idx_conditional_format,
+            cols_idx_dropdown,
+            cols_idx_summary,
+            cols_idx_width,
+            cols_idx_fmt_override,
+            cols_idx_hidden,
+            row_group_levels: calculate_row_group_levels(&options.row_groups),
             header_widths_by_col,
             body_widths_by_col,
-            num_frozen_rows: options.num_frozen_rows.unwrap_or(header_row_count),
+            num_frozen_rows: options.num_frozen_rows.unwrap_or(body_row_start),
             should_keep_missing_values,
         })
     }
@@ -705,7 +1292,7 @@ impl XlsxWriter {
         next_part_idx: &mut usize,
         runtime_sheets: &mut Vec<XlsxSinglePassRuntimeSheet>,
         report: &mut XlsxReport,
-    ) -> Result<(), String> {
+    ) -> Result<usize, String> {
         let batch_col_names = batch
             .schema()
             .iter_names()
@@ -718,6 +1305,7 @@ impl XlsxWriter {
         let batch_start = row_offset;
         let batch_end = row_offset + batch.len();
         let mut segment_start = batch_start;
+        let mut count_integer_text = 0usize;
         while segment_start < batch_end {
             let row_part_start = (segment_start / max_data_rows) * max_data_rows;
             self.ensure_single_pass_runtime_sheets(
@@ -733,15 +1321,27 @@ impl XlsxWriter {
             )?;
 
             for runtime in runtime_sheets.iter_mut() {
-                write_arrow_record_batch_to_runtime_sheet(
+                let sheet_slice = &runtime.runtime.sheet_slice;
+                apply_row_groups(
+                    self.workbook
+                        .worksheet_from_index(runtime.runtime.worksheet_index)
+                        .map_err(format_xlsx_error_text)?,
+                    &plan.row_group_levels,
+                    plan.body_row_start,
+                    sheet_slice.row_start_inclusive,
+                    usize::max(batch_start, sheet_slice.row_start_inclusive),
+                    usize::min(batch_end, sheet_slice.row_end_exclusive),
+                )?;
+                count_integer_text += write_arrow_record_batch_to_runtime_sheet(
                     &mut self.workbook,
-                    &runtime.runtime,
+                    &mut runtime.runtime,
                     batch,
                     row_offset,
-                    plan.header_grid.len(),
+                    plan.body_row_start,
                     plan.should_keep_missing_values,
                     &self.options_write.value_policy,
                     &options.policy_scientific,
+                    report,
                 )?;
                 let report_sheet = &mut report.sheets[runtime.report_index];
                 let overlap_end =
@@ -749,14 +1349,158 @@ impl XlsxWriter {
                 if overlap_end > report_sheet.row_end_exclusive {
                     report_sheet.row_end_exclusive = overlap_end;
                 }
+                if options.should_autofilter {
+                    let worksheet = self
+                        .workbook
+                        .worksheet_from_index(runtime.runtime.worksheet_index)
+                        .map_err(format_xlsx_error_text)?;
+                    apply_autofilter(
+                        worksheet,
+                        plan.body_row_start,
+                        report_sheet.row_end_exclusive - report_sheet.row_start_inclusive,
+                        report_sheet.col_end_exclusive - report_sheet.col_start_inclusive,
+                    )?;
+                }
             }
 
             segment_start = usize::min(batch_end, row_part_start + max_data_rows);
         }
 
+        Ok(count_integer_text)
+    }
+
+    /// Apply extent-dependent sheet features once a single-pass row part is complete.
+    fn finish_single_pass_runtime_sheets(
+        &mut self,
+        plan: &XlsxSinglePassPlan,
+        options: &XlsxSheetWriteOptions,
+        runtime_sheets: &[XlsxSinglePassRuntimeSheet],
+        report: &XlsxReport,
+    ) -> Result<(), String> {
+        for runtime in runtime_sheets {
+            let report_sheet = &report.sheets[runtime.report_index];
+            let worksheet = self
+                .workbook
+                .worksheet_from_index(runtime.runtime.worksheet_index)
+                .map_err(format_xlsx_error_text)?;
+            apply_conditional_formats(
+                worksheet,
+                &options.conditional_formats,
+                &plan.cols_idx_conditional_format,
+                plan.body_row_start,
+                report_sheet.row_end_exclusive - report_sheet.row_start_inclusive,
+                report_sheet.col_start_inclusive,
+                report_sheet.col_end_exclusive,
+                0,
+            )?;
+            apply_banded_rows(
+                worksheet,
+                options,
+                plan.body_row_start,
+                report_sheet.row_end_exclusive - report_sheet.row_start_inclusive,
+                report_sheet.col_end_exclusive - report_sheet.col_start_inclusive,
+                0,
+            )?;
+            apply_dropdown_validations(
+                worksheet,
+                &plan.cols_idx_dropdown,
+                plan.body_row_start,
+                report_sheet.row_end_exclusive - report_sheet.row_start_inclusive,
+                report_sheet.col_start_inclusive,
+                report_sheet.col_end_exclusive,
+                0,
+            )?;
+            apply_print_layout(
+                worksheet,
+                options.page_setup.as_ref(),
+                options.num_reserved_top_rows,
+                plan.body_row_start,
+                report_sheet,
+            )?;
+            write_summary_row(
+                worksheet,
+                &runtime.runtime.summary_accumulators,
+                plan.body_row_start,
+                report_sheet.row_end_exclusive - report_sheet.row_start_inclusive,
+                &runtime.runtime.data_formats_by_col,
+                options.should_use_summary_formulas,
+                0,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Validate per-sheet options together with the writer-level options they depend on.
+    fn validate_sheet_write_options(&self, options: &XlsxSheetWriteOptions) -> Result<(), String> {
+        validate_policy_autofit(&options.policy_autofit)?;
+        validate_policy_scientific(&options.policy_scientific)?;
+        validate_value_policy(&self.options_write.value_policy)?;
+        validate_sheet_split_template(self.options_write.sheet_split_template.as_deref())?;
+        validate_default_layout(&self.options_write)?;
+        validate_table_options(options)?;
+        validate_conditional_formats(&options.conditional_formats)?;
+        validate_band_color(options)?;
+        validate_dropdowns(options)?;
+        validate_column_widths(options)?;
+        validate_currency_options(options)?;
+        validate_percent_options(options)?;
+        validate_header_merge_options(options)?;
+        validate_row_groups(&options.row_groups)?;
+        validate_rich_text_cells(&options.rich_text_cells)?;
+        validate_formula_cells(options)?;
+        validate_zoom(options.zoom)?;
+        validate_page_setup(options.page_setup.as_ref())?;
         Ok(())
     }
 
+    /// Report out-of-range format rotations and fill patterns once per write
+    /// call; they are not applied.
+    fn warn_invalid_format_values(&self, options: &XlsxSheetWriteOptions, report: &mut XlsxReport) {
+        let fmts = [
+            &self.fmt_text,
+            &self.fmt_integer,
+            &self.fmt_decimal,
+            &self.fmt_scientific,
+            &self.fmt_header,
+            &self.options_write.base_format_patch,
+        ]
+        .into_iter()
+        .chain(
+            options
+                .cols_fmt_overrides
+                .iter()
+                .flat_map(|_fmts| _fmts.values()),
+        )
+        .chain(
+            options
+                .rich_text_cells
+                .values()
+                .flatten()
+                .map(|(_fmt, _)| _fmt),
+        )
+        .collect::<Vec<_>>();
+        let rotations_invalid = fmts
+            .iter()
+            .filter_map(|_fmt| _fmt.rotation)
+            .filter(|_rotation| !is_valid_rotation(*_rotation))
+            .collect::<BTreeSet<_>>();
+        for rotation in rotations_invalid {
+            report.warn(format!(
+                "Invalid cell format rotation {rotation}; expected -90..=90 or 270. Ignored."
+            ));
+        }
+        let patterns_invalid = fmts
+            .iter()
+            .filter_map(|_fmt| _fmt.pattern)
+            .filter(|_pattern| *_pattern > FORMAT_PATTERN_MAX)
+            .collect::<BTreeSet<_>>();
+        for pattern in patterns_invalid {
+            report.warn(format!(
+                "Invalid cell format pattern {pattern}; expected 0..={FORMAT_PATTERN_MAX}. Ignored."
+            ));
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn ensure_single_pass_runtime_sheets(
         &mut self,
@@ -774,6 +1518,7 @@ impl XlsxWriter {
             return Ok(());
         }
 
+        self.finish_single_pass_runtime_sheets(plan, options, runtime_sheets, report)?;
         runtime_sheets.clear();
         *active_row_start = Some(row_part_start);
 
@@ -783,16 +1528,25 @@ impl XlsxWriter {
         while col_start < width_body {
             let col_end = usize::min(width_body, col_start + NCOLS_SHEET_MAX);
             let sheet_name_base = sanitize_sheet_name(sheet_name, "_");
-            let sheet_name_planned = if *next_part_idx == 1 && !has_multiple_col_parts {
-                sheet_name_base
-            } else {
-                create_sheet_identifier_local(&sheet_name_base, *next_part_idx)
+            let sheet_name_planned = match self.options_write.sheet_split_template.as_deref() {
+                _ if *next_part_idx == 1 && !has_multiple_col_parts => sheet_name_base,
+                Some(template) => create_sheet_identifier_from_template(
+                    &sheet_name_base,
+                    *next_part_idx,
+                    template,
+                ),
+                None => create_sheet_identifier_local(&sheet_name_base, *next_part_idx),
             };
             *next_part_idx += 1;
 
             let sheet_name_unique = self.ensure_unique_sheet_name(&sheet_name_planned);
             let worksheet_index = self.workbook.worksheets().len();
-            let worksheet = self.workbook.add_worksheet_with_constant_memory();
+            // Constant-memory rows are flushed without outline levels.
+            let worksheet = if options.row_groups.is_empty() {
+                self.workbook.add_worksheet_with_constant_memory()
+            } else {
+                self.workbook.add_worksheet()
+            };
             worksheet
                 .set_name(&sheet_name_unique)
                 .map_err(format_xlsx_error_text)?;
@@ -803,6 +1557,10 @@ impl XlsxWriter {
                 calculate_slice_indices(&plan.cols_idx_integer, col_start, col_end);
             let cols_idx_decimal_slice =
                 calculate_slice_indices(&plan.cols_idx_decimal_specified, col_start, col_end);
+            let cols_idx_percent_slice =
+                calculate_slice_indices(&plan.cols_idx_percent, col_start, col_end);
+            let cols_fmt_overrides_slice =
+                calculate_slice_mapped_indices(&plan.cols_idx_fmt_override, col_start, col_end);
             let column_format_plan = plan_column_formats(ColumnFormatPlanOptions {
                 width_data: col_end - col_start,
                 cols_idx_numeric: &cols_idx_numeric_slice,
@@ -812,7 +1570,9 @@ impl XlsxWriter {
                 } else {
                     Some(&cols_idx_decimal_slice)
                 },
-                cols_fmt_overrides: &BTreeMap::new(),
+                cols_idx_percent: &cols_idx_percent_slice,
+                percent_decimals: options.percent_decimals.unwrap_or(PERCENT_DECIMALS_DEFAULT),
+                cols_fmt_overrides: &cols_fmt_overrides_slice,
                 fmt_text: &self.fmt_text,
                 fmt_integer: &self.fmt_integer,
                 fmt_decimal: &self.fmt_decimal,
@@ -823,10 +1583,12 @@ impl XlsxWriter {
                 .iter()
                 .map(create_rust_xlsx_format)
                 .collect::<Vec<_>>();
-            let fmt_scientific_patch = self
-                .fmt_scientific
-                .merge(&self.options_write.base_format_patch);
-            let fmt_scientific = create_rust_xlsx_format(&fmt_scientific_patch);
+            let fmt_scientific = create_scientific_format(
+                &self.fmt_scientific,
+                &self.options_write,
+                &options.policy_scientific,
+            );
+            let (fmt_date, fmt_datetime) = create_date_formats(&self.fmt_text, &self.options_write);
             let fmt_header = create_rust_xlsx_format(&self.fmt_header);
             let header_grid_slice = plan
                 .header_grid
@@ -836,8 +1598,11 @@ impl XlsxWriter {
             write_header(
                 worksheet,
                 header_grid_slice,
+                options.num_reserved_top_rows,
                 options.should_merge_header,
+                options.should_merge_header_vertical,
                 &fmt_header,
+                0,
             )?;
             worksheet
                 .set_freeze_panes(
@@ -845,12 +1610,26 @@ impl XlsxWriter {
                     cast_col_num(options.num_frozen_cols)?,
                 )
                 .map_err(format_xlsx_error_text)?;
+            apply_sheet_view(worksheet, options, report);
+            apply_default_layout(worksheet, &self.options_write, col_end - col_start, 0)?;
+            apply_page_setup(worksheet, options.page_setup.as_ref());
+            for _placement in &options.images {
+                insert_image_placement(worksheet, _placement)?;
+            }
+            if options.should_autofilter {
+                apply_autofilter(worksheet, plan.body_row_start, 0, col_end - col_start)?;
+            }
+            let cols_idx_hidden_slice =
+                calculate_slice_indices(&plan.cols_idx_hidden, col_start, col_end);
             apply_column_widths(
                 worksheet,
                 &options.policy_autofit,
                 &plan.header_widths_by_col[col_start..col_end],
                 &plan.body_widths_by_col[col_start..col_end],
+                &cols_idx_hidden_slice,
             )?;
+            apply_explicit_column_widths(worksheet, &plan.cols_idx_width, col_start, col_end, 0)?;
+            apply_hidden_columns(worksheet, &cols_idx_hidden_slice, 0)?;
 
             let report_index = report.sheets.len();
             report.sheets.push(SheetSlice {
@@ -873,10 +1652,21 @@ impl XlsxWriter {
                     },
                     data_formats_by_col,
                     fmt_scientific,
+                    fmt_date,
+                    fmt_datetime,
                     numeric_cols_idx: cols_idx_numeric_slice.iter().copied().collect(),
                     integer_cols_idx: cols_idx_integer_slice.iter().copied().collect(),
                     decimal_cols_idx: cols_idx_decimal_slice.iter().copied().collect(),
                     is_decimal_explicit: !cols_idx_decimal_slice.is_empty(),
+                    cols_idx_hyperlink: plan.cols_idx_hyperlink.clone(),
+                    cols_idx_comment_text: plan.cols_idx_comment_text.clone(),
+                    rich_text_cells: options.rich_text_cells.clone(),
+                    formula_cells: options.formula_cells.clone(),
+                    summary_accumulators: create_summary_accumulators(
+                        &plan.cols_idx_summary,
+                        col_start,
+                        col_end,
+                    ),
                 },
                 report_index,
             });
@@ -893,9 +1683,9 @@ impl XlsxWriter {
         sheet_name: &str,
         header: Option<&DataFrame>,
         options: &XlsxSheetWriteOptions,
+        mut shared: Option<&mut SharedSheetTarget>,
     ) -> Result<(), String> {
-        validate_policy_autofit(&options.policy_autofit)?;
-        validate_policy_scientific(&options.policy_scientific)?;
+        self.validate_sheet_write_options(options)?;
 
         let should_keep_missing_values = options
             .should_keep_missing_values
@@ -930,7 +1720,7 @@ impl XlsxWriter {
             header_grid = extract_string_grid_from_dataframe(df_header_custom)?;
         }
 
-        let cols_idx_numeric = if self.options_write.should_infer_numeric_cols {
+        let mut cols_idx_numeric = if self.options_write.should_infer_numeric_cols {
             select_numeric_column_indices(body)
         } else {
             vec![]
@@ -944,38 +1734,102 @@ impl XlsxWriter {
 
         let cols_idx_integer_specified =
             select_sorted_indices_from_refs(&col_names, options.cols_integer.as_deref())?;
-        let cols_idx_decimal_specified =
+        let mut cols_idx_decimal_specified =
             select_sorted_indices_from_refs(&col_names, options.cols_decimal.as_deref())?;
-
-        let cols_idx_integer = if cols_idx_integer_specified.is_empty() {
+        let cols_idx_percent =
+            select_sorted_indices_from_refs(&col_names, options.cols_percent.as_deref())?;
+        let cols_idx_hyperlink = select_hyperlink_column_indices(
+            &col_names,
+            options.cols_hyperlink.as_deref(),
+            options.cols_hyperlink_text.as_ref(),
+        )?;
+        let cols_idx_comment_text =
+            select_column_index_pairs(&col_names, options.cols_comment_text.as_ref())?;
+        let cols_idx_conditional_format =
+            select_conditional_format_column_indices(&col_names, &options.conditional_formats)?;
+        let cols_idx_dropdown =
+            select_mapped_column_indices(&col_names, options.cols_dropdown.as_ref())?;
+        let cols_idx_summary =
+            select_mapped_column_indices(&col_names, options.summary_row.as_ref())?;
+        let cols_idx_width = select_mapped_column_indices(&col_names, options.cols_width.as_ref())?;
+        let mut cols_idx_fmt_override =
+            select_mapped_column_indices(&col_names, options.cols_fmt_overrides.as_ref())?;
+        let cols_idx_hidden =
+            select_sorted_indices_from_refs(&col_names, options.cols_hidden.as_deref())?;
+
+        let mut cols_idx_integer = if cols_idx_integer_specified.is_empty() {
             cols_idx_integer_inferred
         } else {
             cols_idx_integer_specified
         };
-        let header_row_count = header_grid.len();
+        apply_currency_columns(
+            &select_sorted_indices_from_refs(&col_names, options.cols_currency.as_deref())?,
+            options,
+            &mut cols_idx_numeric,
+            &mut cols_idx_integer,
+            &mut cols_idx_decimal_specified,
+            &mut cols_idx_fmt_override,
+        );
+        let body_row_start = options.num_reserved_top_rows + header_grid.len();
 
-        let mut report = XlsxReport {
-            sheets: vec![],
-            warnings: vec![],
-        };
+        let mut report = XlsxReport::default();
+        record_column_kinds(
+            &mut report,
+            &col_names,
+            &cols_idx_numeric,
+            &cols_idx_integer,
+            &cols_idx_decimal_specified,
+            &options.policy_scientific,
+        );
+        self.warn_invalid_format_values(options, &mut report);
+        warn_out_of_range_formula_cells(&mut report, options, height_body, width_body);
+        let mut count_integer_text = 0usize;
 
         let sheet_slices = plan_sheet_slices(
             height_body,
             width_body,
-            header_row_count,
+            body_row_start + calculate_summary_row_height(options),
             &sanitize_sheet_name(sheet_name, "_"),
+            self.options_write.split_mode,
+            self.options_write.sheet_split_template.as_deref(),
             &mut report,
         )?;
 
-        let num_frozen_rows = options.num_frozen_rows.unwrap_or(header_row_count);
+        if shared.is_some() && sheet_slices.len() != 1 {
+            return Err(format!(
+                "Block of {height_body} row(s) x {width_body} column(s) at row {body_row_start} does not fit in one sheet."
+            ));
+        }
+        let is_sheet_setup = shared.as_deref().is_none_or(|target| target.is_first_block);
+        let col_offset = shared.as_deref().map_or(0, |target| target.col_offset);
+
+        let num_frozen_rows = options.num_frozen_rows.unwrap_or(body_row_start);
+        let row_group_levels = calculate_row_group_levels(&options.row_groups);
 
         for _sheet_slice in sheet_slices {
             let sheet_slice = _sheet_slice;
-            let sheet_name_unique = self.ensure_unique_sheet_name(&sheet_slice.sheet_name);
-            let worksheet = self.workbook.add_worksheet();
-            worksheet
-                .set_name(&sheet_name_unique)
-                .map_err(format_xlsx_error_text)?;
+            let (sheet_name_unique, worksheet) = match shared.as_deref() {
+                Some(target) => (
+                    target.sheet_name.clone(),
+                    self.workbook
+                        .worksheet_from_index(target.worksheet_index)
+                        .map_err(format_xlsx_error_text)?,
+                ),
+                None => {
+                    let sheet_name_unique = self.ensure_unique_sheet_name(&sheet_slice.sheet_name);
+                    let worksheet = if self.options_write.should_use_constant_memory
+                        && options.row_groups.is_empty()
+                    {
+                        self.workbook.add_worksheet_with_constant_memory()
+                    } else {
+                        self.workbook.add_worksheet()
+                    };
+                    worksheet
+                        .set_name(&sheet_name_unique)
+                        .map_err(format_xlsx_error_text)?;
+                    (sheet_name_unique, worksheet)
+                }
+            };
 
             let cols_idx_numeric_slice = calculate_slice_indices(
                 &cols_idx_numeric,
@@ -992,6 +1846,16 @@ impl XlsxWriter {
                 sheet_slice.col_start_inclusive,
                 sheet_slice.col_end_exclusive,
             );
+            let cols_idx_percent_slice = calculate_slice_indices(
+                &cols_idx_percent,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+            );
+            let cols_fmt_overrides_slice = calculate_slice_mapped_indices(
+                &cols_idx_fmt_override,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+            );
             let column_format_plan = plan_column_formats(ColumnFormatPlanOptions {
                 width_data: sheet_slice.col_end_exclusive - sheet_slice.col_start_inclusive,
                 cols_idx_numeric: &cols_idx_numeric_slice,
@@ -1001,7 +1865,9 @@ impl XlsxWriter {
                 } else {
                     Some(&cols_idx_decimal_slice)
                 },
-                cols_fmt_overrides: &BTreeMap::new(),
+                cols_idx_percent: &cols_idx_percent_slice,
+                percent_decimals: options.percent_decimals.unwrap_or(PERCENT_DECIMALS_DEFAULT),
+                cols_fmt_overrides: &cols_fmt_overrides_slice,
                 fmt_text: &self.fmt_text,
                 fmt_integer: &self.fmt_integer,
                 fmt_decimal: &self.fmt_decimal,
@@ -1013,10 +1879,12 @@ impl XlsxWriter {
                 .iter()
                 .map(create_rust_xlsx_format)
                 .collect();
-            let fmt_scientific_patch = self
-                .fmt_scientific
-                .merge(&self.options_write.base_format_patch);
-            let fmt_scientific = create_rust_xlsx_format(&fmt_scientific_patch);
+            let fmt_scientific = create_scientific_format(
+                &self.fmt_scientific,
+                &self.options_write,
+                &options.policy_scientific,
+            );
+            let (fmt_date, fmt_datetime) = create_date_formats(&self.fmt_text, &self.options_write);
             let fmt_header = create_rust_xlsx_format(&self.fmt_header);
 
             let header_grid_slice = header_grid
@@ -1059,16 +1927,94 @@ impl XlsxWriter {
             write_header(
                 worksheet,
                 header_grid_slice,
+                options.num_reserved_top_rows,
                 options.should_merge_header,
+                options.should_merge_header_vertical,
                 &fmt_header,
+                col_offset,
             )?;
 
-            worksheet
-                .set_freeze_panes(
-                    cast_row_num(num_frozen_rows)?,
-                    cast_col_num(options.num_frozen_cols)?,
-                )
-                .map_err(format_xlsx_error_text)?;
+            apply_default_layout(
+                worksheet,
+                &self.options_write,
+                sheet_slice.col_end_exclusive - sheet_slice.col_start_inclusive,
+                col_offset,
+            )?;
+            if is_sheet_setup {
+                worksheet
+                    .set_freeze_panes(
+                        cast_row_num(num_frozen_rows)?,
+                        cast_col_num(options.num_frozen_cols)?,
+                    )
+                    .map_err(format_xlsx_error_text)?;
+                apply_sheet_view(worksheet, options, &mut report);
+                apply_page_setup(worksheet, options.page_setup.as_ref());
+                apply_print_layout(
+                    worksheet,
+                    options.page_setup.as_ref(),
+                    options.num_reserved_top_rows,
+                    body_row_start,
+                    &sheet_slice,
+                )?;
+                for _placement in &options.images {
+                    insert_image_placement(worksheet, _placement)?;
+                }
+            }
+            apply_row_groups(
+                worksheet,
+                &row_group_levels,
+                body_row_start,
+                sheet_slice.row_start_inclusive,
+                sheet_slice.row_start_inclusive,
+                sheet_slice.row_end_exclusive,
+            )?;
+            if options.should_autofilter && is_sheet_setup {
+                apply_autofilter(
+                    worksheet,
+                    body_row_start,
+                    sheet_slice.row_end_exclusive - sheet_slice.row_start_inclusive,
+                    sheet_slice.col_end_exclusive - sheet_slice.col_start_inclusive,
+                )?;
+            }
+            if options.should_write_table {
+                add_data_table(
+                    worksheet,
+                    body_row_start,
+                    sheet_slice.row_end_exclusive - sheet_slice.row_start_inclusive,
+                    &header_grid[header_grid.len() - 1]
+                        [sheet_slice.col_start_inclusive..sheet_slice.col_end_exclusive],
+                    &fmt_header,
+                    options.table_style.as_deref(),
+                    col_offset,
+                )?;
+            }
+            apply_conditional_formats(
+                worksheet,
+                &options.conditional_formats,
+                &cols_idx_conditional_format,
+                body_row_start,
+                sheet_slice.row_end_exclusive - sheet_slice.row_start_inclusive,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+                col_offset,
+            )?;
+            apply_banded_rows(
+                worksheet,
+                options,
+                body_row_start,
+                sheet_slice.row_end_exclusive - sheet_slice.row_start_inclusive,
+                sheet_slice.col_end_exclusive - sheet_slice.col_start_inclusive,
+                col_offset,
+            )?;
+            apply_dropdown_validations(
+                worksheet,
+                &cols_idx_dropdown,
+                body_row_start,
+                sheet_slice.row_end_exclusive - sheet_slice.row_start_inclusive,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+                col_offset,
+            )?;
 
             let numeric_cols_idx: BTreeSet<usize> =
                 cols_idx_numeric_slice.iter().copied().collect();
@@ -1097,6 +2043,11 @@ impl XlsxWriter {
             }
             let row_chunks = generate_row_chunks(rows_data_in_sheet, rows_chunk);
 
+            let mut summary_accumulators = create_summary_accumulators(
+                &cols_idx_summary,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+            );
             let mut rows_seen_for_autofit = 0usize;
             for _row_chunk in row_chunks {
                 let (row_chunk_start, row_chunk_len) = _row_chunk;
@@ -1118,14 +2069,30 @@ impl XlsxWriter {
                         let value_raw = convert_any_value_to_cell_value(
                             col.get(row_local)
                                 .map_err(|err| format!("Failed to access cell value: {err}"))?,
-                        );
-                        let value = convert_cell_value(
-                            &value_raw,
-                            is_numeric_col,
-                            is_integer_col,
-                            should_keep_missing_values,
                             &value_policy,
                         );
+                        let value = select_cell_value_override(
+                            &options.rich_text_cells,
+                            &options.formula_cells,
+                            (
+                                sheet_slice.row_start_inclusive + row_local,
+                                sheet_slice.col_start_inclusive + col_idx,
+                            ),
+                        )
+                        .unwrap_or_else(|| {
+                            let value = convert_cell_value(
+                                &value_raw,
+                                is_numeric_col,
+                                is_integer_col,
+                                should_keep_missing_values,
+                                &value_policy,
+                            );
+                            record_cell_conversion(&mut report, &value_raw, &value);
+                            value
+                        });
+                        if let Some(accumulator) = summary_accumulators.get_mut(&col_idx) {
+                            accumulator.update(&value);
+                        }
 
                         if should_autofit_columns
                             && (options.policy_autofit.height_body_inferred_max.is_none()
@@ -1152,19 +2119,50 @@ impl XlsxWriter {
                             is_scientific_candidate,
                             &options.policy_scientific,
                         );
-                        let fmt_cell = if should_use_scientific {
-                            &fmt_scientific
-                        } else {
-                            &data_formats_by_col[col_idx]
+                        let fmt_cell = match value {
+                            CellValue::Date(_) => &fmt_date,
+                            CellValue::DateTime(_) => &fmt_datetime,
+                            _ if should_use_scientific => &fmt_scientific,
+                            _ => &data_formats_by_col[col_idx],
+                        };
+                        if matches!(value, CellValue::Integer(_)) {
+                            count_integer_text += 1;
+                        }
+                        let col_idx_abs = sheet_slice.col_start_inclusive + col_idx;
+                        let row_idx_body = sheet_slice.row_start_inclusive + row_local;
+                        let hyperlink_text = match cols_idx_hyperlink.get(&col_idx_abs) {
+                            Some(Some(col_idx_text)) => read_companion_text(
+                                &body.get_columns()[*col_idx_text],
+                                row_idx_body,
+                                &value_policy,
+                            )?,
+                            _ => None,
                         };
 
-                        write_cell_with_format(
+                        write_body_cell_with_format(
                             worksheet,
-                            header_row_count + row_local,
-                            col_idx,
+                            body_row_start + row_local,
+                            col_offset + col_idx,
                             &value,
                             fmt_cell,
+                            cols_idx_hyperlink.contains_key(&col_idx_abs),
+                            hyperlink_text,
+                            &mut report,
                         )?;
+                        if let Some(col_idx_comment) = cols_idx_comment_text.get(&col_idx_abs)
+                            && let Some(comment_text) = read_companion_text(
+                                &body.get_columns()[*col_idx_comment],
+                                row_idx_body,
+                                &value_policy,
+                            )?
+                        {
+                            insert_cell_comment(
+                                worksheet,
+                                body_row_start + row_local,
+                                col_offset + col_idx,
+                                &comment_text,
+                            )?;
+                        }
                     }
 
                     if should_autofit_columns
@@ -1177,6 +2175,33 @@ impl XlsxWriter {
                 }
             }
 
+            write_summary_row(
+                worksheet,
+                &summary_accumulators,
+                body_row_start,
+                rows_data_in_sheet,
+                &data_formats_by_col,
+                options.should_use_summary_formulas,
+                col_offset,
+            )?;
+
+            let cols_idx_hidden_slice = calculate_slice_indices(
+                &cols_idx_hidden,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+            );
+            if let Some(target) = shared.as_deref_mut() {
+                merge_shared_widths(
+                    &mut target.header_widths_by_col,
+                    col_offset,
+                    &mut header_widths_by_col,
+                );
+                merge_shared_widths(
+                    &mut target.body_widths_by_col,
+                    col_offset,
+                    &mut body_widths_by_col,
+                );
+            }
             if should_autofit_columns && !data_formats_by_col.is_empty() {
                 let width_min = usize::max(1, options.policy_autofit.width_cell_min);
                 let width_max = usize::min(
@@ -1187,6 +2212,9 @@ impl XlsxWriter {
 
                 for _col_idx in 0..data_formats_by_col.len() {
                     let col_idx = _col_idx;
+                    if cols_idx_hidden_slice.contains(&col_idx) {
+                        continue;
+                    }
                     let width_recorded = match options.policy_autofit.mode {
                         AutofitMode::Header => header_widths_by_col[col_idx],
                         AutofitMode::Body => body_widths_by_col[col_idx],
@@ -1200,10 +2228,18 @@ impl XlsxWriter {
                         usize::max(width_min, width_recorded + width_padding),
                     );
                     worksheet
-                        .set_column_width(cast_col_num(col_idx)?, width_final as f64)
+                        .set_column_width(cast_col_num(col_offset + col_idx)?, width_final as f64)
                         .map_err(format_xlsx_error_text)?;
                 }
             }
+            apply_explicit_column_widths(
+                worksheet,
+                &cols_idx_width,
+                sheet_slice.col_start_inclusive,
+                sheet_slice.col_end_exclusive,
+                col_offset,
+            )?;
+            apply_hidden_columns(worksheet, &cols_idx_hidden_slice, col_offset)?;
 
             report.sheets.push(SheetSlice {
                 sheet_name: sheet_name_unique,
@@ -1214,6 +2250,7 @@ impl XlsxWriter {
             });
         }
 
+        warn_integer_text_cells(&mut report, count_integer_text);
         self.reports.push(report);
         Ok(())
     }
@@ -1267,6 +2304,15 @@ impl<'a> XlsxSheetPlanBuilder<'a> {
             cols_idx_numeric: vec![],
             cols_idx_integer: vec![],
             cols_idx_decimal_specified: vec![],
+            cols_idx_percent: vec![],
+            cols_idx_hyperlink: BTreeMap::new(),
+            cols_idx_comment_text: BTreeMap::new(),
+            cols_idx_conditional_format: vec![],
+            cols_idx_dropdown: BTreeMap::new(),
+            cols_idx_summary: BTreeMap::new(),
+            cols_idx_width: BTreeMap::new(),
+            cols_idx_fmt_override: BTreeMap::new(),
+            cols_idx_hidden: vec![],
             header_widths_by_col: vec![],
             body_widths_by_col: vec![],
             rows_seen_for_autofit: 0,
@@ -1341,6 +2387,29 @@ impl<'a> XlsxSheetPlanBuilder<'a> {
             select_sorted_indices_from_refs(&col_names_ref, self.options.cols_integer.as_deref())?;
         self.cols_idx_decimal_specified =
             select_sorted_indices_from_refs(&col_names_ref, self.options.cols_decimal.as_deref())?;
+        self.cols_idx_percent =
+            select_sorted_indices_from_refs(&col_names_ref, self.options.cols_percent.as_deref())?;
+        self.cols_idx_hyperlink = select_hyperlink_column_indices(
+            &col_names_ref,
+            self.options.cols_hyperlink.as_deref(),
+            self.options.cols_hyperlink_text.as_ref(),
+        )?;
+        self.cols_idx_comment_text =
+            select_column_index_pairs(&col_names_ref, self.options.cols_comment_text.as_ref())?;
+        self.cols_idx_conditional_format = select_conditional_format_column_indices(
+            &col_names_ref,
+            &self.options.conditional_formats,
+        )?;
+        self.cols_idx_dropdown =
+            select_mapped_column_indices(&col_names_ref, self.options.cols_dropdown.as_ref())?;
+        self.cols_idx_summary =
+            select_mapped_column_indices(&col_names_ref, self.options.summary_row.as_ref())?;
+        self.cols_idx_width =
+            select_mapped_column_indices(&col_names_ref, self.options.cols_width.as_ref())?;
+        self.cols_idx_fmt_override =
+            select_mapped_column_indices(&col_names_ref, self.options.cols_fmt_overrides.as_ref())?;
+        self.cols_idx_hidden =
+            select_sorted_indices_from_refs(&col_names_ref, self.options.cols_hidden.as_deref())?;
 
         let rows_chunk =
             calculate_row_chunk_size(self.width_body, &self.options_write.row_chunk_policy);
@@ -1353,6 +2422,17 @@ impl<'a> XlsxSheetPlanBuilder<'a> {
         } else {
             cols_idx_integer_specified
         };
+        apply_currency_columns(
+            &select_sorted_indices_from_refs(
+                &col_names_ref,
+                self.options.cols_currency.as_deref(),
+            )?,
+            self.options,
+            &mut self.cols_idx_numeric,
+            &mut self.cols_idx_integer,
+            &mut self.cols_idx_decimal_specified,
+            &mut self.cols_idx_fmt_override,
+        );
 
         self.header_widths_by_col = vec![0usize; self.width_body];
         self.body_widths_by_col = vec![0usize; self.width_body];
@@ -1421,6 +2501,7 @@ impl<'a> XlsxSheetPlanBuilder<'a> {
                 let value_raw = convert_any_value_to_cell_value(
                     col.get(row_local)
                         .map_err(|err| format!("Failed to access cell value: {err}"))?,
+                    &self.value_policy,
                 );
                 let value = convert_cell_value(
                     &value_raw,
@@ -1454,16 +2535,15 @@ impl<'a> XlsxSheetPlanBuilder<'a> {
         let header_grid = self
             .header_grid_custom
             .ok_or_else(|| "Missing resolved header grid.".to_string())?;
-        let header_row_count = header_grid.len();
-        let mut report = XlsxReport {
-            sheets: vec![],
-            warnings: vec![],
-        };
+        let body_row_start = self.options.num_reserved_top_rows + header_grid.len();
+        let mut report = XlsxReport::default();
         let sheet_slices = plan_sheet_slices(
             self.height_body,
             self.width_body,
-            header_row_count,
+            body_row_start + calculate_summary_row_height(self.options),
             &sanitize_sheet_name(self.sheet_name, "_"),
+            self.options_write.split_mode,
+            self.options_write.sheet_split_template.as_deref(),
             &mut report,
         )?;
 
@@ -1471,13 +2551,23 @@ impl<'a> XlsxSheetPlanBuilder<'a> {
             col_names,
             height_body: self.height_body,
             header_grid,
+            body_row_start,
             cols_idx_numeric: self.cols_idx_numeric,
             cols_idx_integer: self.cols_idx_integer,
             cols_idx_decimal_specified: self.cols_idx_decimal_specified,
+            cols_idx_percent: self.cols_idx_percent,
+            cols_idx_hyperlink: self.cols_idx_hyperlink,
+            cols_idx_comment_text: self.cols_idx_comment_text,
+            cols_idx_conditional_format: self.cols_idx_conditional_format,
+            cols_idx_dropdown: self.cols_idx_dropdown,
+            cols_idx_summary: self.cols_idx_summary,
+            cols_idx_width: self.cols_idx_width,
+            cols_idx_fmt_override: self.cols_idx_fmt_override,
+            cols_idx_hidden: self.cols_idx_hidden,
             header_widths_by_col: self.header_widths_by_col,
             body_widths_by_col: self.body_widths_by_col,
             sheet_slices,
-            num_frozen_rows: self.options.num_frozen_rows.unwrap_or(header_row_count),
+            num_frozen_rows: self.options.num_frozen_rows.unwrap_or(body_row_start),
             should_keep_missing_values: self.should_keep_missing_values,
         })
     }
@@ -1496,6 +2586,7 @@ fn apply_column_widths(
     policy_autofit: &AutofitPolicy,
     header_widths_by_col: &[usize],
     body_widths_by_col: &[usize],
+    cols_idx_hidden: &[usize],
 ) -> Result<(), String> {
     if matches!(policy_autofit.mode, AutofitMode::None) || header_widths_by_col.is_empty() {
         return Ok(());
@@ -1506,6 +2597,9 @@ fn apply_column_widths(
     let width_padding = policy_autofit.width_cell_padding;
 
     for col_idx in 0..header_widths_by_col.len() {
+        if cols_idx_hidden.contains(&col_idx) {
+            continue;
+        }
         let width_recorded = match policy_autofit.mode {
             AutofitMode::Header => header_widths_by_col[col_idx],
             AutofitMode::Body => body_widths_by_col[col_idx],
@@ -1525,17 +2619,382 @@ fn apply_column_widths(
     Ok(())
 }
 
+/// Set explicit widths for mapped columns inside `col_start..col_end`, after autofit.
+fn apply_explicit_column_widths(
+    worksheet: &mut Worksheet,
+    cols_idx_width: &BTreeMap<usize, f64>,
+    col_start: usize,
+    col_end: usize,
+    col_offset: usize,
+) -> Result<(), String> {
+    for (&_col_idx_abs, &_width) in cols_idx_width.range(col_start..col_end) {
+        worksheet
+            .set_column_width(cast_col_num(col_offset + _col_idx_abs - col_start)?, _width)
+            .map_err(format_xlsx_error_text)?;
+    }
+    Ok(())
+}
+
+/// Set outline levels for grouped body rows in `row_start..row_end` of one sheet part.
+///
+/// Single-pass callers pass each batch's row range so only written rows are grouped.
+fn apply_row_groups(
+    worksheet: &mut Worksheet,
+    row_group_levels: &BTreeMap<usize, u8>,
+    body_row_start: usize,
+    row_part_start: usize,
+    row_start: usize,
+    row_end: usize,
+) -> Result<(), String> {
+    if row_start >= row_end {
+        return Ok(());
+    }
+    for (&_row_idx, &_level) in row_group_levels.range(row_start..row_end) {
+        let row_num = cast_row_num(body_row_start + _row_idx - row_part_start)?;
+        for _ in 0.._level {
+            worksheet
+                .group_rows(row_num, row_num)
+                .map_err(format_xlsx_error_text)?;
+        }
+    }
+    Ok(())
+}
+
+/// Apply zoom, gridline visibility, and tab color to one sheet part.
+fn apply_sheet_view(
+    worksheet: &mut Worksheet,
+    options: &XlsxSheetWriteOptions,
+    report: &mut XlsxReport,
+) {
+    if let Some(zoom) = options.zoom {
+        worksheet.set_zoom(zoom);
+    }
+    if options.should_hide_gridlines {
+        worksheet.set_screen_gridlines(false);
+    }
+    if let Some(tab_color) = options.tab_color.as_deref() {
+        if is_rgb_hex_color(tab_color) {
+            worksheet.set_tab_color(tab_color);
+        } else {
+            report.warn(format!(
+                "Invalid tab_color {tab_color:?} for sheet {:?}; expected RGB hex like \"#FF0000\". Ignored.",
+                worksheet.name()
+            ));
+        }
+    }
+}
+
+/// Apply the writer-level default column width and row height to one sheet part.
+///
+/// Called before autofit and explicit widths so those still win per column.
+fn apply_default_layout(
+    worksheet: &mut Worksheet,
+    options_write: &XlsxWriteOptions,
+    width_data: usize,
+    col_offset: usize,
+) -> Result<(), String> {
+    if let Some(height) = options_write.default_row_height {
+        worksheet.set_default_row_height(height);
+    }
+    if let Some(width) = options_write.default_col_width
+        && width_data > 0
+    {
+        worksheet
+            .set_column_range_width(
+                cast_col_num(col_offset)?,
+                cast_col_num(col_offset + width_data - 1)?,
+                width,
+            )
+            .map_err(format_xlsx_error_text)?;
+    }
+    Ok(())
+}
+
+/// Apply print orientation, paper size, and margins to one sheet part.
+fn apply_page_setup(worksheet: &mut Worksheet, page_setup: Option<&PageSetup>) {
+    let Some(page_setup) = page_setup else {
+        return;
+    };
+    if page_setup.should_use_landscape {
+        worksheet.set_landscape();
+    } else {
+        worksheet.set_portrait();
+    }
+    if let Some(paper_size) = page_setup.paper_size {
+        worksheet.set_paper_size(paper_size);
+    }
+    if let Some((left, right, top, bottom)) = page_setup.margins {
+        // Negative header/footer margins keep Excel defaults.
+        worksheet.set_margins(left, right, top, bottom, -1.0, -1.0);
+    }
+    if let Some(header_text) = &page_setup.header_text {
+        worksheet.set_header(header_text);
+    }
+    if let Some(footer_text) = &page_setup.footer_text {
+        worksheet.set_footer(footer_text);
+    }
+}
+
+/// Set the print area and repeated header rows for one sheet part.
+///
+/// The body-relative print area is clipped to `sheet_slice` and skipped when they do not overlap.
+fn apply_print_layout(
+    worksheet: &mut Worksheet,
+    page_setup: Option<&PageSetup>,
+    header_row_start: usize,
+    body_row_start: usize,
+    sheet_slice: &SheetSlice,
+) -> Result<(), String> {
+    let Some(page_setup) = page_setup else {
+        return Ok(());
+    };
+    if page_setup.should_repeat_header_on_print && body_row_start > header_row_start {
+        worksheet
+            .set_repeat_rows(
+                cast_row_num(header_row_start)?,
+                cast_row_num(body_row_start - 1)?,
+            )
+            .map_err(format_xlsx_error_text)?;
+    }
+    if let Some((row_first, col_first, row_last, col_last)) = page_setup.print_area {
+        let row_start = usize::max(row_first, sheet_slice.row_start_inclusive);
+        let row_end = usize::min(row_last + 1, sheet_slice.row_end_exclusive);
+        let col_start = usize::max(col_first, sheet_slice.col_start_inclusive);
+        let col_end = usize::min(col_last + 1, sheet_slice.col_end_exclusive);
+        if row_start < row_end && col_start < col_end {
+            worksheet
+                .set_print_area(
+                    cast_row_num(body_row_start + row_start - sheet_slice.row_start_inclusive)?,
+                    cast_col_num(col_start - sheet_slice.col_start_inclusive)?,
+                    cast_row_num(body_row_start + row_end - 1 - sheet_slice.row_start_inclusive)?,
+                    cast_col_num(col_end - 1 - sheet_slice.col_start_inclusive)?,
+                )
+                .map_err(format_xlsx_error_text)?;
+        }
+    }
+    Ok(())
+}
+
+/// Hide slice-local columns; their cells are still written.
+fn apply_hidden_columns(
+    worksheet: &mut Worksheet,
+    cols_idx_hidden: &[usize],
+    col_offset: usize,
+) -> Result<(), String> {
+    for &_col_idx in cols_idx_hidden {
+        worksheet
+            .set_column_hidden(cast_col_num(col_offset + _col_idx)?)
+            .map_err(format_xlsx_error_text)?;
+    }
+    Ok(())
+}
+
+/// Wrap the last header row and the body rows in a native Excel table.
+fn add_data_table(
+    worksheet: &mut Worksheet,
+    body_row_start: usize,
+    height_data: usize,
+    header_names: &[String],
+    fmt_header: &Format,
+    table_style: Option<&str>,
+    col_offset: usize,
+) -> Result<(), String> {
+    if body_row_start == 0 || header_names.is_empty() {
+        return Ok(());
+    }
+    let columns = header_names
+        .iter()
+        .map(|_name| {
+            TableColumn::new()
+                .set_header(_name)
+                .set_header_format(fmt_header)
+        })
+        .collect::<Vec<_>>();
+    let mut table = Table::new().set_columns(&columns);
+    if let Some(style_name) = table_style {
+        table = table.set_style(parse_table_style(style_name)?);
+    }
+
+    // Excel tables need at least one body row, so empty bodies keep a blank one.
+    let row_header_last = body_row_start - 1;
+    worksheet
+        .add_table(
+            cast_row_num(row_header_last)?,
+            cast_col_num(col_offset)?,
+            cast_row_num(row_header_last + usize::max(height_data, 1))?,
+            cast_col_num(col_offset + header_names.len() - 1)?,
+            &table,
+        )
+        .map(|_| ())
+        .map_err(format_xlsx_error_text)
+}
+
+/// Add conditional formats for rule columns inside the current sheet part.
+#[allow(clippy::too_many_arguments)]
+fn apply_conditional_formats(
+    worksheet: &mut Worksheet,
+    rules: &[ConditionalFormatRule],
+    cols_idx_rule: &[usize],
+    body_row_start: usize,
+    height_data: usize,
+    col_start: usize,
+    col_end: usize,
+    col_offset: usize,
+) -> Result<(), String> {
+    if height_data == 0 {
+        return Ok(());
+    }
+    let row_first = cast_row_num(body_row_start)?;
+    let row_last = cast_row_num(body_row_start + height_data - 1)?;
+    for (_rule, &_col_idx_abs) in rules.iter().zip(cols_idx_rule) {
+        if !(col_start..col_end).contains(&_col_idx_abs) {
+            continue;
+        }
+        let col_idx = cast_col_num(col_offset + _col_idx_abs - col_start)?;
+        match _rule.kind {
+            ConditionalFormatKind::ColorScale3 => worksheet.add_conditional_format(
+                row_first,
+                col_idx,
+                row_last,
+                col_idx,
+                &ConditionalFormat3ColorScale::new()
+                    .set_minimum_color(_rule.color_min.as_str())
+                    .set_midpoint_color(_rule.color_mid.as_str())
+                    .set_maximum_color(_rule.color_max.as_str()),
+            ),
+            ConditionalFormatKind::DataBar => worksheet.add_conditional_format(
+                row_first,
+                col_idx,
+                row_last,
+                col_idx,
+                &ConditionalFormatDataBar::new().set_fill_color(_rule.color_bar.as_str()),
+            ),
+        }
+        .map_err(format_xlsx_error_text)?;
+    }
+    Ok(())
+}
+
+/// Shade every second body row of one sheet part with a formula conditional format.
+///
+/// Unlike per-cell format variants, this also works for rows already streamed to disk.
+fn apply_banded_rows(
+    worksheet: &mut Worksheet,
+    options: &XlsxSheetWriteOptions,
+    body_row_start: usize,
+    height_data: usize,
+    width_data: usize,
+    col_offset: usize,
+) -> Result<(), String> {
+    if !options.should_band_rows || height_data < 2 || width_data == 0 {
+        return Ok(());
+    }
+    let band_color = options.band_color.as_deref().unwrap_or(BAND_COLOR_DEFAULT);
+    worksheet
+        .add_conditional_format(
+            cast_row_num(body_row_start)?,
+            cast_col_num(col_offset)?,
+            cast_row_num(body_row_start + height_data - 1)?,
+            cast_col_num(col_offset + width_data - 1)?,
+            &ConditionalFormatFormula::new()
+                .set_rule(format!("=MOD(ROW()-{body_row_start},2)=0").as_str())
+                .set_format(Format::new().set_background_color(band_color)),
+        )
+        .map_err(format_xlsx_error_text)?;
+    Ok(())
+}
+
+/// Add list validations for dropdown columns inside the current sheet part.
+fn apply_dropdown_validations(
+    worksheet: &mut Worksheet,
+    cols_idx_dropdown: &BTreeMap<usize, Vec<String>>,
+    body_row_start: usize,
+    height_data: usize,
+    col_start: usize,
+    col_end: usize,
+    col_offset: usize,
+) -> Result<(), String> {
+    if height_data == 0 {
+        return Ok(());
+    }
+    let row_first = cast_row_num(body_row_start)?;
+    let row_last = cast_row_num(body_row_start + height_data - 1)?;
+    for (&_col_idx_abs, _values) in cols_idx_dropdown.range(col_start..col_end) {
+        let col_idx = cast_col_num(col_offset + _col_idx_abs - col_start)?;
+        worksheet
+            .add_data_validation(
+                row_first,
+                col_idx,
+                row_last,
+                col_idx,
+                &create_dropdown_validation(_values)?,
+            )
+            .map_err(format_xlsx_error_text)?;
+    }
+    Ok(())
+}
+
+fn create_dropdown_validation(values: &[String]) -> Result<DataValidation, String> {
+    DataValidation::new()
+        .allow_list_strings(values)
+        .map_err(format_xlsx_error_text)
+}
+
+fn insert_image_placement(
+    worksheet: &mut Worksheet,
+    placement: &ImagePlacement,
+) -> Result<(), String> {
+    let image = match &placement.source {
+        ImageSource::Path(path) => Image::new(path),
+        ImageSource::Bytes(bytes) => Image::new_from_buffer(bytes),
+    }
+    .map_err(format_xlsx_error_text)?;
+    worksheet
+        .insert_image_with_offset(
+            cast_row_num(placement.row)?,
+            cast_col_num(placement.col)?,
+            &image,
+            placement.x_offset,
+            placement.y_offset,
+        )
+        .map(|_| ())
+        .map_err(format_xlsx_error_text)
+}
+
+/// Apply an auto-filter from the last header row through the last body row.
+fn apply_autofilter(
+    worksheet: &mut Worksheet,
+    body_row_start: usize,
+    height_data: usize,
+    width_data: usize,
+) -> Result<(), String> {
+    if body_row_start == 0 || width_data == 0 {
+        return Ok(());
+    }
+    let row_header_last = body_row_start - 1;
+    worksheet
+        .autofilter(
+            cast_row_num(row_header_last)?,
+            0,
+            cast_row_num(row_header_last + height_data)?,
+            cast_col_num(width_data - 1)?,
+        )
+        .map(|_| ())
+        .map_err(format_xlsx_error_text)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn write_record_batch_to_runtime_sheet(
     workbook: &mut Workbook,
-    runtime: &XlsxSheetRuntime,
+    runtime: &mut XlsxSheetRuntime,
     df_batch: &DataFrame,
     row_offset: usize,
-    header_row_count: usize,
+    body_row_start: usize,
     should_keep_missing_values: bool,
     value_policy: &XlsxValuePolicy,
     policy_scientific: &ScientificPolicy,
-) -> Result<(), String> {
+    report: &mut XlsxReport,
+) -> Result<usize, String> {
     let batch_start = row_offset;
     let batch_end = row_offset + df_batch.height();
     let sheet_start = runtime.sheet_slice.row_start_inclusive;
@@ -1543,12 +3002,13 @@ fn write_record_batch_to_runtime_sheet(
     let overlap_start = usize::max(batch_start, sheet_start);
     let overlap_end = usize::min(batch_end, sheet_end);
     if overlap_start >= overlap_end {
-        return Ok(());
+        return Ok(0);
     }
 
     let worksheet = workbook
         .worksheet_from_index(runtime.worksheet_index)
         .map_err(format_xlsx_error_text)?;
+    let mut count_integer_text = 0usize;
 
     for row_abs in overlap_start..overlap_end {
         let row_local_in_batch = row_abs - batch_start;
@@ -1570,49 +3030,92 @@ fn write_record_batch_to_runtime_sheet(
             let value_raw = convert_any_value_to_cell_value(
                 col.get(row_local_in_batch)
                     .map_err(|err| format!("Failed to access cell value: {err}"))?,
-            );
-            let value = convert_cell_value(
-                &value_raw,
-                is_numeric_col,
-                is_integer_col,
-                should_keep_missing_values,
                 value_policy,
             );
+            let value = select_cell_value_override(
+                &runtime.rich_text_cells,
+                &runtime.formula_cells,
+                (row_abs, col_abs),
+            )
+            .unwrap_or_else(|| {
+                let value = convert_cell_value(
+                    &value_raw,
+                    is_numeric_col,
+                    is_integer_col,
+                    should_keep_missing_values,
+                    value_policy,
+                );
+                record_cell_conversion(report, &value_raw, &value);
+                value
+            });
+            if let Some(accumulator) = runtime.summary_accumulators.get_mut(&col_idx) {
+                accumulator.update(&value);
+            }
             let should_use_scientific = should_use_scientific_value(
                 &value,
                 is_numeric_col,
                 is_scientific_candidate,
                 policy_scientific,
             );
-            let fmt_cell = if should_use_scientific {
-                &runtime.fmt_scientific
-            } else {
-                &runtime.data_formats_by_col[col_idx]
+            let fmt_cell = match value {
+                CellValue::Date(_) => &runtime.fmt_date,
+                CellValue::DateTime(_) => &runtime.fmt_datetime,
+                _ if should_use_scientific => &runtime.fmt_scientific,
+                _ => &runtime.data_formats_by_col[col_idx],
+            };
+            if matches!(value, CellValue::Integer(_)) {
+                count_integer_text += 1;
+            }
+            let hyperlink_text = match runtime.cols_idx_hyperlink.get(&col_abs) {
+                Some(Some(col_idx_text)) => read_companion_text(
+                    &df_batch.get_columns()[*col_idx_text],
+                    row_local_in_batch,
+                    value_policy,
+                )?,
+                _ => None,
             };
-            write_cell_with_format(
+            write_body_cell_with_format(
                 worksheet,
-                header_row_count + row_local_in_sheet,
+                body_row_start + row_local_in_sheet,
                 col_idx,
                 &value,
                 fmt_cell,
+                runtime.cols_idx_hyperlink.contains_key(&col_abs),
+                hyperlink_text,
+                report,
             )?;
+            if let Some(col_idx_comment) = runtime.cols_idx_comment_text.get(&col_abs)
+                && let Some(comment_text) = read_companion_text(
+                    &df_batch.get_columns()[*col_idx_comment],
+                    row_local_in_batch,
+                    value_policy,
+                )?
+            {
+                insert_cell_comment(
+                    worksheet,
+                    body_row_start + row_local_in_sheet,
+                    col_idx,
+                    &comment_text,
+                )?;
+            }
         }
     }
 
-    Ok(())
+    Ok(count_integer_text)
 }
 
 #[allow(clippy::too_many_arguments)]
 fn write_arrow_record_batch_to_runtime_sheet(
     workbook: &mut Workbook,
-    runtime: &XlsxSheetRuntime,
+    runtime: &mut XlsxSheetRuntime,
     batch: &XlsxRecordBatch,
     row_offset: usize,
-    header_row_count: usize,
+    body_row_start: usize,
     should_keep_missing_values: bool,
     value_policy: &XlsxValuePolicy,
     policy_scientific: &ScientificPolicy,
-) -> Result<(), String> {
+    report: &mut XlsxReport,
+) -> Result<usize, String> {
     let batch_start = row_offset;
     let batch_end = row_offset + batch.len();
     let sheet_start = runtime.sheet_slice.row_start_inclusive;
@@ -1620,12 +3123,13 @@ fn write_arrow_record_batch_to_runtime_sheet(
     let overlap_start = usize::max(batch_start, sheet_start);
     let overlap_end = usize::min(batch_end, sheet_end);
     if overlap_start >= overlap_end {
-        return Ok(());
+        return Ok(0);
     }
 
     let worksheet = workbook
         .worksheet_from_index(runtime.worksheet_index)
         .map_err(format_xlsx_error_text)?;
+    let mut count_integer_text = 0usize;
 
     for row_abs in overlap_start..overlap_end {
         let row_local_in_batch = row_abs - batch_start;
@@ -1644,36 +3148,81 @@ fn write_arrow_record_batch_to_runtime_sheet(
                 runtime.is_decimal_explicit,
                 is_decimal_specified,
             );
-            let value_raw = convert_arrow_value_to_cell_value(col.as_ref(), row_local_in_batch)?;
-            let value = convert_cell_value(
-                &value_raw,
-                is_numeric_col,
-                is_integer_col,
-                should_keep_missing_values,
-                value_policy,
-            );
+            let value_raw =
+                convert_arrow_value_to_cell_value(col.as_ref(), row_local_in_batch, value_policy)?;
+            let value = select_cell_value_override(
+                &runtime.rich_text_cells,
+                &runtime.formula_cells,
+                (row_abs, col_abs),
+            )
+            .unwrap_or_else(|| {
+                let value = convert_cell_value(
+                    &value_raw,
+                    is_numeric_col,
+                    is_integer_col,
+                    should_keep_missing_values,
+                    value_policy,
+                );
+                record_cell_conversion(report, &value_raw, &value);
+                value
+            });
+            if let Some(accumulator) = runtime.summary_accumulators.get_mut(&col_idx) {
+                accumulator.update(&value);
+            }
             let should_use_scientific = should_use_scientific_value(
                 &value,
                 is_numeric_col,
                 is_scientific_candidate,
                 policy_scientific,
             );
-            let fmt_cell = if should_use_scientific {
-                &runtime.fmt_scientific
-            } else {
-                &runtime.data_formats_by_col[col_idx]
+            let fmt_cell = match value {
+                CellValue::Date(_) => &runtime.fmt_date,
+                CellValue::DateTime(_) => &runtime.fmt_datetime,
+                _ if should_use_scientific => &runtime.fmt_scientific,
+                _ => &runtime.data_formats_by_col[col_idx],
             };
-            write_cell_with_format(
+            if matches!(value, CellValue::Integer(_)) {
+                count_integer_text += 1;
+            }
+            let hyperlink_text = match runtime.cols_idx_hyperlink.get(&col_abs) {
+                Some(Some(col_idx_text)) => {
+                    derive_companion_text(convert_arrow_value_to_cell_value(
+                        batch.arrays()[*col_idx_text].as_ref(),
+                        row_local_in_batch,
+                        value_policy,
+                    )?)
+                }
+                _ => None,
+            };
+            write_body_cell_with_format(
                 worksheet,
-                header_row_count + row_local_in_sheet,
+                body_row_start + row_local_in_sheet,
                 col_idx,
                 &value,
                 fmt_cell,
+                runtime.cols_idx_hyperlink.contains_key(&col_abs),
+                hyperlink_text,
+                report,
             )?;
+            if let Some(col_idx_comment) = runtime.cols_idx_comment_text.get(&col_abs)
+                && let Some(comment_text) =
+                    derive_companion_text(convert_arrow_value_to_cell_value(
+                        batch.arrays()[*col_idx_comment].as_ref(),
+                        row_local_in_batch,
+                        value_policy,
+                    )?)
+            {
+                insert_cell_comment(
+                    worksheet,
+                    body_row_start + row_local_in_sheet,
+                    col_idx,
+                    &comment_text,
+                )?;
+            }
         }
     }
 
-    Ok(())
+    Ok(count_integer_text)
 }
 
 /// Estimate displayed width units for one normalized cell value.
@@ -1718,13 +3267,30 @@ fn estimate_width_len(
                 is_scientific_candidate,
                 policy_scientific,
             ) {
-                return format!("{n:.2E}").len();
+                return format!("{n:.prec$E}", prec = policy_scientific.precision).len();
             }
             if is_integer_col {
                 return (*n as i64).to_string().len();
             }
             format!("{n:.4}").len()
         }
+        CellValue::Integer(n) => n.to_string().len(),
+        CellValue::Boolean(val) => {
+            if *val {
+                4
+            } else {
+                5
+            }
+        }
+        // Rendered width of the default `yyyy-mm-dd` / `yyyy-mm-dd hh:mm:ss` formats.
+        CellValue::Date(_) => 10,
+        CellValue::DateTime(_) => 19,
+        CellValue::RichText(runs) => runs
+            .iter()
+            .map(|(_, _text)| estimate_unicode_string_width(_text))
+            .sum(),
+        // Formula results are computed by Excel, so they don't widen the column.
+        CellValue::Formula(_) => 0,
     }
 }
 
@@ -1734,6 +3300,48 @@ fn estimate_unicode_string_width(s: &str) -> usize {
     ascii_count + (non_ascii_count as f64 * 1.6).round() as usize
 }
 
+/// Resolve currency columns as decimal numerics carrying the currency `num_format`.
+///
+/// The currency format sits under any `cols_fmt_overrides` number format.
+fn apply_currency_columns(
+    cols_idx_currency: &[usize],
+    options: &XlsxSheetWriteOptions,
+    cols_idx_numeric: &mut Vec<usize>,
+    cols_idx_integer: &mut Vec<usize>,
+    cols_idx_decimal_specified: &mut Vec<usize>,
+    cols_idx_fmt_override: &mut BTreeMap<usize, CellFormatPatch>,
+) {
+    if cols_idx_currency.is_empty() {
+        return;
+    }
+    let num_format = derive_currency_format(
+        options
+            .currency_symbol
+            .as_deref()
+            .unwrap_or(CURRENCY_SYMBOL_DEFAULT),
+        options
+            .currency_decimals
+            .unwrap_or(CURRENCY_DECIMALS_DEFAULT),
+    );
+    let is_decimal_explicit = !cols_idx_decimal_specified.is_empty();
+    for &_col_idx in cols_idx_currency {
+        cols_idx_numeric.push(_col_idx);
+        cols_idx_integer.retain(|&idx| idx != _col_idx);
+        if is_decimal_explicit {
+            cols_idx_decimal_specified.push(_col_idx);
+        }
+        cols_idx_fmt_override
+            .entry(_col_idx)
+            .or_default()
+            .num_format
+            .get_or_insert_with(|| num_format.clone());
+    }
+    for cols_idx in [cols_idx_numeric, cols_idx_decimal_specified] {
+        cols_idx.sort_unstable();
+        cols_idx.dedup();
+    }
+}
+
 /// Build per-column base/final format plans for current sheet slice.
 fn plan_column_formats(options: ColumnFormatPlanOptions<'_>) -> ColumnFormatPlan {
     let ColumnFormatPlanOptions {
@@ -1741,6 +3349,8 @@ fn plan_column_formats(options: ColumnFormatPlanOptions<'_>) -> ColumnFormatPlan
         cols_idx_numeric,
         cols_idx_integer,
         cols_idx_decimal,
+        cols_idx_percent,
+        percent_decimals,
         cols_fmt_overrides,
         fmt_text,
         fmt_integer,
@@ -1752,13 +3362,31 @@ fn plan_column_formats(options: ColumnFormatPlanOptions<'_>) -> ColumnFormatPlan
     let integer_cols_idx: BTreeSet<usize> = cols_idx_integer.iter().copied().collect();
     let decimal_cols_idx: Option<BTreeSet<usize>> =
         cols_idx_decimal.map(|vals| vals.iter().copied().collect());
+    let percent_cols_idx: BTreeSet<usize> = cols_idx_percent.iter().copied().collect();
     let mut fmts_base_by_col = Vec::with_capacity(width_data);
     let mut fmts_by_col = Vec::with_capacity(width_data);
 
     for _col_idx in 0..width_data {
         let col_idx = _col_idx;
-        let mut fmt_base = if integer_cols_idx.contains(&col_idx) {
-            fmt_integer.clone()
+        let mut fmt_base = if percent_cols_idx.contains(&col_idx) {
+            CellFormatPatch {
+                num_format: Some(create_percent_num_format(percent_decimals)),
+                ..fmt_decimal.clone()
+            }
+        } else if integer_cols_idx.contains(&col_idx) {
+            if options_write.should_use_integer_thousands_sep
+                && fmt_integer
+                    .num_format
+                    .as_deref()
+                    .is_none_or(|fmt| fmt == "0")
+            {
+                CellFormatPatch {
+                    num_format: Some(NUM_FORMAT_INTEGER_THOUSANDS_SEP.to_string()),
+                    ..fmt_integer.clone()
+                }
+            } else {
+                fmt_integer.clone()
+            }
         } else if decimal_cols_idx
             .as_ref()
             .map_or(numeric_cols_idx.contains(&col_idx), |indices| {
@@ -1806,6 +3434,255 @@ fn validate_policy_autofit(policy_autofit: &AutofitPolicy) -> Result<(), String>
     Ok(())
 }
 
+fn is_rgb_hex_color(text: &str) -> bool {
+    let hex = text.strip_prefix('#').unwrap_or(text);
+    hex.len() == 6 && hex.chars().all(|_chr| _chr.is_ascii_hexdigit())
+}
+
+fn validate_conditional_formats(rules: &[ConditionalFormatRule]) -> Result<(), String> {
+    for (_idx, _rule) in rules.iter().enumerate() {
+        let colors = match _rule.kind {
+            ConditionalFormatKind::ColorScale3 => vec![
+                ("color_min", &_rule.color_min),
+                ("color_mid", &_rule.color_mid),
+                ("color_max", &_rule.color_max),
+            ],
+            ConditionalFormatKind::DataBar => vec![("color_bar", &_rule.color_bar)],
+        };
+        for (_field, _color) in colors {
+            if !is_rgb_hex_color(_color) {
+                return Err(format!(
+                    "conditional_formats[{_idx}].{_field} must be an RGB hex color like \"#FF0000\", got {_color:?}."
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_band_color(options: &XlsxSheetWriteOptions) -> Result<(), String> {
+    if let Some(band_color) = options.band_color.as_deref()
+        && !is_rgb_hex_color(band_color)
+    {
+        return Err(format!(
+            "band_color must be an RGB hex color like \"#F2F2F2\", got {band_color:?}."
+        ));
+    }
+    Ok(())
+}
+
+fn validate_dropdowns(options: &XlsxSheetWriteOptions) -> Result<(), String> {
+    for (_col, _values) in options.cols_dropdown.iter().flatten() {
+        if _values.is_empty() {
+            return Err(format!("cols_dropdown[{_col:?}] must list >= 1 value."));
+        }
+        create_dropdown_validation(_values)
+            .map_err(|err| format!("cols_dropdown[{_col:?}] is invalid: {err}"))?;
+    }
+    Ok(())
+}
+
+fn validate_column_widths(options: &XlsxSheetWriteOptions) -> Result<(), String> {
+    for (_col, _width) in options.cols_width.iter().flatten() {
+        if !(0.0..=255.0).contains(_width) {
+            return Err(format!("cols_width[{_col:?}] must be within 0..=255."));
+        }
+    }
+    Ok(())
+}
+
+fn validate_default_layout(options_write: &XlsxWriteOptions) -> Result<(), String> {
+    if let Some(width) = options_write.default_col_width
+        && !(width > 0.0 && width <= 255.0)
+    {
+        return Err("default_col_width must be within (0, 255].".to_string());
+    }
+    if let Some(height) = options_write.default_row_height
+        && !(height > 0.0 && height <= 409.0)
+    {
+        return Err("default_row_height must be within (0, 409].".to_string());
+    }
+    Ok(())
+}
+
+fn validate_page_setup(page_setup: Option<&PageSetup>) -> Result<(), String> {
+    if let Some((left, right, top, bottom)) = page_setup.and_then(|_setup| _setup.margins)
+        && [left, right, top, bottom]
+            .iter()
+            .any(|_margin| !_margin.is_finite() || *_margin < 0.0)
+    {
+        return Err("page_setup.margins must be finite and >= 0.".to_string());
+    }
+    if let Some((row_start, col_start, row_end, col_end)) =
+        page_setup.and_then(|_setup| _setup.print_area)
+        && (row_start > row_end || col_start > col_end)
+    {
+        return Err(
+            "page_setup.print_area must have start <= end for rows and columns.".to_string(),
+        );
+    }
+    for (key, text) in [
+        (
+            "header_text",
+            page_setup.and_then(|_setup| _setup.header_text.as_deref()),
+        ),
+        (
+            "footer_text",
+            page_setup.and_then(|_setup| _setup.footer_text.as_deref()),
+        ),
+    ] {
+        if let Some(text) = text
+            && text.chars().count() > LEN_HEADER_FOOTER_MAX
+        {
+            return Err(format!(
+                "page_setup.{key} must be at most {LEN_HEADER_FOOTER_MAX} characters."
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_zoom(zoom: Option<u16>) -> Result<(), String> {
+    if let Some(zoom) = zoom
+        && !(10..=400).contains(&zoom)
+    {
+        return Err("zoom must be in 10..=400.".to_string());
+    }
+    Ok(())
+}
+
+fn validate_row_groups(row_groups: &[RowGroup]) -> Result<(), String> {
+    for (_idx, _group) in row_groups.iter().enumerate() {
+        if !(1..=7).contains(&_group.level) {
+            return Err(format!("row_groups[{_idx}].level must be in 1..=7."));
+        }
+        if _group.row_start > _group.row_end {
+            return Err(format!(
+                "row_groups[{_idx}].row_start must not exceed row_end."
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_rich_text_cells(
+    rich_text_cells: &BTreeMap<(usize, usize), Vec<RichTextRun>>,
+) -> Result<(), String> {
+    for (_cell, _runs) in rich_text_cells {
+        if _runs.is_empty() || _runs.iter().any(|(_, _text)| _text.is_empty()) {
+            return Err(format!(
+                "rich_text_cells[{_cell:?}] must have at least one run and no empty run text."
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_formula_cells(options: &XlsxSheetWriteOptions) -> Result<(), String> {
+    for (_cell, _formula) in &options.formula_cells {
+        if !_formula.starts_with('=') || _formula.len() < 2 {
+            return Err(format!(
+                "formula_cells[{_cell:?}] must start with '=' followed by a formula."
+            ));
+        }
+        if options.rich_text_cells.contains_key(_cell) {
+            return Err(format!(
+                "formula_cells[{_cell:?}] is also set in rich_text_cells."
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_header_merge_options(options: &XlsxSheetWriteOptions) -> Result<(), String> {
+    if options.should_merge_header_vertical && !options.should_merge_header {
+        return Err("should_merge_header_vertical requires should_merge_header.".to_string());
+    }
+    Ok(())
+}
+
+fn validate_table_options(options: &XlsxSheetWriteOptions) -> Result<(), String> {
+    if !options.should_write_table {
+        return Ok(());
+    }
+    if options.should_merge_header {
+        return Err(
+            "should_write_table cannot be combined with should_merge_header: \
+             Excel tables require a single unmerged header row."
+                .to_string(),
+        );
+    }
+    if options.should_autofilter {
+        return Err(
+            "should_write_table cannot be combined with should_autofilter: \
+             Excel tables already include their own filter."
+                .to_string(),
+        );
+    }
+    if let Some(style_name) = options.table_style.as_deref() {
+        parse_table_style(style_name)?;
+    }
+    Ok(())
+}
+
+fn validate_value_policy(value_policy: &XlsxValuePolicy) -> Result<(), String> {
+    if let Some(name) = &value_policy.datetime_tz {
+        parse_timezone(name).map_err(|err| format!("value_policy.datetime_tz: {err}"))?;
+    }
+    Ok(())
+}
+
+fn validate_sheet_split_template(template: Option<&str>) -> Result<(), String> {
+    let Some(template) = template else {
+        return Ok(());
+    };
+    if !template.contains("{n}") {
+        return Err("sheet_split_template must contain \"{n}\".".to_string());
+    }
+    let template_literal = template.replace("{base}", "").replace("{n}", "");
+    if SHEET_NAME_ILLEGAL_CHRS
+        .iter()
+        .any(|_chr| template_literal.contains(_chr))
+    {
+        return Err(format!(
+            "sheet_split_template must not contain any of: {}",
+            SHEET_NAME_ILLEGAL_CHRS.join(" ")
+        ));
+    }
+    Ok(())
+}
+
+fn validate_currency_options(options: &XlsxSheetWriteOptions) -> Result<(), String> {
+    if options
+        .currency_symbol
+        .as_deref()
+        .is_some_and(|symbol| symbol.contains('"'))
+    {
+        return Err("currency_symbol must not contain '\"'.".to_string());
+    }
+    if options
+        .currency_decimals
+        .is_some_and(|decimals| decimals > CURRENCY_DECIMALS_MAX)
+    {
+        return Err(format!(
+            "currency_decimals must be in 0..={CURRENCY_DECIMALS_MAX}."
+        ));
+    }
+    Ok(())
+}
+
+fn validate_percent_options(options: &XlsxSheetWriteOptions) -> Result<(), String> {
+    if options
+        .percent_decimals
+        .is_some_and(|decimals| decimals > PERCENT_DECIMALS_MAX)
+    {
+        return Err(format!(
+            "percent_decimals must be in 0..={PERCENT_DECIMALS_MAX}."
+        ));
+    }
+    Ok(())
+}
+
 fn validate_policy_scientific(policy_scientific: &ScientificPolicy) -> Result<(), String> {
     if policy_scientific.thr_min < 0.0 {
         return Err("policy_scientific.thr_min must be >= 0.".to_string());
@@ -1816,9 +3693,47 @@ fn validate_policy_scientific(policy_scientific: &ScientificPolicy) -> Result<()
     if policy_scientific.thr_min > policy_scientific.thr_max {
         return Err("policy_scientific.thr_min must be <= policy_scientific.thr_max.".to_string());
     }
+    if policy_scientific.precision > SCIENTIFIC_PRECISION_MAX {
+        return Err(format!(
+            "policy_scientific.precision must be in 0..={SCIENTIFIC_PRECISION_MAX}."
+        ));
+    }
     Ok(())
 }
 
+/// Record the numeric, integer, and scientific-candidate columns of one write call by name.
+fn record_column_kinds(
+    report: &mut XlsxReport,
+    col_names: &[&str],
+    cols_idx_numeric: &[usize],
+    cols_idx_integer: &[usize],
+    cols_idx_decimal_specified: &[usize],
+    policy_scientific: &ScientificPolicy,
+) {
+    let select_names = |cols_idx: &[usize]| -> Vec<String> {
+        cols_idx
+            .iter()
+            .map(|&idx| col_names[idx].to_string())
+            .collect()
+    };
+    let is_decimal_explicit = !cols_idx_decimal_specified.is_empty();
+    let cols_idx_scientific = cols_idx_numeric
+        .iter()
+        .copied()
+        .filter(|idx| {
+            is_scientific_candidate_col(
+                policy_scientific,
+                cols_idx_integer.contains(idx),
+                is_decimal_explicit,
+                cols_idx_decimal_specified.contains(idx),
+            )
+        })
+        .collect::<Vec<_>>();
+    report.cols_numeric = select_names(cols_idx_numeric);
+    report.cols_integer = select_names(cols_idx_integer);
+    report.cols_scientific = select_names(&cols_idx_scientific);
+}
+
 fn is_scientific_candidate_col(
     policy_scientific: &ScientificPolicy,
     is_integer_col: bool,
@@ -1979,23 +3894,43 @@ fn format_header_text_from_any_value(value: AnyValue<'_>) -> String {
     }
 }
 
-fn convert_any_value_to_cell_value(value: AnyValue<'_>) -> CellValue {
+fn convert_any_value_to_cell_value(
+    value: AnyValue<'_>,
+    value_policy: &XlsxValuePolicy,
+) -> CellValue {
     match value {
         AnyValue::Null => CellValue::None,
         AnyValue::String(val) => CellValue::String(val.to_string()),
         AnyValue::StringOwned(val) => CellValue::String(val.to_string()),
-        AnyValue::Boolean(val) => CellValue::String(if val { "True" } else { "False" }.to_string()),
+        AnyValue::Boolean(val) => CellValue::Boolean(val),
         AnyValue::UInt8(val) => CellValue::Number(val as f64),
         AnyValue::UInt16(val) => CellValue::Number(val as f64),
         AnyValue::UInt32(val) => CellValue::Number(val as f64),
-        AnyValue::UInt64(val) => CellValue::Number(val as f64),
+        AnyValue::UInt64(val) => convert_integer_to_cell_value(val as i128),
         AnyValue::Int8(val) => CellValue::Number(val as f64),
         AnyValue::Int16(val) => CellValue::Number(val as f64),
         AnyValue::Int32(val) => CellValue::Number(val as f64),
-        AnyValue::Int64(val) => CellValue::Number(val as f64),
-        AnyValue::Int128(val) => CellValue::Number(val as f64),
+        AnyValue::Int64(val) => convert_integer_to_cell_value(val as i128),
+        AnyValue::Int128(val) => convert_integer_to_cell_value(val),
         AnyValue::Float32(val) => CellValue::Number(val as f64),
         AnyValue::Float64(val) => CellValue::Number(val),
+        AnyValue::Date(val) => convert_unix_days_to_excel_serial(val as f64)
+            .map(CellValue::Date)
+            .unwrap_or_else(|| CellValue::String(value.to_string())),
+        AnyValue::Datetime(val, time_unit, tz) => convert_datetime_to_cell_value(
+            val,
+            count_time_units_per_second(time_unit),
+            tz.is_some(),
+            value_policy,
+        )
+        .unwrap_or_else(|| CellValue::String(value.to_string())),
+        AnyValue::DatetimeOwned(val, time_unit, ref tz) => convert_datetime_to_cell_value(
+            val,
+            count_time_units_per_second(time_unit),
+            tz.is_some(),
+            value_policy,
+        )
+        .unwrap_or_else(|| CellValue::String(value.to_string())),
         _ => CellValue::String(value.to_string()),
     }
 }
@@ -2003,6 +3938,7 @@ fn convert_any_value_to_cell_value(value: AnyValue<'_>) -> CellValue {
 fn convert_arrow_value_to_cell_value(
     array: &dyn ArrowArray,
     row_idx: usize,
+    value_policy: &XlsxValuePolicy,
 ) -> Result<CellValue, String> {
     if array.is_null(row_idx) {
         return Ok(CellValue::None);
@@ -2020,6 +3956,31 @@ fn convert_arrow_value_to_cell_value(
         }};
     }
 
+    macro_rules! primitive_integer {
+        ($array_ty:ty) => {{
+            let arr = array.as_any().downcast_ref::<$array_ty>().ok_or_else(|| {
+                format!(
+                    "Failed to downcast Arrow array with dtype {:?}",
+                    array.dtype()
+                )
+            })?;
+            Ok(convert_integer_to_cell_value(arr.value(row_idx) as i128))
+        }};
+    }
+
+    macro_rules! primitive_datetime {
+        ($array_ty:ty, |$value:ident| $convert:expr) => {{
+            let arr = array.as_any().downcast_ref::<$array_ty>().ok_or_else(|| {
+                format!(
+                    "Failed to downcast Arrow array with dtype {:?}",
+                    array.dtype()
+                )
+            })?;
+            let $value = arr.value(row_idx);
+            Ok($convert.unwrap_or_else(|| CellValue::String($value.to_string())))
+        }};
+    }
+
     match array.dtype() {
         ArrowDataType::Null => Ok(CellValue::None),
         ArrowDataType::Boolean => {
@@ -2032,21 +3993,35 @@ fn convert_arrow_value_to_cell_value(
                         array.dtype()
                     )
                 })?;
-            Ok(CellValue::String(
-                if arr.value(row_idx) { "True" } else { "False" }.to_string(),
-            ))
+            Ok(CellValue::Boolean(arr.value(row_idx)))
         }
         ArrowDataType::Int8 => primitive_number!(PrimitiveArray<i8>),
         ArrowDataType::Int16 => primitive_number!(PrimitiveArray<i16>),
         ArrowDataType::Int32 => primitive_number!(PrimitiveArray<i32>),
-        ArrowDataType::Int64 => primitive_number!(PrimitiveArray<i64>),
-        ArrowDataType::Int128 => primitive_number!(PrimitiveArray<i128>),
+        ArrowDataType::Int64 => primitive_integer!(PrimitiveArray<i64>),
+        ArrowDataType::Int128 => primitive_integer!(PrimitiveArray<i128>),
         ArrowDataType::UInt8 => primitive_number!(PrimitiveArray<u8>),
         ArrowDataType::UInt16 => primitive_number!(PrimitiveArray<u16>),
         ArrowDataType::UInt32 => primitive_number!(PrimitiveArray<u32>),
-        ArrowDataType::UInt64 => primitive_number!(PrimitiveArray<u64>),
+        ArrowDataType::UInt64 => primitive_integer!(PrimitiveArray<u64>),
         ArrowDataType::Float32 => primitive_number!(PrimitiveArray<f32>),
         ArrowDataType::Float64 => primitive_number!(PrimitiveArray<f64>),
+        ArrowDataType::Date32 => primitive_datetime!(PrimitiveArray<i32>, |value| {
+            convert_unix_days_to_excel_serial(value as f64).map(CellValue::Date)
+        }),
+        ArrowDataType::Date64 => primitive_datetime!(PrimitiveArray<i64>, |value| {
+            convert_unix_timestamp_to_excel_serial(value, 1_000, None).map(CellValue::Date)
+        }),
+        ArrowDataType::Timestamp(time_unit, tz) => {
+            primitive_datetime!(PrimitiveArray<i64>, |value| {
+                convert_datetime_to_cell_value(
+                    value,
+                    count_arrow_time_units_per_second(*time_unit),
+                    tz.is_some(),
+                    value_policy,
+                )
+            })
+        }
         ArrowDataType::Utf8 => {
             let arr = array
                 .as_any()
@@ -2087,6 +4062,56 @@ fn convert_arrow_value_to_cell_value(
     }
 }
 
+/// Convert a Unix timestamp into a datetime cell.
+///
+/// Timezone-aware values are shown in `value_policy.datetime_tz` (UTC when unset);
+/// naive values keep their wall-clock time.
+fn convert_datetime_to_cell_value(
+    value: i64,
+    units_per_second: i64,
+    is_tz_aware: bool,
+    value_policy: &XlsxValuePolicy,
+) -> Option<CellValue> {
+    let tz_display = if is_tz_aware {
+        value_policy
+            .datetime_tz
+            .as_deref()
+            .and_then(|name| parse_timezone(name).ok())
+    } else {
+        None
+    };
+    convert_unix_timestamp_to_excel_serial(value, units_per_second, tz_display)
+        .map(CellValue::DateTime)
+}
+
+fn count_time_units_per_second(time_unit: TimeUnit) -> i64 {
+    match time_unit {
+        TimeUnit::Milliseconds => 1_000,
+        TimeUnit::Microseconds => 1_000_000,
+        TimeUnit::Nanoseconds => 1_000_000_000,
+    }
+}
+
+fn count_arrow_time_units_per_second(time_unit: ArrowTimeUnit) -> i64 {
+    match time_unit {
+        ArrowTimeUnit::Second => 1,
+        ArrowTimeUnit::Millisecond => 1_000,
+        ArrowTimeUnit::Microsecond => 1_000_000,
+        ArrowTimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
+
+fn calculate_slice_mapped_indices<T: Clone>(
+    values_by_col_idx: &BTreeMap<usize, T>,
+    col_start_inclusive: usize,
+    col_end_exclusive: usize,
+) -> BTreeMap<usize, T> {
+    values_by_col_idx
+        .range(col_start_inclusive..col_end_exclusive)
+        .map(|(_col_idx, _value)| (*_col_idx - col_start_inclusive, _value.clone()))
+        .collect()
+}
+
 fn calculate_slice_indices(
     indices: &[usize],
     col_start_inclusive: usize,
@@ -2135,22 +4160,55 @@ fn write_header_cell(
     Ok(())
 }
 
+/// Copy `format` with its four borders replaced by `border`.
+fn create_bordered_format(format: &Format, border: &CellBorder) -> Format {
+    format
+        .clone()
+        .set_border_top(parse_format_border(border.top))
+        .set_border_bottom(parse_format_border(border.bottom))
+        .set_border_left(parse_format_border(border.left))
+        .set_border_right(parse_format_border(border.right))
+}
+
 fn write_header(
     worksheet: &mut Worksheet,
     mut header_grid: Vec<Vec<String>>,
+    row_start: usize,
     should_merge: bool,
+    should_merge_vertical: bool,
     fmt_header: &Format,
+    col_offset: usize,
 ) -> Result<(), String> {
     if !should_merge {
         for (_row_idx, _row_values) in header_grid.iter().enumerate() {
             for (_col_idx, _cell_value) in _row_values.iter().enumerate() {
-                write_header_cell(worksheet, _row_idx, _col_idx, _cell_value, fmt_header)?;
+                write_header_cell(
+                    worksheet,
+                    row_start + _row_idx,
+                    col_offset + _col_idx,
+                    _cell_value,
+                    fmt_header,
+                )?;
             }
         }
         return Ok(());
     }
 
-    apply_vertical_run_text_blankout(&mut header_grid);
+    let vertical_merges = if should_merge_vertical {
+        plan_vertical_merges(&header_grid)
+    } else {
+        vec![]
+    };
+    let vertical_merge_border_plan = if should_merge_vertical {
+        BTreeMap::new()
+    } else {
+        plan_vertical_visual_merge_borders(&header_grid)
+    };
+    if should_merge_vertical {
+        apply_vertical_merge_text_clear(&mut header_grid, &vertical_merges);
+    } else {
+        apply_vertical_run_text_blankout(&mut header_grid);
+    }
     let horizontal_merges_by_row = plan_horizontal_merges(&header_grid);
     let horizontal_merge_tracker = create_horizontal_merge_tracker(&horizontal_merges_by_row);
 
@@ -2164,7 +4222,16 @@ fn write_header(
                 continue;
             }
 
-            write_header_cell(worksheet, _row_idx, _col_idx, _cell_value, fmt_header)?;
+            let fmt_vertical_merge = vertical_merge_border_plan
+                .get(&(_row_idx, _col_idx))
+                .map(|border| create_bordered_format(fmt_header, border));
+            write_header_cell(
+                worksheet,
+                row_start + _row_idx,
+                col_offset + _col_idx,
+                _cell_value,
+                fmt_vertical_merge.as_ref().unwrap_or(fmt_header),
+            )?;
         }
 
         if let Some(merges) = horizontal_merges_by_row.get(&_row_idx) {
@@ -2172,21 +4239,346 @@ fn write_header(
                 let merge = _merge;
                 worksheet
                     .merge_range(
-                        cast_row_num(_row_idx)?,
-                        cast_col_num(merge.col_idx_start)?,
-                        cast_row_num(_row_idx)?,
-                        cast_col_num(merge.col_idx_end)?,
+                        cast_row_num(row_start + _row_idx)?,
+                        cast_col_num(col_offset + merge.col_idx_start)?,
+                        cast_row_num(row_start + _row_idx)?,
+                        cast_col_num(col_offset + merge.col_idx_end)?,
                         &merge.text,
                         fmt_header,
                     )
                     .map_err(format_xlsx_error_text)?;
             }
         }
+
+        // Merge while the start row is current so constant-memory sheets keep the anchor.
+        for _merge in vertical_merges
+            .iter()
+            .filter(|_merge| _merge.row_idx_start == _row_idx)
+        {
+            worksheet
+                .merge_range(
+                    cast_row_num(row_start + _merge.row_idx_start)?,
+                    cast_col_num(col_offset + _merge.col_idx)?,
+                    cast_row_num(row_start + _merge.row_idx_end)?,
+                    cast_col_num(col_offset + _merge.col_idx)?,
+                    &_merge.text,
+                    fmt_header,
+                )
+                .map_err(format_xlsx_error_text)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write one body cell, routing non-empty hyperlink-column strings through `write_url`.
+///
+/// URLs rejected by Excel fall back to a plain string cell plus a report warning.
+#[allow(clippy::too_many_arguments)]
+fn write_body_cell_with_format(
+    worksheet: &mut Worksheet,
+    row_idx: usize,
+    col_idx: usize,
+    value: &CellValue,
+    format: &Format,
+    is_hyperlink_col: bool,
+    hyperlink_text: Option<String>,
+    report: &mut XlsxReport,
+) -> Result<(), String> {
+    if is_hyperlink_col
+        && let CellValue::String(url) = value
+        && !url.is_empty()
+    {
+        let mut link = Url::new(url.as_str());
+        if let Some(text) = hyperlink_text {
+            link = link.set_text(text);
+        }
+        match worksheet.write_url_with_format(
+            cast_row_num(row_idx)?,
+            cast_col_num(col_idx)?,
+            link,
+            format,
+        ) {
+            Ok(_) => return Ok(()),
+            Err(XlsxError::UnknownUrlType(_) | XlsxError::MaxUrlLengthExceeded) => {
+                report.warn(format!(
+                    "Invalid hyperlink in sheet {:?} at row {row_idx}, col {col_idx}; wrote as text: {url}",
+                    worksheet.name()
+                ));
+            }
+            Err(err) => return Err(format_xlsx_error_text(err)),
+        }
+    }
+    write_cell_with_format(worksheet, row_idx, col_idx, value, format)
+}
+
+impl SummaryAccumulator {
+    fn new(agg: SummaryAgg) -> Self {
+        Self {
+            agg,
+            sum: 0.0,
+            count_numeric: 0,
+            count_non_blank: 0,
+        }
+    }
+
+    /// Count one written body cell the way Excel's `SUM`/`AVERAGE`/`COUNTA` would see it.
+    fn update(&mut self, value: &CellValue) {
+        match value {
+            CellValue::None => return,
+            CellValue::String(s) if s.is_empty() => return,
+            CellValue::Number(v) | CellValue::Date(v) | CellValue::DateTime(v) => {
+                self.sum += v;
+                self.count_numeric += 1;
+            }
+            _ => {}
+        }
+        self.count_non_blank += 1;
+    }
+
+    fn resolve(&self) -> Option<f64> {
+        match self.agg {
+            SummaryAgg::Sum => Some(self.sum),
+            SummaryAgg::Mean => {
+                (self.count_numeric > 0).then(|| self.sum / self.count_numeric as f64)
+            }
+            SummaryAgg::Count => Some(self.count_non_blank as f64),
+        }
+    }
+}
+
+/// Convert document properties to `rust_xlsxwriter`, skipping blank fields.
+fn create_doc_properties(properties: &DocumentProperties) -> DocProperties {
+    let select_text = |_value: &Option<String>| {
+        _value
+            .as_deref()
+            .map(str::trim)
+            .filter(|_text| !_text.is_empty())
+            .map(str::to_string)
+    };
+    let mut doc_properties = DocProperties::new();
+    if let Some(title) = select_text(&properties.title) {
+        doc_properties = doc_properties.set_title(title);
+    }
+    if let Some(subject) = select_text(&properties.subject) {
+        doc_properties = doc_properties.set_subject(subject);
+    }
+    if let Some(author) = select_text(&properties.author) {
+        doc_properties = doc_properties.set_author(author);
+    }
+    if let Some(company) = select_text(&properties.company) {
+        doc_properties = doc_properties.set_company(company);
+    }
+    if let Some(keywords) = select_text(&properties.keywords) {
+        doc_properties = doc_properties.set_keywords(keywords);
     }
+    doc_properties
+}
+
+/// Return the rich text or formula set for one `(body row, data column)` cell, if any.
+fn select_cell_value_override(
+    rich_text_cells: &BTreeMap<(usize, usize), Vec<RichTextRun>>,
+    formula_cells: &BTreeMap<(usize, usize), String>,
+    cell: (usize, usize),
+) -> Option<CellValue> {
+    rich_text_cells
+        .get(&cell)
+        .map(|_runs| CellValue::RichText(_runs.clone()))
+        .or_else(|| {
+            formula_cells
+                .get(&cell)
+                .map(|_formula| CellValue::Formula(_formula.clone()))
+        })
+}
 
+/// Number of rows the summary row takes below each sheet part's body.
+fn calculate_summary_row_height(options: &XlsxSheetWriteOptions) -> usize {
+    usize::from(
+        options
+            .summary_row
+            .as_ref()
+            .is_some_and(|_map| !_map.is_empty()),
+    )
+}
+
+/// Start one accumulator per summary column inside `col_start..col_end`, keyed slice-locally.
+fn create_summary_accumulators(
+    cols_idx_summary: &BTreeMap<usize, SummaryAgg>,
+    col_start: usize,
+    col_end: usize,
+) -> BTreeMap<usize, SummaryAccumulator> {
+    cols_idx_summary
+        .range(col_start..col_end)
+        .map(|(&_col_idx_abs, &_agg)| (_col_idx_abs - col_start, SummaryAccumulator::new(_agg)))
+        .collect()
+}
+
+/// Write the bold, top-bordered summary row directly below one sheet part's body.
+///
+/// Formula cells carry the computed value as their cached result.
+fn write_summary_row(
+    worksheet: &mut Worksheet,
+    summary_accumulators: &BTreeMap<usize, SummaryAccumulator>,
+    body_row_start: usize,
+    height_data: usize,
+    data_formats_by_col: &[Format],
+    should_use_formulas: bool,
+    col_offset: usize,
+) -> Result<(), String> {
+    let row_summary = cast_row_num(body_row_start + height_data)?;
+    for (&_col_idx, _accumulator) in summary_accumulators {
+        let col_num = cast_col_num(col_offset + _col_idx)?;
+        let fmt_summary = match _accumulator.agg {
+            SummaryAgg::Count => Format::new(),
+            SummaryAgg::Sum | SummaryAgg::Mean => data_formats_by_col[_col_idx].clone(),
+        }
+        .set_bold()
+        .set_border_top(FormatBorder::Thin);
+        let value = _accumulator.resolve();
+
+        if should_use_formulas && height_data > 0 {
+            let fn_name = match _accumulator.agg {
+                SummaryAgg::Sum => "SUM",
+                SummaryAgg::Mean => "AVERAGE",
+                SummaryAgg::Count => "COUNTA",
+            };
+            let range = cell_range(
+                cast_row_num(body_row_start)?,
+                col_num,
+                cast_row_num(body_row_start + height_data - 1)?,
+                col_num,
+            );
+            let mut formula = Formula::new(format!("={fn_name}({range})"));
+            if let Some(v) = value {
+                formula = formula.set_result(v.to_string());
+            }
+            worksheet.write_formula_with_format(row_summary, col_num, formula, &fmt_summary)
+        } else if let Some(v) = value {
+            worksheet.write_number_with_format(row_summary, col_num, v, &fmt_summary)
+        } else {
+            worksheet.write_blank(row_summary, col_num, &fmt_summary)
+        }
+        .map_err(format_xlsx_error_text)?;
+    }
     Ok(())
 }
 
+/// Widen a block's autofit widths to the widest seen in earlier blocks of a shared sheet.
+///
+/// `widths_shared` is indexed by worksheet column and `widths_block` starts at `col_offset`.
+fn merge_shared_widths(
+    widths_shared: &mut Vec<usize>,
+    col_offset: usize,
+    widths_block: &mut [usize],
+) {
+    let col_end = col_offset + widths_block.len();
+    if widths_shared.len() < col_end {
+        widths_shared.resize(col_end, 0);
+    }
+    for (_width_shared, _width_block) in widths_shared[col_offset..col_end]
+        .iter_mut()
+        .zip(widths_block.iter_mut())
+    {
+        *_width_shared = usize::max(*_width_shared, *_width_block);
+        *_width_block = *_width_shared;
+    }
+}
+
+/// Fold one block's report into the report of the whole sheet.
+fn merge_report(report: &mut XlsxReport, report_block: XlsxReport) {
+    report.sheets.extend(report_block.sheets);
+    report.warnings.extend(report_block.warnings);
+    report.cols_numeric.extend(report_block.cols_numeric);
+    report.cols_integer.extend(report_block.cols_integer);
+    report.cols_scientific.extend(report_block.cols_scientific);
+    report.count_cells_blanked += report_block.count_cells_blanked;
+    report.count_cells_coerced += report_block.count_cells_coerced;
+}
+
+/// Count one converted body cell as blanked or coerced by the value policy.
+fn record_cell_conversion(report: &mut XlsxReport, value_raw: &CellValue, value: &CellValue) {
+    if matches!(value, CellValue::None) {
+        report.count_cells_blanked += 1;
+    } else if is_cell_value_coerced(value_raw, value) {
+        report.count_cells_coerced += 1;
+    }
+}
+
+/// Report `formula_cells` keys that fall outside the written body; they are not written.
+fn warn_out_of_range_formula_cells(
+    report: &mut XlsxReport,
+    options: &XlsxSheetWriteOptions,
+    height_body: usize,
+    width_body: usize,
+) {
+    let cells_out_of_range = options
+        .formula_cells
+        .keys()
+        .filter(|(_row, _col)| *_row >= height_body || *_col >= width_body)
+        .collect::<Vec<_>>();
+    let Some(cell_first) = cells_out_of_range.first() else {
+        return;
+    };
+    report.warn(format!(
+        "Ignored {} formula_cells key(s) outside the written body of {height_body} row(s) x {width_body} column(s), first {cell_first:?}.",
+        cells_out_of_range.len()
+    ));
+}
+
+/// Report integer cells written as text because they exceed exact `f64` precision.
+fn warn_integer_text_cells(report: &mut XlsxReport, count_integer_text: usize) {
+    if count_integer_text == 0 {
+        return;
+    }
+    let sheet_name = report
+        .sheets
+        .first()
+        .map(|sheet| sheet.sheet_name.clone())
+        .unwrap_or_default();
+    report.warn(format!(
+        "Wrote {count_integer_text} integer cell(s) beyond 2^53 as text in sheet {sheet_name:?} to avoid precision loss."
+    ));
+}
+
+fn derive_companion_text(value: CellValue) -> Option<String> {
+    match value {
+        CellValue::String(text) if !text.is_empty() => Some(text),
+        CellValue::Number(num) => Some(num.to_string()),
+        CellValue::Integer(num) => Some(num.to_string()),
+        _ => None,
+    }
+}
+
+fn read_companion_text(
+    column: &Column,
+    row_idx: usize,
+    value_policy: &XlsxValuePolicy,
+) -> Result<Option<String>, String> {
+    let value = column
+        .get(row_idx)
+        .map_err(|err| format!("Failed to access cell value: {err}"))?;
+    Ok(derive_companion_text(convert_any_value_to_cell_value(
+        value,
+        value_policy,
+    )))
+}
+
+fn insert_cell_comment(
+    worksheet: &mut Worksheet,
+    row_idx: usize,
+    col_idx: usize,
+    text: &str,
+) -> Result<(), String> {
+    worksheet
+        .insert_note(
+            cast_row_num(row_idx)?,
+            cast_col_num(col_idx)?,
+            &Note::new(text),
+        )
+        .map(|_| ())
+        .map_err(format_xlsx_error_text)
+}
+
 fn write_cell_with_format(
     worksheet: &mut Worksheet,
     row_idx: usize,
@@ -2220,10 +4612,104 @@ fn write_cell_with_format(
                 )
                 .map_err(format_xlsx_error_text)?;
         }
+        CellValue::Integer(val) => {
+            worksheet
+                .write_string_with_format(
+                    cast_row_num(row_idx)?,
+                    cast_col_num(col_idx)?,
+                    val.to_string(),
+                    format,
+                )
+                .map_err(format_xlsx_error_text)?;
+        }
+        CellValue::Boolean(val) => {
+            worksheet
+                .write_boolean_with_format(
+                    cast_row_num(row_idx)?,
+                    cast_col_num(col_idx)?,
+                    *val,
+                    format,
+                )
+                .map_err(format_xlsx_error_text)?;
+        }
+        CellValue::RichText(runs) => {
+            let fmts_run = runs
+                .iter()
+                .map(|(_fmt, _)| create_rust_xlsx_format(_fmt))
+                .collect::<Vec<_>>();
+            let segments = fmts_run
+                .iter()
+                .zip(runs)
+                .map(|(_fmt, (_, _text))| (_fmt, _text.as_str()))
+                .collect::<Vec<_>>();
+            worksheet
+                .write_rich_string_with_format(
+                    cast_row_num(row_idx)?,
+                    cast_col_num(col_idx)?,
+                    &segments,
+                    format,
+                )
+                .map_err(format_xlsx_error_text)?;
+        }
+        CellValue::Formula(text) => {
+            worksheet
+                .write_formula_with_format(
+                    cast_row_num(row_idx)?,
+                    cast_col_num(col_idx)?,
+                    Formula::new(text),
+                    format,
+                )
+                .map_err(format_xlsx_error_text)?;
+        }
+        CellValue::Date(val) | CellValue::DateTime(val) => {
+            let datetime =
+                ExcelDateTime::from_serial_datetime(*val).map_err(format_xlsx_error_text)?;
+            worksheet
+                .write_datetime_with_format(
+                    cast_row_num(row_idx)?,
+                    cast_col_num(col_idx)?,
+                    datetime,
+                    format,
+                )
+                .map_err(format_xlsx_error_text)?;
+        }
     }
     Ok(())
 }
 
+/// Build the scientific cell format with the policy's mantissa precision.
+fn create_scientific_format(
+    fmt_scientific: &CellFormatPatch,
+    options_write: &XlsxWriteOptions,
+    policy_scientific: &ScientificPolicy,
+) -> Format {
+    let fmt_base = fmt_scientific.merge(&options_write.base_format_patch);
+    create_rust_xlsx_format(&fmt_base.with_(CellFormatPatch {
+        num_format: Some(create_scientific_num_format(policy_scientific.precision)),
+        ..Default::default()
+    }))
+}
+
+/// Build date and datetime cell formats from the text preset.
+fn create_date_formats(
+    fmt_text: &CellFormatPatch,
+    options_write: &XlsxWriteOptions,
+) -> (Format, Format) {
+    let fmt_base = fmt_text.merge(&options_write.base_format_patch);
+    let fmt_date = fmt_base.with_(CellFormatPatch {
+        num_format: Some(options_write.date_num_format.clone()),
+        ..Default::default()
+    });
+    let fmt_datetime = fmt_base.with_(CellFormatPatch {
+        num_format: Some(options_write.datetime_num_format.clone()),
+        ..Default::default()
+    });
+    (
+        create_rust_xlsx_format(&fmt_date),
+        create_rust_xlsx_format(&fmt_datetime),
+    )
+}
+
 fn create_rust_xlsx_format(spec: &CellFormatPatch) -> Format {
     let mut format = Format::new();
 
@@ -2239,14 +4725,20 @@ fn create_rust_xlsx_format(spec: &CellFormatPatch) -> Format {
     if spec.italic.unwrap_or(false) {
         format = format.set_italic();
     }
+    if spec.underline.unwrap_or(false) {
+        format = format.set_underline(FormatUnderline::Single);
+    }
+    if spec.strikethrough.unwrap_or(false) {
+        format = format.set_font_strikethrough();
+    }
 
     if let Some(val) = &spec.align
-        && let Some(align) = parse_format_align(val)
+        && let Some(align) = parse_format_align_horizontal(val)
     {
         format = format.set_align(align);
     }
     if let Some(val) = &spec.valign
-        && let Some(align) = parse_format_align(val)
+        && let Some(align) = parse_format_align_vertical(val)
     {
         format = format.set_align(align);
     }
@@ -2257,6 +4749,14 @@ fn create_rust_xlsx_format(spec: &CellFormatPatch) -> Format {
     if let Some(val) = &spec.bg_color {
         format = format.set_background_color(val.as_str());
     }
+    if let Some(val) = &spec.fg_color {
+        format = format.set_foreground_color(val.as_str());
+    }
+    if let Some(val) = spec.pattern
+        && val <= FORMAT_PATTERN_MAX
+    {
+        format = format.set_pattern(parse_format_pattern(val));
+    }
     if let Some(val) = &spec.font_color {
         format = format.set_font_color(val.as_str());
     }
@@ -2276,14 +4776,35 @@ fn create_rust_xlsx_format(spec: &CellFormatPatch) -> Format {
     if let Some(val) = spec.right {
         format = format.set_border_right(parse_format_border(val));
     }
+    if let Some(val) = spec.diagonal {
+        format = format.set_border_diagonal(parse_format_border(val));
+    }
+    if let Some(val) = spec.diagonal_type {
+        format = format.set_border_diagonal_type(parse_format_diagonal_type(val));
+    }
 
     if spec.text_wrap.unwrap_or(false) {
         format = format.set_text_wrap();
     }
+    if spec.shrink_to_fit.unwrap_or(false) {
+        format = format.set_shrink();
+    }
+    if let Some(val) = spec.indent {
+        format = format.set_indent(val.clamp(0, FORMAT_INDENT_MAX) as u8);
+    }
+    if let Some(val) = spec.rotation
+        && is_valid_rotation(val)
+    {
+        format = format.set_rotation(val);
+    }
 
     format
 }
 
+fn is_valid_rotation(rotation: i16) -> bool {
+    (-90..=90).contains(&rotation) || rotation == 270
+}
+
 fn parse_format_border(border: i64) -> FormatBorder {
     match border {
         0 => FormatBorder::None,
@@ -2304,10 +4825,43 @@ fn parse_format_border(border: i64) -> FormatBorder {
     }
 }
 
-fn parse_format_align(align: &str) -> Option<FormatAlign> {
+fn parse_format_diagonal_type(diagonal_type: u8) -> FormatDiagonalBorder {
+    match diagonal_type {
+        1 => FormatDiagonalBorder::BorderUp,
+        2 => FormatDiagonalBorder::BorderDown,
+        3 => FormatDiagonalBorder::BorderUpDown,
+        _ => FormatDiagonalBorder::None,
+    }
+}
+
+fn parse_format_pattern(pattern: u8) -> FormatPattern {
+    match pattern {
+        1 => FormatPattern::Solid,
+        2 => FormatPattern::MediumGray,
+        3 => FormatPattern::DarkGray,
+        4 => FormatPattern::LightGray,
+        5 => FormatPattern::DarkHorizontal,
+        6 => FormatPattern::DarkVertical,
+        7 => FormatPattern::DarkDown,
+        8 => FormatPattern::DarkUp,
+        9 => FormatPattern::DarkGrid,
+        10 => FormatPattern::DarkTrellis,
+        11 => FormatPattern::LightHorizontal,
+        12 => FormatPattern::LightVertical,
+        13 => FormatPattern::LightDown,
+        14 => FormatPattern::LightUp,
+        15 => FormatPattern::LightGrid,
+        16 => FormatPattern::LightTrellis,
+        17 => FormatPattern::Gray125,
+        18 => FormatPattern::Gray0625,
+        _ => FormatPattern::None,
+    }
+}
+
+/// Horizontal alignment only; `set_align(General)` would also reset the vertical axis.
+fn parse_format_align_horizontal(align: &str) -> Option<FormatAlign> {
     let value = align.trim().to_ascii_lowercase();
     match value.as_str() {
-        "general" => Some(FormatAlign::General),
         "left" => Some(FormatAlign::Left),
         "center" => Some(FormatAlign::Center),
         "right" => Some(FormatAlign::Right),
@@ -2315,11 +4869,21 @@ fn parse_format_align(align: &str) -> Option<FormatAlign> {
         "justify" => Some(FormatAlign::Justify),
         "center_across" => Some(FormatAlign::CenterAcross),
         "distributed" => Some(FormatAlign::Distributed),
+        _ => None,
+    }
+}
+
+/// Vertical alignment only; `center`/`justify`/`distributed` map to their vertical variants.
+fn parse_format_align_vertical(valign: &str) -> Option<FormatAlign> {
+    let value = valign.trim().to_ascii_lowercase();
+    match value.as_str() {
         "top" => Some(FormatAlign::Top),
         "bottom" => Some(FormatAlign::Bottom),
-        "vcenter" | "vertical_center" => Some(FormatAlign::VerticalCenter),
-        "vjustify" | "vertical_justify" => Some(FormatAlign::VerticalJustify),
-        "vdistributed" | "vertical_distributed" => Some(FormatAlign::VerticalDistributed),
+        "center" | "vcenter" | "vertical_center" => Some(FormatAlign::VerticalCenter),
+        "justify" | "vjustify" | "vertical_justify" => Some(FormatAlign::VerticalJustify),
+        "distributed" | "vdistributed" | "vertical_distributed" => {
+            Some(FormatAlign::VerticalDistributed)
+        }
         _ => None,
     }
 }
@@ -2335,3 +4899,74 @@ fn cast_col_num(value: usize) -> Result<u16, String> {
 fn format_xlsx_error_text(err: XlsxError) -> String {
     format!("xlsx write error: {err}")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polars::prelude::df;
+
+    #[test]
+    fn test_write_sheets_stacked_keeps_summary_row_inside_block() {
+        let body = df!("x" => [1i64, 2]).unwrap();
+        let options = XlsxSheetWriteOptions {
+            summary_row: Some(BTreeMap::from([("x".to_string(), SummaryAgg::Sum)])),
+            ..XlsxSheetWriteOptions::default()
+        };
+        for _should_use_constant_memory in [false, true] {
+            let mut writer = XlsxWriter::new(
+                None,
+                CellFormatPatch::default(),
+                CellFormatPatch::default(),
+                CellFormatPatch::default(),
+                CellFormatPatch::default(),
+                CellFormatPatch::default(),
+                XlsxWriteOptions {
+                    should_use_constant_memory: _should_use_constant_memory,
+                    ..XlsxWriteOptions::default()
+                },
+            );
+            writer
+                .write_sheets_stacked("S", &[(&body, None), (&body, None)], 0, &options)
+                .unwrap();
+            let blocks = &writer.report()[0].blocks;
+            // Header at row 0, body at rows 1..3, total at row 3.
+            assert_eq!(
+                (blocks[0].row_body_start, blocks[0].row_end_exclusive),
+                (1, 4)
+            );
+            assert_eq!(blocks[1].row_header_start, 4);
+            assert_eq!(blocks[1].row_end_exclusive, 8);
+            writer.close_to_bytes().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_write_sheet_warns_on_formula_cells_outside_body() {
+        let body = df!("x" => [1i64, 2]).unwrap();
+        let options = XlsxSheetWriteOptions {
+            formula_cells: BTreeMap::from([
+                ((0, 0), "=1+1".to_string()),
+                ((2, 0), "=2+2".to_string()),
+                ((0, 1), "=3+3".to_string()),
+            ]),
+            ..XlsxSheetWriteOptions::default()
+        };
+        let mut writer = XlsxWriter::new(
+            None,
+            CellFormatPatch::default(),
+            CellFormatPatch::default(),
+            CellFormatPatch::default(),
+            CellFormatPatch::default(),
+            CellFormatPatch::default(),
+            XlsxWriteOptions::default(),
+        );
+        writer
+            .write_sheet_from_dataframes(&body, "S", None, &options)
+            .unwrap();
+        let warnings = &writer.report()[0].warnings;
+        assert!(warnings.iter().any(|_warning| _warning.contains(
+            "Ignored 2 formula_cells key(s) outside the written body of 2 row(s) x 1 column(s), first (0, 1)."
+        )));
+        writer.close_to_bytes().unwrap();
+    }
+}
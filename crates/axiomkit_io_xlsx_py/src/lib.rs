@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -8,8 +9,10 @@ use axiomkit_io_xlsx::constant::{
     ColumnIdentifier, create_default_xlsx_formats, create_default_xlsx_write_options,
 };
 use axiomkit_io_xlsx::spec::{
-    AutofitMode, AutofitPolicy, CellFormatPatch, IntegerCoerceMode, ScientificPolicy,
-    ScientificScope, SheetSlice, XlsxValuePolicy, XlsxWriteOptions,
+    AutofitMode, AutofitPolicy, CellFormatPatch, ConditionalFormatKind, ConditionalFormatRule,
+    DocumentProperties, ImagePlacement, ImageSource, IntegerCoerceMode, PageSetup, RichTextRun,
+    RowGroup, ScientificPolicy, ScientificScope, SheetSlice, SheetSplitMode, SummaryAgg,
+    XlsxValuePolicy, XlsxWriteOptions,
 };
 use axiomkit_io_xlsx::{
     XlsxRecordBatch, XlsxRecordBatchResult, XlsxSheetWriteOptions, XlsxWriter as RsXlsxWriter,
@@ -18,7 +21,7 @@ use polars::prelude::{AnyValue, DataFrame};
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::ffi as pyffi;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyIterator, PyList, PyTuple};
+use pyo3::types::{PyAny, PyBytes, PyIterator, PyList, PyTuple};
 
 pub const BRIDGE_ABI_VERSION: u64 = 2;
 pub const BRIDGE_CONTRACT_VERSION: &str = "axiomkit.xlsx.writer.v2";
@@ -69,8 +72,10 @@ impl PyXlsxWriter {
         fmt_decimal = None,
         fmt_scientific = None,
         fmt_header = None,
-        options_write = None
+        options_write = None,
+        doc_properties = None
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         file_out: String,
         fmt_text: Option<&Bound<'_, PyAny>>,
@@ -79,6 +84,7 @@ impl PyXlsxWriter {
         fmt_scientific: Option<&Bound<'_, PyAny>>,
         fmt_header: Option<&Bound<'_, PyAny>>,
         options_write: Option<&Bound<'_, PyAny>>,
+        doc_properties: Option<BTreeMap<String, String>>,
     ) -> PyResult<Self> {
         let path_file_out = PathBuf::from(&file_out);
 
@@ -114,7 +120,7 @@ impl PyXlsxWriter {
         let cfg_options_write = parse_xlsx_write_options(options_write)?
             .unwrap_or_else(create_default_xlsx_write_options);
 
-        let inner = RsXlsxWriter::new(
+        let mut inner = RsXlsxWriter::new(
             path_file_out,
             c_fmt_text,
             c_fmt_integer,
@@ -123,6 +129,11 @@ impl PyXlsxWriter {
             c_fmt_header,
             cfg_options_write,
         );
+        if let Some(properties) = parse_document_properties(doc_properties)? {
+            inner
+                .set_properties(&properties)
+                .map_err(PyValueError::new_err)?;
+        }
 
         Ok(Self { file_out, inner })
     }
@@ -145,6 +156,20 @@ impl PyXlsxWriter {
         self.inner.close().map_err(PyRuntimeError::new_err)
     }
 
+    fn reopen(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf.inner.reopen();
+        slf
+    }
+
+    #[pyo3(name = "to_bytes")]
+    fn close_to_bytes<'py>(&mut self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let buffer = self
+            .inner
+            .close_to_bytes()
+            .map_err(PyRuntimeError::new_err)?;
+        Ok(PyBytes::new(py, &buffer))
+    }
+
     fn report(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         let l_reports = self.inner.report();
 
@@ -159,8 +184,15 @@ impl PyXlsxWriter {
                 l_sheet_obj.push(create_sheet_slice_object(&cls_sheet_slice, &sheet)?);
             }
 
-            let inst_report =
-                cls_xlsx_report.call1((PyList::new(py, l_sheet_obj)?, report.warnings))?;
+            let inst_report = cls_xlsx_report.call1((
+                PyList::new(py, l_sheet_obj)?,
+                report.warnings,
+                report.cols_numeric,
+                report.cols_integer,
+                report.cols_scientific,
+                report.count_cells_blanked,
+                report.count_cells_coerced,
+            ))?;
             l_report_obj.push(inst_report.unbind());
         }
 
@@ -174,9 +206,39 @@ impl PyXlsxWriter {
         header = None,
         cols_integer = None,
         cols_decimal = None,
+        cols_hyperlink = None,
+        cols_hyperlink_text = None,
+        cols_comment_text = None,
         num_frozen_cols = 0,
         num_frozen_rows = None,
+        num_reserved_top_rows = 0,
         should_merge_header = false,
+        should_merge_header_vertical = false,
+        should_autofilter = false,
+        should_write_table = false,
+        table_style = None,
+        zoom = None,
+        should_hide_gridlines = false,
+        tab_color = None,
+        page_setup = None,
+        conditional_formats = None,
+        should_band_rows = false,
+        band_color = None,
+        cols_dropdown = None,
+        cols_width = None,
+        cols_fmt_overrides = None,
+        cols_hidden = None,
+        cols_currency = None,
+        currency_symbol = None,
+        currency_decimals = None,
+        cols_percent = None,
+        percent_decimals = None,
+        summary_row = None,
+        should_use_summary_formulas = false,
+        rich_text_cells = None,
+        formula_cells = None,
+        images = None,
+        row_groups = None,
         should_keep_missing_values = None,
         policy_autofit = None,
         policy_scientific = None
@@ -190,9 +252,39 @@ impl PyXlsxWriter {
         header: Option<&Bound<'py, PyAny>>,
         cols_integer: Option<&Bound<'py, PyAny>>,
         cols_decimal: Option<&Bound<'py, PyAny>>,
+        cols_hyperlink: Option<&Bound<'py, PyAny>>,
+        cols_hyperlink_text: Option<BTreeMap<String, String>>,
+        cols_comment_text: Option<BTreeMap<String, String>>,
         num_frozen_cols: usize,
         num_frozen_rows: Option<usize>,
+        num_reserved_top_rows: usize,
         should_merge_header: bool,
+        should_merge_header_vertical: bool,
+        should_autofilter: bool,
+        should_write_table: bool,
+        table_style: Option<String>,
+        zoom: Option<u16>,
+        should_hide_gridlines: bool,
+        tab_color: Option<String>,
+        page_setup: Option<&Bound<'py, PyAny>>,
+        conditional_formats: Option<&Bound<'py, PyAny>>,
+        should_band_rows: bool,
+        band_color: Option<String>,
+        cols_dropdown: Option<BTreeMap<String, Vec<String>>>,
+        cols_width: Option<BTreeMap<String, f64>>,
+        cols_fmt_overrides: Option<&Bound<'py, PyAny>>,
+        cols_hidden: Option<&Bound<'py, PyAny>>,
+        cols_currency: Option<&Bound<'py, PyAny>>,
+        currency_symbol: Option<String>,
+        currency_decimals: Option<usize>,
+        cols_percent: Option<&Bound<'py, PyAny>>,
+        percent_decimals: Option<usize>,
+        summary_row: Option<BTreeMap<String, String>>,
+        should_use_summary_formulas: bool,
+        rich_text_cells: Option<&Bound<'py, PyAny>>,
+        formula_cells: Option<BTreeMap<(usize, usize), String>>,
+        images: Option<&Bound<'py, PyAny>>,
+        row_groups: Option<&Bound<'py, PyAny>>,
         should_keep_missing_values: Option<bool>,
         policy_autofit: Option<&Bound<'py, PyAny>>,
         policy_scientific: Option<&Bound<'py, PyAny>>,
@@ -200,9 +292,39 @@ impl PyXlsxWriter {
         let cfg_sheet_write_options = XlsxSheetWriteOptions {
             cols_integer: parse_column_refs(cols_integer)?,
             cols_decimal: parse_column_refs(cols_decimal)?,
+            cols_hyperlink: parse_column_refs(cols_hyperlink)?,
+            cols_hyperlink_text,
+            cols_comment_text,
             num_frozen_cols,
             num_frozen_rows,
+            num_reserved_top_rows,
             should_merge_header,
+            should_merge_header_vertical,
+            should_autofilter,
+            should_write_table,
+            table_style,
+            zoom,
+            should_hide_gridlines,
+            tab_color,
+            page_setup: parse_page_setup(page_setup)?,
+            conditional_formats: parse_conditional_formats(conditional_formats)?,
+            should_band_rows,
+            band_color,
+            cols_dropdown,
+            cols_width,
+            cols_fmt_overrides: parse_cols_fmt_overrides(cols_fmt_overrides)?,
+            cols_hidden: parse_column_refs(cols_hidden)?,
+            cols_currency: parse_column_refs(cols_currency)?,
+            currency_symbol,
+            currency_decimals,
+            cols_percent: parse_column_refs(cols_percent)?,
+            percent_decimals,
+            summary_row: parse_summary_row(summary_row)?,
+            should_use_summary_formulas,
+            rich_text_cells: parse_rich_text_cells(rich_text_cells)?,
+            formula_cells: formula_cells.unwrap_or_default(),
+            images: parse_image_placements(images)?,
+            row_groups: parse_row_groups(row_groups)?,
             should_keep_missing_values,
             policy_autofit: parse_autofit_policy(policy_autofit)?
                 .unwrap_or_else(AutofitPolicy::default),
@@ -211,6 +333,8 @@ impl PyXlsxWriter {
         };
 
         let header_grid = derive_optional_header_grid(py, header)?;
+        // Both passes pull the body batch-by-batch from its Arrow C stream, so peak
+        // memory stays at one record batch; only the small header grid is materialized.
         let plan = slf
             .inner
             .plan_sheet_from_record_batch_results(
@@ -238,9 +362,39 @@ impl PyXlsxWriter {
         header = None,
         cols_integer = None,
         cols_decimal = None,
+        cols_hyperlink = None,
+        cols_hyperlink_text = None,
+        cols_comment_text = None,
         num_frozen_cols = 0,
         num_frozen_rows = None,
+        num_reserved_top_rows = 0,
         should_merge_header = false,
+        should_merge_header_vertical = false,
+        should_autofilter = false,
+        should_write_table = false,
+        table_style = None,
+        zoom = None,
+        should_hide_gridlines = false,
+        tab_color = None,
+        page_setup = None,
+        conditional_formats = None,
+        should_band_rows = false,
+        band_color = None,
+        cols_dropdown = None,
+        cols_width = None,
+        cols_fmt_overrides = None,
+        cols_hidden = None,
+        cols_currency = None,
+        currency_symbol = None,
+        currency_decimals = None,
+        cols_percent = None,
+        percent_decimals = None,
+        summary_row = None,
+        should_use_summary_formulas = false,
+        rich_text_cells = None,
+        formula_cells = None,
+        images = None,
+        row_groups = None,
         should_keep_missing_values = None,
         policy_autofit = None,
         policy_scientific = None,
@@ -256,9 +410,39 @@ impl PyXlsxWriter {
         header: Option<&Bound<'py, PyAny>>,
         cols_integer: Option<&Bound<'py, PyAny>>,
         cols_decimal: Option<&Bound<'py, PyAny>>,
+        cols_hyperlink: Option<&Bound<'py, PyAny>>,
+        cols_hyperlink_text: Option<BTreeMap<String, String>>,
+        cols_comment_text: Option<BTreeMap<String, String>>,
         num_frozen_cols: usize,
         num_frozen_rows: Option<usize>,
+        num_reserved_top_rows: usize,
         should_merge_header: bool,
+        should_merge_header_vertical: bool,
+        should_autofilter: bool,
+        should_write_table: bool,
+        table_style: Option<String>,
+        zoom: Option<u16>,
+        should_hide_gridlines: bool,
+        tab_color: Option<String>,
+        page_setup: Option<&Bound<'py, PyAny>>,
+        conditional_formats: Option<&Bound<'py, PyAny>>,
+        should_band_rows: bool,
+        band_color: Option<String>,
+        cols_dropdown: Option<BTreeMap<String, Vec<String>>>,
+        cols_width: Option<BTreeMap<String, f64>>,
+        cols_fmt_overrides: Option<&Bound<'py, PyAny>>,
+        cols_hidden: Option<&Bound<'py, PyAny>>,
+        cols_currency: Option<&Bound<'py, PyAny>>,
+        currency_symbol: Option<String>,
+        currency_decimals: Option<usize>,
+        cols_percent: Option<&Bound<'py, PyAny>>,
+        percent_decimals: Option<usize>,
+        summary_row: Option<BTreeMap<String, String>>,
+        should_use_summary_formulas: bool,
+        rich_text_cells: Option<&Bound<'py, PyAny>>,
+        formula_cells: Option<BTreeMap<(usize, usize), String>>,
+        images: Option<&Bound<'py, PyAny>>,
+        row_groups: Option<&Bound<'py, PyAny>>,
         should_keep_missing_values: Option<bool>,
         policy_autofit: Option<&Bound<'py, PyAny>>,
         policy_scientific: Option<&Bound<'py, PyAny>>,
@@ -267,9 +451,39 @@ impl PyXlsxWriter {
         let cfg_sheet_write_options = XlsxSheetWriteOptions {
             cols_integer: parse_column_refs(cols_integer)?,
             cols_decimal: parse_column_refs(cols_decimal)?,
+            cols_hyperlink: parse_column_refs(cols_hyperlink)?,
+            cols_hyperlink_text,
+            cols_comment_text,
             num_frozen_cols,
             num_frozen_rows,
+            num_reserved_top_rows,
             should_merge_header,
+            should_merge_header_vertical,
+            should_autofilter,
+            should_write_table,
+            table_style,
+            zoom,
+            should_hide_gridlines,
+            tab_color,
+            page_setup: parse_page_setup(page_setup)?,
+            conditional_formats: parse_conditional_formats(conditional_formats)?,
+            should_band_rows,
+            band_color,
+            cols_dropdown,
+            cols_width,
+            cols_fmt_overrides: parse_cols_fmt_overrides(cols_fmt_overrides)?,
+            cols_hidden: parse_column_refs(cols_hidden)?,
+            cols_currency: parse_column_refs(cols_currency)?,
+            currency_symbol,
+            currency_decimals,
+            cols_percent: parse_column_refs(cols_percent)?,
+            percent_decimals,
+            summary_row: parse_summary_row(summary_row)?,
+            should_use_summary_formulas,
+            rich_text_cells: parse_rich_text_cells(rich_text_cells)?,
+            formula_cells: formula_cells.unwrap_or_default(),
+            images: parse_image_placements(images)?,
+            row_groups: parse_row_groups(row_groups)?,
             should_keep_missing_values,
             policy_autofit: parse_autofit_policy(policy_autofit)?
                 .unwrap_or_else(AutofitPolicy::default),
@@ -310,9 +524,39 @@ impl PyXlsxWriter {
         header = None,
         cols_integer = None,
         cols_decimal = None,
+        cols_hyperlink = None,
+        cols_hyperlink_text = None,
+        cols_comment_text = None,
         num_frozen_cols = 0,
         num_frozen_rows = None,
+        num_reserved_top_rows = 0,
         should_merge_header = false,
+        should_merge_header_vertical = false,
+        should_autofilter = false,
+        should_write_table = false,
+        table_style = None,
+        zoom = None,
+        should_hide_gridlines = false,
+        tab_color = None,
+        page_setup = None,
+        conditional_formats = None,
+        should_band_rows = false,
+        band_color = None,
+        cols_dropdown = None,
+        cols_width = None,
+        cols_fmt_overrides = None,
+        cols_hidden = None,
+        cols_currency = None,
+        currency_symbol = None,
+        currency_decimals = None,
+        cols_percent = None,
+        percent_decimals = None,
+        summary_row = None,
+        should_use_summary_formulas = false,
+        rich_text_cells = None,
+        formula_cells = None,
+        images = None,
+        row_groups = None,
         should_keep_missing_values = None,
         policy_autofit = None,
         policy_scientific = None,
@@ -327,9 +571,39 @@ impl PyXlsxWriter {
         header: Option<&Bound<'py, PyAny>>,
         cols_integer: Option<&Bound<'py, PyAny>>,
         cols_decimal: Option<&Bound<'py, PyAny>>,
+        cols_hyperlink: Option<&Bound<'py, PyAny>>,
+        cols_hyperlink_text: Option<BTreeMap<String, String>>,
+        cols_comment_text: Option<BTreeMap<String, String>>,
         num_frozen_cols: usize,
         num_frozen_rows: Option<usize>,
+        num_reserved_top_rows: usize,
         should_merge_header: bool,
+        should_merge_header_vertical: bool,
+        should_autofilter: bool,
+        should_write_table: bool,
+        table_style: Option<String>,
+        zoom: Option<u16>,
+        should_hide_gridlines: bool,
+        tab_color: Option<String>,
+        page_setup: Option<&Bound<'py, PyAny>>,
+        conditional_formats: Option<&Bound<'py, PyAny>>,
+        should_band_rows: bool,
+        band_color: Option<String>,
+        cols_dropdown: Option<BTreeMap<String, Vec<String>>>,
+        cols_width: Option<BTreeMap<String, f64>>,
+        cols_fmt_overrides: Option<&Bound<'py, PyAny>>,
+        cols_hidden: Option<&Bound<'py, PyAny>>,
+        cols_currency: Option<&Bound<'py, PyAny>>,
+        currency_symbol: Option<String>,
+        currency_decimals: Option<usize>,
+        cols_percent: Option<&Bound<'py, PyAny>>,
+        percent_decimals: Option<usize>,
+        summary_row: Option<BTreeMap<String, String>>,
+        should_use_summary_formulas: bool,
+        rich_text_cells: Option<&Bound<'py, PyAny>>,
+        formula_cells: Option<BTreeMap<(usize, usize), String>>,
+        images: Option<&Bound<'py, PyAny>>,
+        row_groups: Option<&Bound<'py, PyAny>>,
         should_keep_missing_values: Option<bool>,
         policy_autofit: Option<&Bound<'py, PyAny>>,
         policy_scientific: Option<&Bound<'py, PyAny>>,
@@ -338,9 +612,39 @@ impl PyXlsxWriter {
         let cfg_sheet_write_options = XlsxSheetWriteOptions {
             cols_integer: parse_column_refs(cols_integer)?,
             cols_decimal: parse_column_refs(cols_decimal)?,
+            cols_hyperlink: parse_column_refs(cols_hyperlink)?,
+            cols_hyperlink_text,
+            cols_comment_text,
             num_frozen_cols,
             num_frozen_rows,
+            num_reserved_top_rows,
             should_merge_header,
+            should_merge_header_vertical,
+            should_autofilter,
+            should_write_table,
+            table_style,
+            zoom,
+            should_hide_gridlines,
+            tab_color,
+            page_setup: parse_page_setup(page_setup)?,
+            conditional_formats: parse_conditional_formats(conditional_formats)?,
+            should_band_rows,
+            band_color,
+            cols_dropdown,
+            cols_width,
+            cols_fmt_overrides: parse_cols_fmt_overrides(cols_fmt_overrides)?,
+            cols_hidden: parse_column_refs(cols_hidden)?,
+            cols_currency: parse_column_refs(cols_currency)?,
+            currency_symbol,
+            currency_decimals,
+            cols_percent: parse_column_refs(cols_percent)?,
+            percent_decimals,
+            summary_row: parse_summary_row(summary_row)?,
+            should_use_summary_formulas,
+            rich_text_cells: parse_rich_text_cells(rich_text_cells)?,
+            formula_cells: formula_cells.unwrap_or_default(),
+            images: parse_image_placements(images)?,
+            row_groups: parse_row_groups(row_groups)?,
             should_keep_missing_values,
             policy_autofit: parse_autofit_policy(policy_autofit)?
                 .unwrap_or_else(AutofitPolicy::default),
@@ -363,6 +667,30 @@ impl PyXlsxWriter {
 
         Ok(slf)
     }
+
+    #[pyo3(signature = (sheet_name, row, col, source, x_offset = 0, y_offset = 0))]
+    fn insert_image<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        sheet_name: &str,
+        row: usize,
+        col: usize,
+        source: &Bound<'py, PyAny>,
+        x_offset: u32,
+        y_offset: u32,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let placement = ImagePlacement {
+            row,
+            col,
+            source: parse_image_source(source)?,
+            x_offset,
+            y_offset,
+        };
+        slf.inner
+            .insert_image(sheet_name, &placement)
+            .map_err(PyValueError::new_err)?;
+
+        Ok(slf)
+    }
 }
 
 fn create_sheet_slice_object(
@@ -701,20 +1029,49 @@ fn parse_cell_format_patch(obj: Option<&Bound<'_, PyAny>>) -> PyResult<Option<Ce
         font_size: extract_optional_attr::<i64>(obj, "font_size")?,
         bold: extract_optional_attr::<bool>(obj, "bold")?,
         italic: extract_optional_attr::<bool>(obj, "italic")?,
+        underline: extract_optional_attr::<bool>(obj, "underline")?,
+        strikethrough: extract_optional_attr::<bool>(obj, "strikethrough")?,
         align: extract_optional_attr::<String>(obj, "align")?,
         valign: extract_optional_attr::<String>(obj, "valign")?,
         border: extract_optional_attr::<i64>(obj, "border")?,
         text_wrap: extract_optional_attr::<bool>(obj, "text_wrap")?,
+        shrink_to_fit: extract_optional_attr::<bool>(obj, "shrink_to_fit")?,
+        indent: extract_optional_attr::<i64>(obj, "indent")?,
+        rotation: extract_optional_attr::<i16>(obj, "rotation")?,
         top: extract_optional_attr::<i64>(obj, "top")?,
         bottom: extract_optional_attr::<i64>(obj, "bottom")?,
         left: extract_optional_attr::<i64>(obj, "left")?,
         right: extract_optional_attr::<i64>(obj, "right")?,
+        diagonal: extract_optional_attr::<i64>(obj, "diagonal")?,
+        diagonal_type: extract_optional_attr::<u8>(obj, "diagonal_type")?,
         num_format: extract_optional_attr::<String>(obj, "num_format")?,
         bg_color: extract_optional_attr::<String>(obj, "bg_color")?,
+        fg_color: extract_optional_attr::<String>(obj, "fg_color")?,
+        pattern: extract_optional_attr::<u8>(obj, "pattern")?,
         font_color: extract_optional_attr::<String>(obj, "font_color")?,
     }))
 }
 
+fn parse_cols_fmt_overrides(
+    obj: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Option<BTreeMap<String, CellFormatPatch>>> {
+    let Some(obj) = obj else {
+        return Ok(None);
+    };
+    if obj.is_none() {
+        return Ok(None);
+    }
+
+    let mut fmts_by_col = BTreeMap::new();
+    for item in obj.call_method0("items")?.try_iter()? {
+        let (col, fmt_obj) = item?.extract::<(String, Bound<'_, PyAny>)>()?;
+        if let Some(fmt) = parse_cell_format_patch(Some(&fmt_obj))? {
+            fmts_by_col.insert(col, fmt);
+        }
+    }
+    Ok(Some(fmts_by_col))
+}
+
 fn parse_xlsx_write_options(obj: Option<&Bound<'_, PyAny>>) -> PyResult<Option<XlsxWriteOptions>> {
     let Some(obj) = obj else {
         return Ok(None);
@@ -746,6 +1103,20 @@ fn parse_xlsx_write_options(obj: Option<&Bound<'_, PyAny>>) -> PyResult<Option<X
                 IntegerCoerceMode::Strict
             };
         }
+        if let Some(v) = extract_optional_attr::<String>(&value_policy_obj, "datetime_tz")? {
+            value_policy.datetime_tz = Some(v);
+        }
+        if let Some(v) =
+            extract_optional_attr::<bool>(&value_policy_obj, "should_write_native_booleans")?
+        {
+            value_policy.should_write_native_booleans = v;
+        }
+        if let Some(v) = extract_optional_attr::<String>(&value_policy_obj, "bool_true_str")? {
+            value_policy.bool_true_str = v;
+        }
+        if let Some(v) = extract_optional_attr::<String>(&value_policy_obj, "bool_false_str")? {
+            value_policy.bool_false_str = v;
+        }
         cfg_options_write.value_policy = value_policy;
     }
 
@@ -785,10 +1156,41 @@ fn parse_xlsx_write_options(obj: Option<&Bound<'_, PyAny>>) -> PyResult<Option<X
     {
         cfg_options_write.base_format_patch = fmt_patch;
     }
+    if let Some(v) = extract_optional_attr::<String>(obj, "date_num_format")? {
+        cfg_options_write.date_num_format = v;
+    }
+    if let Some(v) = extract_optional_attr::<String>(obj, "datetime_num_format")? {
+        cfg_options_write.datetime_num_format = v;
+    }
+    if let Some(v) = extract_optional_attr::<String>(obj, "split_mode")? {
+        cfg_options_write.split_mode = parse_sheet_split_mode(&v)?;
+    }
+    if let Some(v) = extract_optional_attr::<String>(obj, "sheet_split_template")? {
+        cfg_options_write.sheet_split_template = Some(v);
+    }
+    if let Some(v) = extract_optional_attr::<bool>(obj, "should_use_integer_thousands_sep")? {
+        cfg_options_write.should_use_integer_thousands_sep = v;
+    }
+    if let Some(v) = extract_optional_attr::<f64>(obj, "default_col_width")? {
+        cfg_options_write.default_col_width = Some(v);
+    }
+    if let Some(v) = extract_optional_attr::<f64>(obj, "default_row_height")? {
+        cfg_options_write.default_row_height = Some(v);
+    }
 
     Ok(Some(cfg_options_write))
 }
 
+fn parse_sheet_split_mode(value: &str) -> PyResult<SheetSplitMode> {
+    match value {
+        "columns_first" => Ok(SheetSplitMode::ColumnsFirst),
+        "rows_first" => Ok(SheetSplitMode::RowsFirst),
+        _ => Err(PyValueError::new_err(format!(
+            "{PY_ARG_OPTIONS_WRITE}.split_mode must be one of: 'columns_first', 'rows_first'."
+        ))),
+    }
+}
+
 fn parse_autofit_mode(value: &str) -> PyResult<AutofitMode> {
     match value {
         "none" => Ok(AutofitMode::None),
@@ -866,9 +1268,207 @@ fn parse_scientific_policy(obj: Option<&Bound<'_, PyAny>>) -> PyResult<Option<Sc
     if let Some(v) = extract_optional_attr::<f64>(obj, "thr_max")? {
         policy.thr_max = v;
     }
+    if let Some(v) = extract_optional_attr::<usize>(obj, "precision")? {
+        policy.precision = v;
+    }
     Ok(Some(policy))
 }
 
+fn parse_conditional_format_kind(value: &str) -> PyResult<ConditionalFormatKind> {
+    match value {
+        "color_scale_3" => Ok(ConditionalFormatKind::ColorScale3),
+        "data_bar" => Ok(ConditionalFormatKind::DataBar),
+        _ => Err(PyValueError::new_err(
+            "conditional_formats kind must be one of: 'color_scale_3', 'data_bar'.",
+        )),
+    }
+}
+
+fn parse_summary_agg(value: &str) -> PyResult<SummaryAgg> {
+    match value {
+        "sum" => Ok(SummaryAgg::Sum),
+        "mean" => Ok(SummaryAgg::Mean),
+        "count" => Ok(SummaryAgg::Count),
+        _ => Err(PyValueError::new_err(
+            "summary_row aggregate must be one of: 'sum', 'mean', 'count'.",
+        )),
+    }
+}
+
+fn parse_summary_row(
+    value: Option<BTreeMap<String, String>>,
+) -> PyResult<Option<BTreeMap<String, SummaryAgg>>> {
+    value
+        .map(|_aggs_by_col| {
+            _aggs_by_col
+                .into_iter()
+                .map(|(_col, _agg)| Ok((_col, parse_summary_agg(&_agg)?)))
+                .collect()
+        })
+        .transpose()
+}
+
+fn parse_conditional_formats(
+    obj: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Vec<ConditionalFormatRule>> {
+    let Some(obj) = obj else {
+        return Ok(vec![]);
+    };
+    if obj.is_none() {
+        return Ok(vec![]);
+    }
+
+    let mut rules = Vec::new();
+    for item in obj.try_iter()? {
+        let item = item?;
+        let mut rule = ConditionalFormatRule {
+            col: item.getattr("col")?.extract::<String>()?,
+            ..ConditionalFormatRule::default()
+        };
+        if let Some(v) = extract_optional_attr::<String>(&item, "kind")? {
+            rule.kind = parse_conditional_format_kind(&v)?;
+        }
+        if let Some(v) = extract_optional_attr::<String>(&item, "color_min")? {
+            rule.color_min = v;
+        }
+        if let Some(v) = extract_optional_attr::<String>(&item, "color_mid")? {
+            rule.color_mid = v;
+        }
+        if let Some(v) = extract_optional_attr::<String>(&item, "color_max")? {
+            rule.color_max = v;
+        }
+        if let Some(v) = extract_optional_attr::<String>(&item, "color_bar")? {
+            rule.color_bar = v;
+        }
+        rules.push(rule);
+    }
+    Ok(rules)
+}
+
+fn parse_image_source(obj: &Bound<'_, PyAny>) -> PyResult<ImageSource> {
+    if let Ok(bytes) = obj.downcast::<PyBytes>() {
+        return Ok(ImageSource::Bytes(bytes.as_bytes().to_vec()));
+    }
+    Ok(ImageSource::Path(obj.extract::<PathBuf>()?))
+}
+
+fn parse_image_placements(obj: Option<&Bound<'_, PyAny>>) -> PyResult<Vec<ImagePlacement>> {
+    let Some(obj) = obj else {
+        return Ok(vec![]);
+    };
+    if obj.is_none() {
+        return Ok(vec![]);
+    }
+
+    let mut placements = Vec::new();
+    for item in obj.try_iter()? {
+        let item = item?;
+        placements.push(ImagePlacement {
+            row: item.getattr("row")?.extract::<usize>()?,
+            col: item.getattr("col")?.extract::<usize>()?,
+            source: parse_image_source(&item.getattr("source")?)?,
+            x_offset: extract_optional_attr::<u32>(&item, "x_offset")?.unwrap_or(0),
+            y_offset: extract_optional_attr::<u32>(&item, "y_offset")?.unwrap_or(0),
+        });
+    }
+    Ok(placements)
+}
+
+fn parse_document_properties(
+    value: Option<BTreeMap<String, String>>,
+) -> PyResult<Option<DocumentProperties>> {
+    let Some(fields) = value else {
+        return Ok(None);
+    };
+    let mut properties = DocumentProperties::default();
+    for (_key, _text) in fields {
+        let field = match _key.as_str() {
+            "title" => &mut properties.title,
+            "subject" => &mut properties.subject,
+            "author" => &mut properties.author,
+            "company" => &mut properties.company,
+            "keywords" => &mut properties.keywords,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "doc_properties key {_key:?} must be one of: 'title', 'subject', 'author', 'company', 'keywords'."
+                )));
+            }
+        };
+        *field = Some(_text);
+    }
+    Ok(Some(properties))
+}
+
+fn parse_page_setup(obj: Option<&Bound<'_, PyAny>>) -> PyResult<Option<PageSetup>> {
+    let Some(obj) = obj else {
+        return Ok(None);
+    };
+    if obj.is_none() {
+        return Ok(None);
+    }
+
+    Ok(Some(PageSetup {
+        should_use_landscape: extract_optional_attr::<bool>(obj, "should_use_landscape")?
+            .unwrap_or(false),
+        paper_size: extract_optional_attr::<u8>(obj, "paper_size")?,
+        margins: extract_optional_attr::<(f64, f64, f64, f64)>(obj, "margins")?,
+        print_area: extract_optional_attr::<(usize, usize, usize, usize)>(obj, "print_area")?,
+        should_repeat_header_on_print: extract_optional_attr::<bool>(
+            obj,
+            "should_repeat_header_on_print",
+        )?
+        .unwrap_or(false),
+        header_text: extract_optional_attr::<String>(obj, "header_text")?,
+        footer_text: extract_optional_attr::<String>(obj, "footer_text")?,
+    }))
+}
+
+fn parse_rich_text_cells(
+    obj: Option<&Bound<'_, PyAny>>,
+) -> PyResult<BTreeMap<(usize, usize), Vec<RichTextRun>>> {
+    let Some(obj) = obj else {
+        return Ok(BTreeMap::new());
+    };
+    if obj.is_none() {
+        return Ok(BTreeMap::new());
+    }
+
+    let mut runs_by_cell = BTreeMap::new();
+    for item in obj.call_method0("items")?.try_iter()? {
+        let (cell, runs_obj) = item?.extract::<((usize, usize), Bound<'_, PyAny>)>()?;
+        let mut runs = Vec::new();
+        for run in runs_obj.try_iter()? {
+            let (fmt_obj, text) = run?.extract::<(Bound<'_, PyAny>, String)>()?;
+            runs.push((
+                parse_cell_format_patch(Some(&fmt_obj))?.unwrap_or_default(),
+                text,
+            ));
+        }
+        runs_by_cell.insert(cell, runs);
+    }
+    Ok(runs_by_cell)
+}
+
+fn parse_row_groups(obj: Option<&Bound<'_, PyAny>>) -> PyResult<Vec<RowGroup>> {
+    let Some(obj) = obj else {
+        return Ok(vec![]);
+    };
+    if obj.is_none() {
+        return Ok(vec![]);
+    }
+
+    let mut row_groups = Vec::new();
+    for item in obj.try_iter()? {
+        let item = item?;
+        row_groups.push(RowGroup {
+            row_start: item.getattr("row_start")?.extract::<usize>()?,
+            row_end: item.getattr("row_end")?.extract::<usize>()?,
+            level: extract_optional_attr::<u8>(&item, "level")?.unwrap_or(1),
+        });
+    }
+    Ok(row_groups)
+}
+
 fn parse_column_refs(value: Option<&Bound<'_, PyAny>>) -> PyResult<Option<Vec<ColumnIdentifier>>> {
     let Some(value) = value else {
         return Ok(None);
@@ -1,3 +1,3 @@
-from .copy import copy_tree
+from .copy import copy_entry, copy_tree
 
-__all__ = ["copy_tree"]
+__all__ = ["copy_entry", "copy_tree"]
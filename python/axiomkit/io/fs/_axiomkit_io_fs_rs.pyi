@@ -7,16 +7,32 @@ __bridge_transport__: str
 
 class CopyErrorRecord:
     path: str
+    source_path: str | None
+    kind: str
     exception: str
 
 
+class CopyPlanEntry:
+    src_path: str
+    dst_path: str
+    action: str
+
+
 class CopyReport:
     cnt_matched: int
     cnt_scanned: int
     cnt_copied: int
     cnt_skipped: int
+    cnt_verified: int
+    cnt_unchanged: int
+    cnt_linked: int
+    cnt_symlinks: int
+    bytes_copied: int
     warnings: list[str]
     errors: list[CopyErrorRecord]
+    plan: list[CopyPlanEntry]
+    manifest: list[tuple[str, str]]
+    stats_by_ext: dict[str, tuple[int, int]]
 
     @property
     def error_count(self) -> int: ...
@@ -45,4 +61,56 @@ def copy_tree(
     workers_max: int | None = None,
     should_keep_tree: bool = True,
     should_dry_run: bool = False,
+    mtime_after: int | None = None,
+    mtime_before: int | None = None,
+    should_match_full_path: bool = False,
+    symlink_follow_max: int | None = None,
+    should_preserve_ownership: bool = False,
+    should_preserve_dir_metadata: bool = False,
+    should_copy_atomically: bool = False,
+    should_fsync: bool = False,
+    copy_buffer_bytes: int | None = None,
+    should_preserve_sparse: bool = False,
+    throughput_max_bytes_per_sec: int | None = None,
+    should_skip_hidden: bool = False,
+    should_preserve_acl: bool = False,
+    should_rewrite_symlinks_relative: bool = False,
+    should_collect_plan: bool = False,
+    should_verify: bool = False,
+    manifest_algorithm: str | None = None,
+    should_copy_incrementally: bool = False,
+    bytes_budget_max: int | None = None,
+    files_max: int | None = None,
+    rule_special_file: str = "skip",
+    rule_conflict_symlink_dst: str = "error",
+    filename_normalization: str | None = None,
+    should_collect_ext_stats: bool = False,
+    should_dedup_destination: bool = False,
+    should_force_overwrite_readonly: bool = False,
+    mtime_tolerance_ms: int = 0,
+    should_sanitize_dest_names: bool = False,
+    should_fail_fast: bool = False,
+) -> CopyReport: ...
+
+
+def copy_entry(
+    file_source: str,
+    dir_destination: str,
+    *,
+    rule_conflict_file: str = "skip",
+    rule_symlink: str = "copy_symlinks",
+    should_dry_run: bool = False,
+    should_preserve_ownership: bool = False,
+    should_copy_atomically: bool = False,
+    should_fsync: bool = False,
+    copy_buffer_bytes: int | None = None,
+    should_preserve_sparse: bool = False,
+    throughput_max_bytes_per_sec: int | None = None,
+    should_preserve_acl: bool = False,
+    should_verify: bool = False,
+    manifest_algorithm: str | None = None,
+    should_copy_incrementally: bool = False,
+    rule_special_file: str = "skip",
+    rule_conflict_symlink_dst: str = "error",
+    filename_normalization: str | None = None,
 ) -> CopyReport: ...
@@ -8,10 +8,17 @@ from .report import CopyReport
 from .spec import (
     CopyDepthLimitMode,
     CopyDirectoryConflictMode,
+    CopyDstSymlinkConflictMode,
     CopyFileConflictMode,
+    CopyHashAlgorithm,
     CopyPatternMode,
+    CopySpecialFileMode,
     CopySymlinkMode,
+    CopyUnicodeForm,
+    CopyErrorKind,
     CopyErrorRecord,
+    CopyPlanAction,
+    CopyPlanEntry,
 )
 
 EXPECTED_BRIDGE_ABI = 1
@@ -19,6 +26,7 @@ EXPECTED_BRIDGE_CONTRACT = "axiomkit.fs.copy_tree.v1"
 EXPECTED_BRIDGE_TRANSPORT = "rust_native"
 
 _copy_tree_rs: Any | None = None
+_copy_entry_rs: Any | None = None
 _mod_rs: Any | None = None
 _error_import: Exception | None = None
 _error_contract: Exception | None = None
@@ -27,9 +35,11 @@ try:
     from . import _axiomkit_io_fs_rs as _mod_rs
 
     _copy_tree_rs = _mod_rs.copy_tree
+    _copy_entry_rs = _mod_rs.copy_entry
 except Exception as exc:  # pragma: no cover
     _mod_rs = None
     _copy_tree_rs = None
+    _copy_entry_rs = None
     _error_import = exc
 
 
@@ -63,6 +73,7 @@ if _mod_rs is not None:
         _validate_bridge_contract()
     except Exception as exc:  # pragma: no cover
         _copy_tree_rs = None
+        _copy_entry_rs = None
         _error_contract = exc
 
 
@@ -101,6 +112,35 @@ def copy_tree_via_rs(
     workers_max: int | None,
     should_keep_tree: bool,
     should_dry_run: bool,
+    mtime_after: int | None,
+    mtime_before: int | None,
+    should_match_full_path: bool,
+    symlink_follow_max: int | None,
+    should_preserve_ownership: bool,
+    should_preserve_dir_metadata: bool,
+    should_copy_atomically: bool,
+    should_fsync: bool,
+    copy_buffer_bytes: int | None,
+    should_preserve_sparse: bool,
+    throughput_max_bytes_per_sec: int | None,
+    should_skip_hidden: bool,
+    should_preserve_acl: bool,
+    should_rewrite_symlinks_relative: bool,
+    should_collect_plan: bool,
+    should_verify: bool,
+    manifest_algorithm: CopyHashAlgorithm | None,
+    should_copy_incrementally: bool,
+    bytes_budget_max: int | None,
+    files_max: int | None,
+    rule_special_file: CopySpecialFileMode,
+    rule_conflict_symlink_dst: CopyDstSymlinkConflictMode,
+    filename_normalization: CopyUnicodeForm | None,
+    should_collect_ext_stats: bool,
+    should_dedup_destination: bool,
+    should_force_overwrite_readonly: bool,
+    mtime_tolerance_ms: int,
+    should_sanitize_dest_names: bool,
+    should_fail_fast: bool,
 ) -> CopyReport:
     if _copy_tree_rs is None:  # pragma: no cover
         _raise_unavailable()
@@ -121,19 +161,121 @@ def copy_tree_via_rs(
             workers_max=workers_max,
             should_keep_tree=should_keep_tree,
             should_dry_run=should_dry_run,
+            mtime_after=mtime_after,
+            mtime_before=mtime_before,
+            should_match_full_path=should_match_full_path,
+            symlink_follow_max=symlink_follow_max,
+            should_preserve_ownership=should_preserve_ownership,
+            should_preserve_dir_metadata=should_preserve_dir_metadata,
+            should_copy_atomically=should_copy_atomically,
+            should_fsync=should_fsync,
+            copy_buffer_bytes=copy_buffer_bytes,
+            should_preserve_sparse=should_preserve_sparse,
+            throughput_max_bytes_per_sec=throughput_max_bytes_per_sec,
+            should_skip_hidden=should_skip_hidden,
+            should_preserve_acl=should_preserve_acl,
+            should_rewrite_symlinks_relative=should_rewrite_symlinks_relative,
+            should_collect_plan=should_collect_plan,
+            should_verify=should_verify,
+            manifest_algorithm=manifest_algorithm.value if manifest_algorithm is not None else None,
+            should_copy_incrementally=should_copy_incrementally,
+            bytes_budget_max=bytes_budget_max,
+            files_max=files_max,
+            rule_special_file=rule_special_file.value,
+            rule_conflict_symlink_dst=rule_conflict_symlink_dst.value,
+            filename_normalization=filename_normalization.value if filename_normalization is not None else None,
+            should_collect_ext_stats=should_collect_ext_stats,
+            should_dedup_destination=should_dedup_destination,
+            should_force_overwrite_readonly=should_force_overwrite_readonly,
+            mtime_tolerance_ms=mtime_tolerance_ms,
+            should_sanitize_dest_names=should_sanitize_dest_names,
+            should_fail_fast=should_fail_fast,
         )
 
+    return _convert_report_rs(report_rs)
+
+
+def copy_entry_via_rs(
+    file_source: Path,
+    dir_destination: Path,
+    *,
+    rule_conflict_file: CopyFileConflictMode,
+    rule_symlink: CopySymlinkMode,
+    should_dry_run: bool,
+    should_preserve_ownership: bool,
+    should_copy_atomically: bool,
+    should_fsync: bool,
+    copy_buffer_bytes: int | None,
+    should_preserve_sparse: bool,
+    throughput_max_bytes_per_sec: int | None,
+    should_preserve_acl: bool,
+    should_verify: bool,
+    manifest_algorithm: CopyHashAlgorithm | None,
+    should_copy_incrementally: bool,
+    rule_special_file: CopySpecialFileMode,
+    rule_conflict_symlink_dst: CopyDstSymlinkConflictMode,
+    filename_normalization: CopyUnicodeForm | None,
+) -> CopyReport:
+    if _copy_entry_rs is None:  # pragma: no cover
+        _raise_unavailable()
+    else:
+        report_rs = _copy_entry_rs(
+            str(file_source),
+            str(dir_destination),
+            rule_conflict_file=rule_conflict_file.value,
+            rule_symlink=rule_symlink.value,
+            should_dry_run=should_dry_run,
+            should_preserve_ownership=should_preserve_ownership,
+            should_copy_atomically=should_copy_atomically,
+            should_fsync=should_fsync,
+            copy_buffer_bytes=copy_buffer_bytes,
+            should_preserve_sparse=should_preserve_sparse,
+            throughput_max_bytes_per_sec=throughput_max_bytes_per_sec,
+            should_preserve_acl=should_preserve_acl,
+            should_verify=should_verify,
+            manifest_algorithm=manifest_algorithm.value if manifest_algorithm is not None else None,
+            should_copy_incrementally=should_copy_incrementally,
+            rule_special_file=rule_special_file.value,
+            rule_conflict_symlink_dst=rule_conflict_symlink_dst.value,
+            filename_normalization=filename_normalization.value if filename_normalization is not None else None,
+        )
+
+    return _convert_report_rs(report_rs)
+
+
+def _convert_report_rs(report_rs: Any) -> CopyReport:
     errors = tuple(
-        CopyErrorRecord(path=Path(e.path), exception=RuntimeError(e.exception))
+        CopyErrorRecord(
+            path=Path(e.path),
+            exception=RuntimeError(e.exception),
+            kind=CopyErrorKind(e.kind),
+            source_path=Path(e.source_path) if e.source_path is not None else None,
+        )
         for e in report_rs.errors
     )
     warnings = tuple(report_rs.warnings)
+    plan = tuple(
+        CopyPlanEntry(
+            src_path=Path(p.src_path),
+            dst_path=Path(p.dst_path),
+            action=CopyPlanAction(p.action),
+        )
+        for p in report_rs.plan
+    )
 
     return CopyReport(
         cnt_matched=report_rs.cnt_matched,
         cnt_scanned=report_rs.cnt_scanned,
         cnt_copied=report_rs.cnt_copied,
         cnt_skipped=report_rs.cnt_skipped,
+        cnt_verified=report_rs.cnt_verified,
+        cnt_unchanged=report_rs.cnt_unchanged,
+        cnt_linked=report_rs.cnt_linked,
+        cnt_symlinks=report_rs.cnt_symlinks,
+        bytes_copied=report_rs.bytes_copied,
         errors=errors,
         warnings=warnings,
+        plan=plan,
+        manifest=tuple((Path(p), d) for p, d in report_rs.manifest),
+        stats_by_ext=dict(report_rs.stats_by_ext),
     )
@@ -2,21 +2,29 @@ import os
 from collections.abc import Sequence
 from pathlib import Path
 
-from ._rs_bridge import copy_tree_via_rs, is_rs_backend_available
+from ._rs_bridge import copy_entry_via_rs, copy_tree_via_rs, is_rs_backend_available
 from .report import CopyReport
 from .spec import (
     CopyDepthLimitMode,
     CopyDirectoryConflictMode,
+    CopyDstSymlinkConflictMode,
     CopyFileConflictMode,
+    CopyHashAlgorithm,
     CopyPatternMode,
+    CopySpecialFileMode,
     CopySymlinkMode,
+    CopyUnicodeForm,
 )
 from .util import (
     normalize_copy_depth_mode,
     normalize_copy_dir_conflict_mode,
+    normalize_copy_dst_symlink_conflict_mode,
     normalize_copy_file_conflict_mode,
+    normalize_copy_hash_algorithm,
     normalize_copy_pattern_mode,
+    normalize_copy_special_file_mode,
     normalize_copy_symlink_mode,
+    normalize_copy_unicode_form,
 )
 
 ################################################################################
@@ -39,6 +47,35 @@ def copy_tree(
     workers_max: int | None = None,
     should_keep_tree: bool = True,
     should_dry_run: bool = False,
+    mtime_after: int | None = None,
+    mtime_before: int | None = None,
+    should_match_full_path: bool = False,
+    symlink_follow_max: int | None = None,
+    should_preserve_ownership: bool = False,
+    should_preserve_dir_metadata: bool = False,
+    should_copy_atomically: bool = False,
+    should_fsync: bool = False,
+    copy_buffer_bytes: int | None = None,
+    should_preserve_sparse: bool = False,
+    throughput_max_bytes_per_sec: int | None = None,
+    should_skip_hidden: bool = False,
+    should_preserve_acl: bool = False,
+    should_rewrite_symlinks_relative: bool = False,
+    should_collect_plan: bool = False,
+    should_verify: bool = False,
+    manifest_algorithm: CopyHashAlgorithm | str | None = None,
+    should_copy_incrementally: bool = False,
+    bytes_budget_max: int | None = None,
+    files_max: int | None = None,
+    rule_special_file: CopySpecialFileMode | str = "skip",
+    rule_conflict_symlink_dst: CopyDstSymlinkConflictMode | str = "error",
+    filename_normalization: CopyUnicodeForm | str | None = None,
+    should_collect_ext_stats: bool = False,
+    should_dedup_destination: bool = False,
+    should_force_overwrite_readonly: bool = False,
+    mtime_tolerance_ms: int = 0,
+    should_sanitize_dest_names: bool = False,
+    should_fail_fast: bool = False,
 ) -> CopyReport:
     """Copy a directory tree with filtering, depth limits, and conflict handling.
 
@@ -60,6 +97,9 @@ def copy_tree(
             - ``glob``: (Default) Unix shell-style wildcards.
             - ``regex``: Regular expressions.
             - ``literal``: Exact string matches.
+            - ``gitignore``: ``.gitignore``-style rules (``!`` negation,
+              trailing ``/`` for directories, leading ``/`` anchors at root),
+              always matched against the path relative to ``dir_source``.
         rule_conflict_file:
             File conflict strategy. See :class:`CopyFileConflictMode`.
             - ``skip``: (Default) Skip existing files.
@@ -91,13 +131,142 @@ def copy_tree(
         should_dry_run:
             - ``False``: (Default) Perform actual copy.
             - ``True``: Simulate copy without making changes.
+        mtime_after:
+            Unix-epoch seconds; only copy files modified at or after this time.
+        mtime_before:
+            Unix-epoch seconds; only copy files modified at or before this time.
+            Both bounds are inclusive. Files outside the window are skipped.
+        should_match_full_path:
+            - ``False``: (Default) Match patterns against entry basenames.
+            - ``True``: Match patterns against the ``/``-separated path relative
+              to ``dir_source`` (e.g. ``logs/app.tmp``). In glob mode ``*`` does
+              not cross ``/``; use ``**`` to span directories (``logs/**/*.tmp``).
+        symlink_follow_max:
+            Maximum number of directory symlinks followed along one path when
+            ``rule_symlink`` is ``dereference`` (None means unbounded). Deeper
+            symlinked directories are skipped with a warning.
+        should_preserve_ownership:
+            Linux only. Apply the source uid/gid to copied files. Failures (e.g.
+            insufficient privilege) are reported as warnings.
+        should_preserve_dir_metadata:
+            Apply source directory permissions and modification times to the
+            created destination directories after all files are copied.
+            Failures are reported as warnings.
+        should_copy_atomically:
+            Copy each file into a hidden sibling temp file and rename it over the
+            destination, so an interrupted run never leaves a truncated file.
+        should_fsync:
+            Flush each copied file (and its parent directory on Unix) to disk.
+            Durable but expensive; expect much lower throughput. Forces the
+            streaming copy path.
+        copy_buffer_bytes:
+            Stream file bytes through buffers of this size instead of the
+            platform fast path (None). Must be >= 1 when set.
+        should_preserve_sparse:
+            Linux only. Keep holes of sparse files unallocated at the destination;
+            falls back to a regular copy when holes cannot be detected.
+        throughput_max_bytes_per_sec:
+            Cap aggregate copy throughput in bytes per second, shared by all
+            workers. Forces the streaming copy path. Must be >= 1 when set.
+        should_skip_hidden:
+            Skip dot-prefixed entries (and, on Windows, entries with the hidden
+            attribute) during traversal, independent of pattern lists. The
+            source root itself is never skipped.
+        should_preserve_acl:
+            Linux only. Apply the source POSIX access ACL to each copied file after
+            its permission bits. Failures are reported as warnings.
+        should_rewrite_symlinks_relative:
+            With ``copy_symlinks``, rewrite absolute symlink targets inside the
+            source tree to relative targets in the destination tree. Targets
+            outside the source tree are kept. Only applies with ``should_keep_tree``.
+        should_collect_plan:
+            With ``should_dry_run``, record every planned action (copy file, create
+            directory, create symlink, skip on conflict) in ``CopyReport.plan``.
+        should_verify:
+            Re-read source and destination after each copy and compare xxh3
+            hashes. A mismatch removes the destination and records an error.
+            Roughly doubles the bytes read; expect a substantial slowdown.
+        manifest_algorithm:
+            ``sha256`` or ``blake3``. Digest every copied file from the bytes being
+            copied (single read) and collect ``(destination, hex digest)`` pairs in
+            ``CopyReport.manifest``. Forces the streaming copy path.
+        should_copy_incrementally:
+            Skip files whose destination already has the same size and mtime as
+            the source, regardless of ``rule_conflict_file``; counted in
+            ``cnt_unchanged``. Filesystems with coarse mtime precision (FAT, some
+            network mounts) only match with a suitable ``mtime_tolerance_ms``.
+        bytes_budget_max:
+            Maximum total source bytes to copy. Once the next file would exceed
+            it, that file and all remaining ones are skipped and a warning is
+            recorded; files copied before remain valid. Files hard-linked by
+            ``should_dedup_destination`` add no bytes. ``None`` means no limit.
+        files_max:
+            Maximum number of matched files to copy. Once exceeded, traversal
+            stops with a warning and the partial report is returned.
+            Independent of ``bytes_budget_max``. ``None`` means no limit.
+        rule_special_file:
+            Handling of FIFOs, sockets and device files (also as dereferenced
+            symlink targets). See :class:`CopySpecialFileMode`.
+            - ``skip``: (Default) Skip with a warning.
+            - ``error``: Record a ``special_file`` error for each entry.
+            - ``attempt_copy``: Unix only, best effort: recreate FIFOs and device
+              nodes with ``mknod`` (device nodes usually need root). Sockets are
+              still skipped.
+        rule_conflict_symlink_dst:
+            Policy for an existing symlink at a destination entry path.
+            See :class:`CopyDstSymlinkConflictMode`.
+            - ``error``: (Default) Record an ``unsafe_destination`` error.
+            - ``replace``: Remove the symlink (never its target) before the
+              conflict rules run and write the entry fresh. Symlinks among parent
+              components still error; a dry run removes nothing.
+        filename_normalization:
+            ``nfc`` or ``nfd``. Normalize destination file and directory names to
+            this Unicode form (e.g. NFD names from macOS become NFC on Linux);
+            source paths are read unchanged. Each renamed entry records a warning,
+            and names that collapse to the same result fall under the conflict
+            rules.
+        should_collect_ext_stats:
+            Record ``(count, bytes)`` of copied files per lowercased file
+            extension in ``CopyReport.stats_by_ext``. Files without an extension
+            are counted under ``""``.
+        should_dedup_destination:
+            Hard-link a destination file to an earlier copied file with the same
+            size, BLAKE3 content hash and permissions instead of copying it
+            (``cnt_linked``). Every source file is read once more for hashing.
+            A linked file is the same inode as the first copy, so it shows that
+            copy's mtime, owner and xattrs. Linked files do not count against
+            ``bytes_budget_max``. Falls back to copying when linking fails.
+        should_force_overwrite_readonly:
+            Before overwriting an existing read-only destination file, clear its
+            read-only attribute (Windows) or add owner write permission (Unix).
+            A failure to do so is recorded as an error for that file only.
+        mtime_tolerance_ms:
+            With ``should_copy_incrementally``, treat source and destination mtimes
+            within this many milliseconds as equal. ``0`` (Default) requires exact
+            equality; use ``2000`` when either side is FAT/exFAT and ``1000`` for
+            HFS+ or SMB/NFS mounts with second precision.
+        should_sanitize_dest_names:
+            Make destination names valid on Windows: ``<>:"/\\|?*``, control
+            characters and trailing dots/spaces become ``_``, and reserved device
+            names (``CON``, ``PRN``, ``AUX``, ``NUL``, ``COM1``-``COM9``,
+            ``LPT1``-``LPT9``) get a ``_`` suffix on the stem. Applied after
+            ``filename_normalization``; each renamed entry records a warning.
+        should_fail_fast:
+            Stop at the first per-entry error and raise ``OSError`` instead of
+            returning a report. Copies committed before the error stay on disk.
 
     Raises:
         ValueError:
             If ``depth_limit`` is invalid, ``rule_depth_limit`` is ``exact`` without
-            ``depth_limit``, or any enum-like rule value is invalid.
+            ``depth_limit``, ``copy_buffer_bytes``,
+            ``throughput_max_bytes_per_sec``, ``bytes_budget_max``, ``files_max`` or
+            ``mtime_tolerance_ms`` is invalid, ``mtime_after``/``mtime_before`` is
+            out of range for the platform clock, or any enum-like value (rules,
+            ``manifest_algorithm``, ``filename_normalization``) is invalid.
         NotADirectoryError:
             If ``dir_source`` is not a directory.
+        OSError:
+            If ``should_fail_fast`` is set and any entry fails.
         RuntimeError:
             If Rust backend is unavailable.
 
@@ -129,12 +298,36 @@ def copy_tree(
     enum_rule_conflict_dir = normalize_copy_dir_conflict_mode(rule_conflict_dir)
     enum_rule_symlink = normalize_copy_symlink_mode(rule_symlink)
     enum_rule_depth_limit = normalize_copy_depth_mode(rule_depth_limit)
+    enum_rule_special_file = normalize_copy_special_file_mode(rule_special_file)
+    enum_rule_conflict_symlink_dst = normalize_copy_dst_symlink_conflict_mode(
+        rule_conflict_symlink_dst
+    )
+    enum_filename_normalization = (
+        None
+        if filename_normalization is None
+        else normalize_copy_unicode_form(filename_normalization)
+    )
+    enum_manifest_algorithm = (
+        None
+        if manifest_algorithm is None
+        else normalize_copy_hash_algorithm(manifest_algorithm)
+    )
 
     if depth_limit is None:
         if enum_rule_depth_limit is CopyDepthLimitMode.EXACT:
             raise ValueError("`depth_limit` is required when depth_mode='exact'.")
     elif depth_limit < 1:
         raise ValueError("Arg `depth_limit` must be >= 1 or None.")
+    if copy_buffer_bytes is not None and copy_buffer_bytes < 1:
+        raise ValueError("Arg `copy_buffer_bytes` must be >= 1 or None.")
+    if throughput_max_bytes_per_sec is not None and throughput_max_bytes_per_sec < 1:
+        raise ValueError("Arg `throughput_max_bytes_per_sec` must be >= 1 or None.")
+    if bytes_budget_max is not None and bytes_budget_max < 0:
+        raise ValueError("Arg `bytes_budget_max` must be >= 0 or None.")
+    if files_max is not None and files_max < 0:
+        raise ValueError("Arg `files_max` must be >= 0 or None.")
+    if mtime_tolerance_ms < 0:
+        raise ValueError("Arg `mtime_tolerance_ms` must be >= 0.")
 
     if not is_rs_backend_available():
         raise RuntimeError(
@@ -157,4 +350,131 @@ def copy_tree(
         workers_max=workers_max,
         should_keep_tree=should_keep_tree,
         should_dry_run=should_dry_run,
+        mtime_after=mtime_after,
+        mtime_before=mtime_before,
+        should_match_full_path=should_match_full_path,
+        symlink_follow_max=symlink_follow_max,
+        should_preserve_ownership=should_preserve_ownership,
+        should_preserve_dir_metadata=should_preserve_dir_metadata,
+        should_copy_atomically=should_copy_atomically,
+        should_fsync=should_fsync,
+        copy_buffer_bytes=copy_buffer_bytes,
+        should_preserve_sparse=should_preserve_sparse,
+        throughput_max_bytes_per_sec=throughput_max_bytes_per_sec,
+        should_skip_hidden=should_skip_hidden,
+        should_preserve_acl=should_preserve_acl,
+        should_rewrite_symlinks_relative=should_rewrite_symlinks_relative,
+        should_collect_plan=should_collect_plan,
+        should_verify=should_verify,
+        manifest_algorithm=enum_manifest_algorithm,
+        should_copy_incrementally=should_copy_incrementally,
+        bytes_budget_max=bytes_budget_max,
+        files_max=files_max,
+        rule_special_file=enum_rule_special_file,
+        rule_conflict_symlink_dst=enum_rule_conflict_symlink_dst,
+        filename_normalization=enum_filename_normalization,
+        should_collect_ext_stats=should_collect_ext_stats,
+        should_dedup_destination=should_dedup_destination,
+        should_force_overwrite_readonly=should_force_overwrite_readonly,
+        mtime_tolerance_ms=mtime_tolerance_ms,
+        should_sanitize_dest_names=should_sanitize_dest_names,
+        should_fail_fast=should_fail_fast,
+    )
+
+
+def copy_entry(
+    file_source: os.PathLike[str] | str,
+    dir_destination: os.PathLike[str] | str,
+    *,
+    rule_conflict_file: CopyFileConflictMode | str = "skip",
+    rule_symlink: CopySymlinkMode | str = "copy_symlinks",
+    should_dry_run: bool = False,
+    should_preserve_ownership: bool = False,
+    should_copy_atomically: bool = False,
+    should_fsync: bool = False,
+    copy_buffer_bytes: int | None = None,
+    should_preserve_sparse: bool = False,
+    throughput_max_bytes_per_sec: int | None = None,
+    should_preserve_acl: bool = False,
+    should_verify: bool = False,
+    manifest_algorithm: CopyHashAlgorithm | str | None = None,
+    should_copy_incrementally: bool = False,
+    rule_special_file: CopySpecialFileMode | str = "skip",
+    rule_conflict_symlink_dst: CopyDstSymlinkConflictMode | str = "error",
+    filename_normalization: CopyUnicodeForm | str | None = None,
+) -> CopyReport:
+    """Copy a single file into a directory.
+
+    The file lands at ``dir_destination / file_source.name``; the directory is
+    created when missing. Conflict, symlink and safety handling, metadata
+    preservation, verification and manifest behave exactly as in
+    :func:`copy_tree`, whose docstring describes each argument.
+
+    Raises:
+        ValueError:
+            If ``copy_buffer_bytes`` or ``throughput_max_bytes_per_sec`` is
+            invalid, any enum-like value is invalid, or ``dir_destination`` is
+            the directory that already contains ``file_source``.
+        FileNotFoundError:
+            If ``file_source`` does not exist.
+        IsADirectoryError:
+            If ``file_source`` is a directory.
+        RuntimeError:
+            If Rust backend is unavailable.
+
+    Returns:
+        CopyReport: Summary of the copy operation.
+
+    Examples:
+        >>> from pathlib import Path
+        >>> report = copy_entry(Path("data/raw/a.csv"), Path("data/processed"))
+        >>> report.cnt_copied
+        1
+    """
+    enum_rule_conflict_file = normalize_copy_file_conflict_mode(rule_conflict_file)
+    enum_rule_symlink = normalize_copy_symlink_mode(rule_symlink)
+    enum_rule_special_file = normalize_copy_special_file_mode(rule_special_file)
+    enum_rule_conflict_symlink_dst = normalize_copy_dst_symlink_conflict_mode(
+        rule_conflict_symlink_dst
+    )
+    enum_filename_normalization = (
+        None
+        if filename_normalization is None
+        else normalize_copy_unicode_form(filename_normalization)
+    )
+    enum_manifest_algorithm = (
+        None
+        if manifest_algorithm is None
+        else normalize_copy_hash_algorithm(manifest_algorithm)
+    )
+
+    if copy_buffer_bytes is not None and copy_buffer_bytes < 1:
+        raise ValueError("Arg `copy_buffer_bytes` must be >= 1 or None.")
+    if throughput_max_bytes_per_sec is not None and throughput_max_bytes_per_sec < 1:
+        raise ValueError("Arg `throughput_max_bytes_per_sec` must be >= 1 or None.")
+
+    if not is_rs_backend_available():
+        raise RuntimeError(
+            "Rust fs backend is unavailable. Build/install `_axiomkit_io_fs_rs` first."
+        )
+
+    return copy_entry_via_rs(
+        Path(file_source),
+        Path(dir_destination),
+        rule_conflict_file=enum_rule_conflict_file,
+        rule_symlink=enum_rule_symlink,
+        should_dry_run=should_dry_run,
+        should_preserve_ownership=should_preserve_ownership,
+        should_copy_atomically=should_copy_atomically,
+        should_fsync=should_fsync,
+        copy_buffer_bytes=copy_buffer_bytes,
+        should_preserve_sparse=should_preserve_sparse,
+        throughput_max_bytes_per_sec=throughput_max_bytes_per_sec,
+        should_preserve_acl=should_preserve_acl,
+        should_verify=should_verify,
+        manifest_algorithm=enum_manifest_algorithm,
+        should_copy_incrementally=should_copy_incrementally,
+        rule_special_file=enum_rule_special_file,
+        rule_conflict_symlink_dst=enum_rule_conflict_symlink_dst,
+        filename_normalization=enum_filename_normalization,
     )
@@ -1,6 +1,8 @@
-from dataclasses import dataclass
+from dataclasses import dataclass, field
 
-from .spec import CopyErrorRecord
+from pathlib import Path
+
+from .spec import CopyErrorRecord, CopyPlanEntry
 
 
 @dataclass(frozen=True, slots=True)
@@ -25,6 +27,20 @@ class CopyReport:
         cnt_skipped:
             Number of entries that were intentionally not copied
             (for example due to conflict resolution strategy, filters, or patterns).
+        cnt_verified:
+            Number of copied files whose content hash was verified
+            (only with ``should_verify``).
+        cnt_unchanged:
+            Number of files left alone by ``should_copy_incrementally`` because the
+            destination already had the same size and mtime.
+        cnt_linked:
+            Number of files hard-linked to an identical, already copied destination
+            file by ``should_dedup_destination`` instead of being copied.
+        cnt_symlinks:
+            Number of symlinks recreated under ``rule_symlink="copy_symlinks"``;
+            these are also included in ``cnt_copied``.
+        bytes_copied:
+            Total number of source bytes written by successful file copies.
         errors:
             Tuple of :class:`CopyErrorRecord` instances describing failures
             that occurred while attempting to copy specific paths.
@@ -33,14 +49,32 @@ class CopyReport:
             Tuple of warning messages (as strings) produced during the copy operation.
             These typically indicate non-fatal issues or
             noteworthy conditions that did not prevent the operation from continuing.
+        plan:
+            Tuple of :class:`CopyPlanEntry` instances describing what a dry run
+            would do. Only filled when ``should_collect_plan`` is set.
+        manifest:
+            Tuple of ``(destination path, hex digest)`` pairs of copied files.
+            Only filled when ``manifest_algorithm`` is set.
+        stats_by_ext:
+            Mapping of lowercased file extension (``""`` for none) to
+            ``(count, bytes)`` of copied files.
+            Only filled when ``should_collect_ext_stats`` is set.
     """
 
     cnt_matched: int
     cnt_scanned: int = 0
     cnt_copied: int = 0
     cnt_skipped: int = 0
+    cnt_verified: int = 0
+    cnt_unchanged: int = 0
+    cnt_linked: int = 0
+    cnt_symlinks: int = 0
+    bytes_copied: int = 0
     errors: tuple[CopyErrorRecord, ...] = ()
     warnings: tuple[str, ...] = ()
+    plan: tuple[CopyPlanEntry, ...] = ()
+    manifest: tuple[tuple[Path, str], ...] = ()
+    stats_by_ext: dict[str, tuple[int, int]] = field(default_factory=dict)
 
     @property
     def error_count(self) -> int:
@@ -57,6 +91,11 @@ class CopyReport:
             "cnt_scanned": self.cnt_scanned,
             "cnt_copied": self.cnt_copied,
             "cnt_skipped": self.cnt_skipped,
+            "cnt_verified": self.cnt_verified,
+            "cnt_unchanged": self.cnt_unchanged,
+            "cnt_linked": self.cnt_linked,
+            "cnt_symlinks": self.cnt_symlinks,
+            "bytes_copied": self.bytes_copied,
             "cnt_errors": self.error_count,
             "cnt_warnings": self.warning_count,
         }
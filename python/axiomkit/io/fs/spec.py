@@ -14,6 +14,12 @@ class CopySymlinkMode(StrEnum):
     SKIP_SYMLINKS = "skip_symlinks"
 
 
+class CopySpecialFileMode(StrEnum):
+    SKIP = "skip"
+    ERROR = "error"
+    ATTEMPT_COPY = "attempt_copy"
+
+
 class CopyFileConflictMode(StrEnum):
     SKIP = "skip"
     OVERWRITE = "overwrite"
@@ -26,10 +32,46 @@ class CopyDirectoryConflictMode(StrEnum):
     ERROR = "error"
 
 
+class CopyDstSymlinkConflictMode(StrEnum):
+    ERROR = "error"
+    REPLACE = "replace"
+
+
 class CopyPatternMode(StrEnum):
     GLOB = "glob"
     REGEX = "regex"
     LITERAL = "literal"
+    GITIGNORE = "gitignore"
+
+
+class CopyErrorKind(StrEnum):
+    IO_COPY_FAILED = "io_copy_failed"
+    UNSAFE_DESTINATION = "unsafe_destination"
+    BROKEN_SYMLINK = "broken_symlink"
+    VERIFY_MISMATCH = "verify_mismatch"
+    CONFLICT_ERROR = "conflict_error"
+    METADATA_FAILED = "metadata_failed"
+    CREATE_DIR_FAILED = "create_dir_failed"
+    SYMLINK_FAILED = "symlink_failed"
+    SPECIAL_FILE = "special_file"
+
+
+class CopyHashAlgorithm(StrEnum):
+    SHA256 = "sha256"
+    BLAKE3 = "blake3"
+
+
+class CopyUnicodeForm(StrEnum):
+    NFC = "nfc"
+    NFD = "nfd"
+
+
+class CopyPlanAction(StrEnum):
+    COPY_FILE = "copy_file"
+    CREATE_DIR = "create_dir"
+    CREATE_SYMLINK = "create_symlink"
+    CREATE_SPECIAL_FILE = "create_special_file"
+    SKIP_CONFLICT = "skip_conflict"
 
 
 class CopyDepthLimitMode(StrEnum):
@@ -41,6 +83,15 @@ class CopyDepthLimitMode(StrEnum):
 class CopyErrorRecord:
     path: Path
     exception: Exception
+    kind: CopyErrorKind | None = None
+    source_path: Path | None = None
+
+
+@dataclass(frozen=True, slots=True)
+class CopyPlanEntry:
+    src_path: Path
+    dst_path: Path
+    action: CopyPlanAction
 
 
 @dataclass(frozen=True, slots=True)
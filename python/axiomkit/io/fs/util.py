@@ -1,9 +1,13 @@
 from .spec import (
     CopyDepthLimitMode,
     CopyDirectoryConflictMode,
+    CopyDstSymlinkConflictMode,
     CopyFileConflictMode,
+    CopyHashAlgorithm,
     CopyPatternMode,
+    CopySpecialFileMode,
     CopySymlinkMode,
+    CopyUnicodeForm,
 )
 
 ################################################################################
@@ -40,6 +44,21 @@ def normalize_copy_dir_conflict_mode(
         ) from e
 
 
+def normalize_copy_dst_symlink_conflict_mode(
+    value: CopyDstSymlinkConflictMode | str,
+) -> CopyDstSymlinkConflictMode:
+    """Validate and normalize a destination symlink conflict strategy."""
+    if isinstance(value, CopyDstSymlinkConflictMode):
+        return value
+    try:
+        return CopyDstSymlinkConflictMode(value)
+    except ValueError as e:
+        raise ValueError(
+            f"Invalid destination symlink conflict strategy: `{value}`. "
+            f"Expected one of: {[s.value for s in CopyDstSymlinkConflictMode]}"
+        ) from e
+
+
 def normalize_copy_file_conflict_mode(
     value: CopyFileConflictMode | str,
 ) -> CopyFileConflictMode:
@@ -55,6 +74,21 @@ def normalize_copy_file_conflict_mode(
         ) from e
 
 
+def normalize_copy_hash_algorithm(
+    value: CopyHashAlgorithm | str,
+) -> CopyHashAlgorithm:
+    """Validate and normalize a manifest digest algorithm."""
+    if isinstance(value, CopyHashAlgorithm):
+        return value
+    try:
+        return CopyHashAlgorithm(value)
+    except ValueError as e:
+        raise ValueError(
+            f"Invalid hash algorithm: `{value}`. "
+            f"Expected one of: {[s.value for s in CopyHashAlgorithm]}"
+        ) from e
+
+
 def normalize_copy_pattern_mode(
     value: CopyPatternMode | str,
 ) -> CopyPatternMode:
@@ -70,6 +104,21 @@ def normalize_copy_pattern_mode(
         ) from e
 
 
+def normalize_copy_special_file_mode(
+    value: CopySpecialFileMode | str,
+) -> CopySpecialFileMode:
+    """Validate and normalize a special file handling strategy."""
+    if isinstance(value, CopySpecialFileMode):
+        return value
+    try:
+        return CopySpecialFileMode(value)
+    except ValueError as e:
+        raise ValueError(
+            f"Invalid special file strategy: `{value}`. "
+            f"Expected one of: {[s.value for s in CopySpecialFileMode]}"
+        ) from e
+
+
 def normalize_copy_symlink_mode(
     value: CopySymlinkMode | str,
 ) -> CopySymlinkMode:
@@ -85,5 +134,20 @@ def normalize_copy_symlink_mode(
         ) from e
 
 
+def normalize_copy_unicode_form(
+    value: CopyUnicodeForm | str,
+) -> CopyUnicodeForm:
+    """Validate and normalize a filename Unicode normalization form."""
+    if isinstance(value, CopyUnicodeForm):
+        return value
+    try:
+        return CopyUnicodeForm(value)
+    except ValueError as e:
+        raise ValueError(
+            f"Invalid Unicode form: `{value}`. "
+            f"Expected one of: {[s.value for s in CopyUnicodeForm]}"
+        ) from e
+
+
 # #endregion
 ################################################################################
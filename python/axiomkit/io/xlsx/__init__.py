@@ -8,16 +8,36 @@ __all__ = [
     "XlsxWriter",
     "CellFormatPatch",
     "AutofitPolicy",
+    "ConditionalFormatRule",
+    "ImagePlacement",
+    "PageSetup",
+    "RowGroup",
     "ScientificPolicy",
 ]
 
 if TYPE_CHECKING:
-    from .spec import AutofitPolicy, CellFormatPatch, ScientificPolicy
+    from .spec import (
+        AutofitPolicy,
+        CellFormatPatch,
+        ConditionalFormatRule,
+        ImagePlacement,
+        PageSetup,
+        RowGroup,
+        ScientificPolicy,
+    )
     from .writer import XlsxWriter
 
 
 def __getattr__(name: str) -> Any:
-    if name in {"CellFormatPatch", "AutofitPolicy", "ScientificPolicy"}:
+    if name in {
+        "CellFormatPatch",
+        "AutofitPolicy",
+        "ConditionalFormatRule",
+        "ImagePlacement",
+        "PageSetup",
+        "RowGroup",
+        "ScientificPolicy",
+    }:
         return import_optional_attr(
             module_name=".spec",
             attr_name=name,
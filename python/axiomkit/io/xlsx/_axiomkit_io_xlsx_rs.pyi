@@ -1,9 +1,19 @@
 from __future__ import annotations
 
-from collections.abc import Sequence
+import os
+from collections.abc import Mapping, Sequence
 from typing import Any, Literal
 
-from .spec import AutofitPolicy, ScientificPolicy, XlsxReport
+from .spec import (
+    AutofitPolicy,
+    CellFormatPatch,
+    ConditionalFormatRule,
+    ImagePlacement,
+    PageSetup,
+    RowGroup,
+    ScientificPolicy,
+    XlsxReport,
+)
 
 __bridge_abi__: int
 __bridge_contract__: str
@@ -28,6 +38,7 @@ class XlsxWriter:
         fmt_scientific: Any = ...,
         fmt_header: Any = ...,
         options_write: Any = ...,
+        doc_properties: Mapping[str, str] | None = ...,
     ) -> None: ...
     def __enter__(self) -> XlsxWriter: ...
     def __exit__(
@@ -37,6 +48,8 @@ class XlsxWriter:
         tb: Any | None,
     ) -> None: ...
     def close(self) -> None: ...
+    def reopen(self) -> XlsxWriter: ...
+    def to_bytes(self) -> bytes: ...
     def report(self) -> tuple[XlsxReport, ...]: ...
     def write_sheet(
         self,
@@ -46,9 +59,41 @@ class XlsxWriter:
         header: Any | None = ...,
         cols_integer: Sequence[str | int] | str | int | None = ...,
         cols_decimal: Sequence[str | int] | str | int | Literal[False] | None = ...,
+        cols_hyperlink: Sequence[str | int] | str | int | None = ...,
+        cols_hyperlink_text: Mapping[str, str] | None = ...,
+        cols_comment_text: Mapping[str, str] | None = ...,
         num_frozen_cols: int = ...,
         num_frozen_rows: int | None = ...,
+        num_reserved_top_rows: int = ...,
         should_merge_header: bool = ...,
+        should_merge_header_vertical: bool = ...,
+        should_autofilter: bool = ...,
+        should_write_table: bool = ...,
+        table_style: str | None = ...,
+        zoom: int | None = ...,
+        should_hide_gridlines: bool = ...,
+        tab_color: str | None = ...,
+        page_setup: PageSetup | None = ...,
+        conditional_formats: Sequence[ConditionalFormatRule] | None = ...,
+        should_band_rows: bool = ...,
+        band_color: str | None = ...,
+        cols_dropdown: Mapping[str, Sequence[str]] | None = ...,
+        cols_width: Mapping[str, float] | None = ...,
+        cols_fmt_overrides: Mapping[str, CellFormatPatch] | None = ...,
+        cols_hidden: Sequence[str | int] | str | int | None = ...,
+        cols_currency: Sequence[str | int] | str | int | None = ...,
+        currency_symbol: str | None = ...,
+        currency_decimals: int | None = ...,
+        cols_percent: Sequence[str | int] | str | int | None = ...,
+        percent_decimals: int | None = ...,
+        summary_row: Mapping[str, Literal["sum", "mean", "count"]] | None = ...,
+        should_use_summary_formulas: bool = ...,
+        rich_text_cells: (
+            Mapping[tuple[int, int], Sequence[tuple[CellFormatPatch, str]]] | None
+        ) = ...,
+        formula_cells: Mapping[tuple[int, int], str] | None = ...,
+        images: Sequence[ImagePlacement] | None = ...,
+        row_groups: Sequence[RowGroup] | None = ...,
         should_keep_missing_values: bool | None = ...,
         policy_autofit: AutofitPolicy | None = ...,
         policy_scientific: ScientificPolicy | None = ...,
@@ -62,9 +107,41 @@ class XlsxWriter:
         header: Any | None = ...,
         cols_integer: Sequence[str | int] | str | int | None = ...,
         cols_decimal: Sequence[str | int] | str | int | Literal[False] | None = ...,
+        cols_hyperlink: Sequence[str | int] | str | int | None = ...,
+        cols_hyperlink_text: Mapping[str, str] | None = ...,
+        cols_comment_text: Mapping[str, str] | None = ...,
         num_frozen_cols: int = ...,
         num_frozen_rows: int | None = ...,
+        num_reserved_top_rows: int = ...,
         should_merge_header: bool = ...,
+        should_merge_header_vertical: bool = ...,
+        should_autofilter: bool = ...,
+        should_write_table: bool = ...,
+        table_style: str | None = ...,
+        zoom: int | None = ...,
+        should_hide_gridlines: bool = ...,
+        tab_color: str | None = ...,
+        page_setup: PageSetup | None = ...,
+        conditional_formats: Sequence[ConditionalFormatRule] | None = ...,
+        should_band_rows: bool = ...,
+        band_color: str | None = ...,
+        cols_dropdown: Mapping[str, Sequence[str]] | None = ...,
+        cols_width: Mapping[str, float] | None = ...,
+        cols_fmt_overrides: Mapping[str, CellFormatPatch] | None = ...,
+        cols_hidden: Sequence[str | int] | str | int | None = ...,
+        cols_currency: Sequence[str | int] | str | int | None = ...,
+        currency_symbol: str | None = ...,
+        currency_decimals: int | None = ...,
+        cols_percent: Sequence[str | int] | str | int | None = ...,
+        percent_decimals: int | None = ...,
+        summary_row: Mapping[str, Literal["sum", "mean", "count"]] | None = ...,
+        should_use_summary_formulas: bool = ...,
+        rich_text_cells: (
+            Mapping[tuple[int, int], Sequence[tuple[CellFormatPatch, str]]] | None
+        ) = ...,
+        formula_cells: Mapping[tuple[int, int], str] | None = ...,
+        images: Sequence[ImagePlacement] | None = ...,
+        row_groups: Sequence[RowGroup] | None = ...,
         should_keep_missing_values: bool | None = ...,
         policy_autofit: AutofitPolicy | None = ...,
         policy_scientific: ScientificPolicy | None = ...,
@@ -78,13 +155,54 @@ class XlsxWriter:
         header: Any | None = ...,
         cols_integer: Sequence[str | int] | str | int | None = ...,
         cols_decimal: Sequence[str | int] | str | int | Literal[False] | None = ...,
+        cols_hyperlink: Sequence[str | int] | str | int | None = ...,
+        cols_hyperlink_text: Mapping[str, str] | None = ...,
+        cols_comment_text: Mapping[str, str] | None = ...,
         num_frozen_cols: int = ...,
         num_frozen_rows: int | None = ...,
+        num_reserved_top_rows: int = ...,
         should_merge_header: bool = ...,
+        should_merge_header_vertical: bool = ...,
+        should_autofilter: bool = ...,
+        should_write_table: bool = ...,
+        table_style: str | None = ...,
+        zoom: int | None = ...,
+        should_hide_gridlines: bool = ...,
+        tab_color: str | None = ...,
+        page_setup: PageSetup | None = ...,
+        conditional_formats: Sequence[ConditionalFormatRule] | None = ...,
+        should_band_rows: bool = ...,
+        band_color: str | None = ...,
+        cols_dropdown: Mapping[str, Sequence[str]] | None = ...,
+        cols_width: Mapping[str, float] | None = ...,
+        cols_fmt_overrides: Mapping[str, CellFormatPatch] | None = ...,
+        cols_hidden: Sequence[str | int] | str | int | None = ...,
+        cols_currency: Sequence[str | int] | str | int | None = ...,
+        currency_symbol: str | None = ...,
+        currency_decimals: int | None = ...,
+        cols_percent: Sequence[str | int] | str | int | None = ...,
+        percent_decimals: int | None = ...,
+        summary_row: Mapping[str, Literal["sum", "mean", "count"]] | None = ...,
+        should_use_summary_formulas: bool = ...,
+        rich_text_cells: (
+            Mapping[tuple[int, int], Sequence[tuple[CellFormatPatch, str]]] | None
+        ) = ...,
+        formula_cells: Mapping[tuple[int, int], str] | None = ...,
+        images: Sequence[ImagePlacement] | None = ...,
+        row_groups: Sequence[RowGroup] | None = ...,
         should_keep_missing_values: bool | None = ...,
         policy_autofit: AutofitPolicy | None = ...,
         policy_scientific: ScientificPolicy | None = ...,
         schema_body: Any | None = ...,
     ) -> XlsxWriter: ...
+    def insert_image(
+        self,
+        sheet_name: str,
+        row: int,
+        col: int,
+        source: str | os.PathLike[str] | bytes,
+        x_offset: int = ...,
+        y_offset: int = ...,
+    ) -> XlsxWriter: ...
 
 def _profile_arrow_drain(source: Any) -> XlsxArrowDrainProfile: ...
@@ -75,6 +75,7 @@ def create_xlsx_writer_via_rs(
     fmt_scientific: Any = None,
     fmt_header: Any = None,
     options_write: Any = None,
+    doc_properties: Any = None,
 ):
     if _XlsxWriterRs is None:  # pragma: no cover
         _raise_unavailable()
@@ -87,4 +88,5 @@ def create_xlsx_writer_via_rs(
             fmt_scientific=fmt_scientific,
             fmt_header=fmt_header,
             options_write=options_write,
+            doc_properties=doc_properties,
         )
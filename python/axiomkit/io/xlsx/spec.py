@@ -1,3 +1,4 @@
+import os
 from dataclasses import dataclass, field, replace
 from typing import Any, Literal, Self, cast
 
@@ -7,6 +8,13 @@ class _MissingType:
 
 
 _FORMAT_UNSET = _MissingType()
+# Fields whose XlsxWriter property key differs from the field name.
+_XLSXWRITER_KEYS_BY_FIELD = {
+    "strikethrough": "font_strikeout",
+    "shrink_to_fit": "shrink",
+    "diagonal": "diag_border",
+    "diagonal_type": "diag_type",
+}
 
 
 ################################################################################
@@ -18,19 +26,28 @@ class CellFormatPatch:
     font_size: int | None = None
     bold: bool | None = None
     italic: bool | None = None
+    underline: bool | None = None
+    strikethrough: bool | None = None
 
     align: str | None = None
     valign: str | None = None
     border: int | None = None
     text_wrap: bool | None = None
+    shrink_to_fit: bool | None = None
+    indent: int | None = None  # clamped to 0..15
+    rotation: int | None = None  # -90..90, or 270 for vertical text
 
     top: int | None = None
     bottom: int | None = None
     left: int | None = None
     right: int | None = None
+    diagonal: int | None = None
+    diagonal_type: int | None = None  # 1 up, 2 down, 3 both
 
     num_format: str | None = None
     bg_color: str | None = None
+    fg_color: str | None = None
+    pattern: int | None = None  # 0..18 (else ignored), 1 = solid; no gradient fills
     font_color: str | None = None
 
     def with_(
@@ -40,16 +57,25 @@ class CellFormatPatch:
         font_size: int | None = cast(Any, _FORMAT_UNSET),
         bold: bool | None = cast(Any, _FORMAT_UNSET),
         italic: bool | None = cast(Any, _FORMAT_UNSET),
+        underline: bool | None = cast(Any, _FORMAT_UNSET),
+        strikethrough: bool | None = cast(Any, _FORMAT_UNSET),
         align: str | None = cast(Any, _FORMAT_UNSET),
         valign: str | None = cast(Any, _FORMAT_UNSET),
         border: int | None = cast(Any, _FORMAT_UNSET),
         text_wrap: bool | None = cast(Any, _FORMAT_UNSET),
+        shrink_to_fit: bool | None = cast(Any, _FORMAT_UNSET),
+        indent: int | None = cast(Any, _FORMAT_UNSET),
+        rotation: int | None = cast(Any, _FORMAT_UNSET),
         top: int | None = cast(Any, _FORMAT_UNSET),
         bottom: int | None = cast(Any, _FORMAT_UNSET),
         left: int | None = cast(Any, _FORMAT_UNSET),
         right: int | None = cast(Any, _FORMAT_UNSET),
+        diagonal: int | None = cast(Any, _FORMAT_UNSET),
+        diagonal_type: int | None = cast(Any, _FORMAT_UNSET),
         num_format: str | None = cast(Any, _FORMAT_UNSET),
         bg_color: str | None = cast(Any, _FORMAT_UNSET),
+        fg_color: str | None = cast(Any, _FORMAT_UNSET),
+        pattern: int | None = cast(Any, _FORMAT_UNSET),
         font_color: str | None = cast(Any, _FORMAT_UNSET),
     ) -> Self:
         data: dict[str, Any] = {}
@@ -61,6 +87,10 @@ class CellFormatPatch:
             data["bold"] = bold
         if italic is not _FORMAT_UNSET:
             data["italic"] = italic
+        if underline is not _FORMAT_UNSET:
+            data["underline"] = underline
+        if strikethrough is not _FORMAT_UNSET:
+            data["strikethrough"] = strikethrough
         if align is not _FORMAT_UNSET:
             data["align"] = align
         if valign is not _FORMAT_UNSET:
@@ -69,6 +99,12 @@ class CellFormatPatch:
             data["border"] = border
         if text_wrap is not _FORMAT_UNSET:
             data["text_wrap"] = text_wrap
+        if shrink_to_fit is not _FORMAT_UNSET:
+            data["shrink_to_fit"] = shrink_to_fit
+        if indent is not _FORMAT_UNSET:
+            data["indent"] = indent
+        if rotation is not _FORMAT_UNSET:
+            data["rotation"] = rotation
         if top is not _FORMAT_UNSET:
             data["top"] = top
         if bottom is not _FORMAT_UNSET:
@@ -77,10 +113,18 @@ class CellFormatPatch:
             data["left"] = left
         if right is not _FORMAT_UNSET:
             data["right"] = right
+        if diagonal is not _FORMAT_UNSET:
+            data["diagonal"] = diagonal
+        if diagonal_type is not _FORMAT_UNSET:
+            data["diagonal_type"] = diagonal_type
         if num_format is not _FORMAT_UNSET:
             data["num_format"] = num_format
         if bg_color is not _FORMAT_UNSET:
             data["bg_color"] = bg_color
+        if fg_color is not _FORMAT_UNSET:
+            data["fg_color"] = fg_color
+        if pattern is not _FORMAT_UNSET:
+            data["pattern"] = pattern
         if font_color is not _FORMAT_UNSET:
             data["font_color"] = font_color
         return replace(self, **data)
@@ -99,7 +143,7 @@ class CellFormatPatch:
 
     def to_xlsxwriter(self) -> dict[str, Any]:
         return {
-            _k: getattr(self, _k)
+            _XLSXWRITER_KEYS_BY_FIELD.get(_k, _k): getattr(self, _k)
             for _k in self.__dataclass_fields__
             if getattr(self, _k) is not None
         }
@@ -125,6 +169,10 @@ class XlsxValuePolicy:
     posinf_str: str = "Inf"
     neginf_str: str = "-Inf"
     integer_coerce: Literal["coerce", "strict"] = "strict"
+    datetime_tz: str | None = None
+    should_write_native_booleans: bool = False
+    bool_true_str: str = "True"
+    bool_false_str: str = "False"
 
 
 @dataclass(frozen=True, slots=True)
@@ -151,6 +199,17 @@ class XlsxWriteOptions:
             border=0, top=0, bottom=0, left=0, right=0
         )
     )
+    date_num_format: str = "yyyy-mm-dd"
+    datetime_num_format: str = "yyyy-mm-dd hh:mm:ss"
+    # part order for tables split across sheets; single-pass writes are always rows-first
+    split_mode: Literal["columns_first", "rows_first"] = "columns_first"
+    # split part names, e.g. "{base} (part {n})"; must contain "{n}"; None keeps "base_N"
+    sheet_split_template: str | None = None
+    # integer columns use "#,##0" instead of the plain "0" preset; overrides are untouched
+    should_use_integer_thousands_sep: bool = False
+    # baseline sheet layout; autofit and cols_width still override the column width
+    default_col_width: float | None = None
+    default_row_height: float | None = None
 
 
 @dataclass(frozen=True, slots=True)
@@ -158,6 +217,7 @@ class ScientificPolicy:
     scope: Literal["none", "decimal", "integer", "all"] = "none"
     thr_min: float = 0.0001
     thr_max: float = 1_000_000_000_000.0
+    precision: int = 2  # mantissa decimals, 0..=20
 
 
 @dataclass(frozen=True, slots=True)
@@ -169,6 +229,16 @@ class AutofitPolicy:
     width_cell_padding: int = 2
 
 
+@dataclass(frozen=True, slots=True)
+class ConditionalFormatRule:
+    col: str
+    kind: Literal["color_scale_3", "data_bar"] = "color_scale_3"
+    color_min: str = "#F8696B"
+    color_mid: str = "#FFEB84"
+    color_max: str = "#63BE7B"
+    color_bar: str = "#638EC6"
+
+
 # #endregion
 ################################################################################
 # #region SheetFormatSpecification
@@ -181,6 +251,34 @@ class SheetSlice:
     col_end_exclusive: int  # exclusive in source df cols
 
 
+@dataclass(frozen=True, slots=True)
+class ImagePlacement:
+    row: int  # zero-based sheet row, not body row
+    col: int
+    source: str | os.PathLike[str] | bytes
+    x_offset: int = 0  # pixels
+    y_offset: int = 0  # pixels
+
+
+@dataclass(frozen=True, slots=True)
+class RowGroup:
+    row_start: int  # zero-based body row, inclusive
+    row_end: int  # inclusive
+    level: int = 1  # outline level in 1..=7
+
+
+@dataclass(frozen=True, slots=True)
+class PageSetup:
+    should_use_landscape: bool = False
+    paper_size: int | None = None  # Excel paper index, e.g. 9 = A4
+    margins: tuple[float, float, float, float] | None = None  # inches: left, right, top, bottom
+    # inclusive body rows / data columns: row_start, col_start, row_end, col_end
+    print_area: tuple[int, int, int, int] | None = None
+    should_repeat_header_on_print: bool = False
+    header_text: str | None = None  # Excel field codes: &P page, &N pages, &D date
+    footer_text: str | None = None
+
+
 @dataclass(frozen=True, slots=True)
 class SheetHorizontalMerge:
     row_idx_start: int
@@ -196,6 +294,13 @@ class SheetHorizontalMerge:
 class XlsxReport:
     sheets: list[SheetSlice]
     warnings: list[str]
+    # columns written as numbers, using the integer format, and eligible for scientific format
+    cols_numeric: list[str] = field(default_factory=list)
+    cols_integer: list[str] = field(default_factory=list)
+    cols_scientific: list[str] = field(default_factory=list)
+    # body cells written blank, and non-blank cells whose value the value policy changed
+    count_cells_blanked: int = 0
+    count_cells_coerced: int = 0
 
     def warn(self, msg: str) -> None:
         self.warnings.append(str(msg))
@@ -17,6 +17,10 @@ from .constant import (
 from .spec import (
     AutofitPolicy,
     CellFormatPatch,
+    ConditionalFormatRule,
+    ImagePlacement,
+    PageSetup,
+    RowGroup,
     ScientificPolicy,
     XlsxReport,
     XlsxWriteOptions,
@@ -26,6 +30,10 @@ from .spec import (
 class ProtocolXlsxWriterBackend(Protocol):
     def close(self) -> None: ...
 
+    def reopen(self) -> Any: ...
+
+    def to_bytes(self) -> bytes: ...
+
     def report(self) -> tuple[XlsxReport, ...]: ...
 
     def write_sheet(
@@ -36,9 +44,41 @@ class ProtocolXlsxWriterBackend(Protocol):
         header: Any | None = None,
         cols_integer: Sequence[ColumnIdentifier] | None = None,
         cols_decimal: Sequence[ColumnIdentifier] | None | Literal[False] = None,
+        cols_hyperlink: Sequence[ColumnIdentifier] | None = None,
+        cols_hyperlink_text: Mapping[str, str] | None = None,
+        cols_comment_text: Mapping[str, str] | None = None,
         num_frozen_cols: int = 0,
         num_frozen_rows: int | None = None,
+        num_reserved_top_rows: int = 0,
         should_merge_header: bool = False,
+        should_merge_header_vertical: bool = False,
+        should_autofilter: bool = False,
+        should_write_table: bool = False,
+        table_style: str | None = None,
+        zoom: int | None = None,
+        should_hide_gridlines: bool = False,
+        tab_color: str | None = None,
+        page_setup: PageSetup | None = None,
+        conditional_formats: Sequence[ConditionalFormatRule] | None = None,
+        should_band_rows: bool = False,
+        band_color: str | None = None,
+        cols_dropdown: Mapping[str, Sequence[str]] | None = None,
+        cols_width: Mapping[str, float] | None = None,
+        cols_fmt_overrides: Mapping[str, CellFormatPatch] | None = None,
+        cols_hidden: Sequence[ColumnIdentifier] | None = None,
+        cols_currency: Sequence[ColumnIdentifier] | None = None,
+        currency_symbol: str | None = None,
+        currency_decimals: int | None = None,
+        cols_percent: Sequence[ColumnIdentifier] | None = None,
+        percent_decimals: int | None = None,
+        summary_row: Mapping[str, Literal["sum", "mean", "count"]] | None = None,
+        should_use_summary_formulas: bool = False,
+        rich_text_cells: (
+            Mapping[tuple[int, int], Sequence[tuple[CellFormatPatch, str]]] | None
+        ) = None,
+        formula_cells: Mapping[tuple[int, int], str] | None = None,
+        images: Sequence[ImagePlacement] | None = None,
+        row_groups: Sequence[RowGroup] | None = None,
         should_keep_missing_values: bool | None = None,
         policy_autofit: AutofitPolicy | None = None,
         policy_scientific: ScientificPolicy | None = None,
@@ -53,9 +93,41 @@ class ProtocolXlsxWriterBackend(Protocol):
         header: Any | None = None,
         cols_integer: Sequence[ColumnIdentifier] | None = None,
         cols_decimal: Sequence[ColumnIdentifier] | None | Literal[False] = None,
+        cols_hyperlink: Sequence[ColumnIdentifier] | None = None,
+        cols_hyperlink_text: Mapping[str, str] | None = None,
+        cols_comment_text: Mapping[str, str] | None = None,
         num_frozen_cols: int = 0,
         num_frozen_rows: int | None = None,
+        num_reserved_top_rows: int = 0,
         should_merge_header: bool = False,
+        should_merge_header_vertical: bool = False,
+        should_autofilter: bool = False,
+        should_write_table: bool = False,
+        table_style: str | None = None,
+        zoom: int | None = None,
+        should_hide_gridlines: bool = False,
+        tab_color: str | None = None,
+        page_setup: PageSetup | None = None,
+        conditional_formats: Sequence[ConditionalFormatRule] | None = None,
+        should_band_rows: bool = False,
+        band_color: str | None = None,
+        cols_dropdown: Mapping[str, Sequence[str]] | None = None,
+        cols_width: Mapping[str, float] | None = None,
+        cols_fmt_overrides: Mapping[str, CellFormatPatch] | None = None,
+        cols_hidden: Sequence[ColumnIdentifier] | None = None,
+        cols_currency: Sequence[ColumnIdentifier] | None = None,
+        currency_symbol: str | None = None,
+        currency_decimals: int | None = None,
+        cols_percent: Sequence[ColumnIdentifier] | None = None,
+        percent_decimals: int | None = None,
+        summary_row: Mapping[str, Literal["sum", "mean", "count"]] | None = None,
+        should_use_summary_formulas: bool = False,
+        rich_text_cells: (
+            Mapping[tuple[int, int], Sequence[tuple[CellFormatPatch, str]]] | None
+        ) = None,
+        formula_cells: Mapping[tuple[int, int], str] | None = None,
+        images: Sequence[ImagePlacement] | None = None,
+        row_groups: Sequence[RowGroup] | None = None,
         should_keep_missing_values: bool | None = None,
         policy_autofit: AutofitPolicy | None = None,
         policy_scientific: ScientificPolicy | None = None,
@@ -70,15 +142,57 @@ class ProtocolXlsxWriterBackend(Protocol):
         header: Any | None = None,
         cols_integer: Sequence[ColumnIdentifier] | None = None,
         cols_decimal: Sequence[ColumnIdentifier] | None | Literal[False] = None,
+        cols_hyperlink: Sequence[ColumnIdentifier] | None = None,
+        cols_hyperlink_text: Mapping[str, str] | None = None,
+        cols_comment_text: Mapping[str, str] | None = None,
         num_frozen_cols: int = 0,
         num_frozen_rows: int | None = None,
+        num_reserved_top_rows: int = 0,
         should_merge_header: bool = False,
+        should_merge_header_vertical: bool = False,
+        should_autofilter: bool = False,
+        should_write_table: bool = False,
+        table_style: str | None = None,
+        zoom: int | None = None,
+        should_hide_gridlines: bool = False,
+        tab_color: str | None = None,
+        page_setup: PageSetup | None = None,
+        conditional_formats: Sequence[ConditionalFormatRule] | None = None,
+        should_band_rows: bool = False,
+        band_color: str | None = None,
+        cols_dropdown: Mapping[str, Sequence[str]] | None = None,
+        cols_width: Mapping[str, float] | None = None,
+        cols_fmt_overrides: Mapping[str, CellFormatPatch] | None = None,
+        cols_hidden: Sequence[ColumnIdentifier] | None = None,
+        cols_currency: Sequence[ColumnIdentifier] | None = None,
+        currency_symbol: str | None = None,
+        currency_decimals: int | None = None,
+        cols_percent: Sequence[ColumnIdentifier] | None = None,
+        percent_decimals: int | None = None,
+        summary_row: Mapping[str, Literal["sum", "mean", "count"]] | None = None,
+        should_use_summary_formulas: bool = False,
+        rich_text_cells: (
+            Mapping[tuple[int, int], Sequence[tuple[CellFormatPatch, str]]] | None
+        ) = None,
+        formula_cells: Mapping[tuple[int, int], str] | None = None,
+        images: Sequence[ImagePlacement] | None = None,
+        row_groups: Sequence[RowGroup] | None = None,
         should_keep_missing_values: bool | None = None,
         policy_autofit: AutofitPolicy | None = None,
         policy_scientific: ScientificPolicy | None = None,
         schema_body: Any | None = None,
     ) -> Any: ...
 
+    def insert_image(
+        self,
+        sheet_name: str,
+        row: int,
+        col: int,
+        source: str | os.PathLike[str] | bytes,
+        x_offset: int = 0,
+        y_offset: int = 0,
+    ) -> Any: ...
+
 
 class XlsxWriter:
     """Rust-backed XLSX writer.
@@ -103,7 +217,15 @@ class XlsxWriter:
         fmt_scientific: CellFormatPatch | None = None,
         fmt_header: CellFormatPatch | None = None,
         options_write: XlsxWriteOptions | None = None,
+        doc_properties: Mapping[str, str] | None = None,
     ):
+        """Create a writer for ``file_out``.
+
+        Args:
+            doc_properties:
+                Workbook metadata keyed by ``"title"``, ``"subject"``, ``"author"``,
+                ``"company"``, or ``"keywords"``. Blank values are left unset.
+        """
         if not is_rs_backend_available():
             raise RuntimeError(
                 "Rust xlsx backend is unavailable. Build/install `_axiomkit_io_xlsx_rs` first."
@@ -123,6 +245,7 @@ class XlsxWriter:
                 fmt_scientific=fmt_scientific,
                 fmt_header=fmt_header,
                 options_write=self._options_write,
+                doc_properties=doc_properties,
             ),
         )
 
@@ -137,6 +260,29 @@ class XlsxWriter:
     def close(self) -> None:
         self._writer.close()
 
+    def reopen(self) -> Self:
+        """Reopen a closed writer to append more sheets to the same workbook.
+
+        Sheets written before the previous ``close()`` are kept and saved again on
+        the next ``close()``. Existing ``.xlsx`` files not created by this writer
+        cannot be reopened.
+
+        Returns:
+            Self: The current writer instance for fluent chaining.
+        """
+        self._writer.reopen()
+        return self
+
+    def to_bytes(self) -> bytes:
+        """Finish the workbook and return it as XLSX bytes without writing to disk.
+
+        The writer is closed afterwards; ``file_out`` is never created.
+
+        Returns:
+            bytes: The complete XLSX file content.
+        """
+        return self._writer.to_bytes()
+
     def report(self) -> tuple[XlsxReport, ...]:
         return self._writer.report()
 
@@ -148,9 +294,41 @@ class XlsxWriter:
         header: pl.DataFrame | None = None,
         cols_integer: Sequence[ColumnIdentifier] | None = None,
         cols_decimal: Sequence[ColumnIdentifier] | None | Literal[False] = None,
+        cols_hyperlink: Sequence[ColumnIdentifier] | None = None,
+        cols_hyperlink_text: Mapping[str, str] | None = None,
+        cols_comment_text: Mapping[str, str] | None = None,
         num_frozen_cols: int = 0,
         num_frozen_rows: int | None = None,
+        num_reserved_top_rows: int = 0,
         should_merge_header: bool = False,
+        should_merge_header_vertical: bool = False,
+        should_autofilter: bool = False,
+        should_write_table: bool = False,
+        table_style: str | None = None,
+        zoom: int | None = None,
+        should_hide_gridlines: bool = False,
+        tab_color: str | None = None,
+        page_setup: PageSetup | None = None,
+        conditional_formats: Sequence[ConditionalFormatRule] | None = None,
+        should_band_rows: bool = False,
+        band_color: str | None = None,
+        cols_dropdown: Mapping[str, Sequence[str]] | None = None,
+        cols_width: Mapping[str, float] | None = None,
+        cols_fmt_overrides: Mapping[str, CellFormatPatch] | None = None,
+        cols_hidden: Sequence[ColumnIdentifier] | None = None,
+        cols_currency: Sequence[ColumnIdentifier] | None = None,
+        currency_symbol: str | None = None,
+        currency_decimals: int | None = None,
+        cols_percent: Sequence[ColumnIdentifier] | None = None,
+        percent_decimals: int | None = None,
+        summary_row: Mapping[str, Literal["sum", "mean", "count"]] | None = None,
+        should_use_summary_formulas: bool = False,
+        rich_text_cells: (
+            Mapping[tuple[int, int], Sequence[tuple[CellFormatPatch, str]]] | None
+        ) = None,
+        formula_cells: Mapping[tuple[int, int], str] | None = None,
+        images: Sequence[ImagePlacement] | None = None,
+        row_groups: Sequence[RowGroup] | None = None,
         should_keep_missing_values: bool | None = None,
         policy_autofit: AutofitPolicy | None = None,
         policy_scientific: ScientificPolicy | None = None,
@@ -177,12 +355,111 @@ class XlsxWriter:
                 column indices. Pure numeric strings such as ``"0"`` are treated as
                 column names, not indices.
                 Pass ``False`` to disable explicit decimal-column selection.
+            cols_hyperlink:
+                Optional column identifiers whose non-empty string values are
+                written as clickable hyperlinks. Use ``str`` for literal column
+                names and ``int`` for zero-based column indices. Values Excel
+                rejects as URLs are written as plain text with a report warning.
+            cols_hyperlink_text:
+                Optional mapping from hyperlink column name to the column name
+                holding its display text. Mapped columns are written as
+                hyperlinks even when absent from ``cols_hyperlink``.
+            cols_comment_text:
+                Optional mapping from data column name to the column name holding
+                its cell comment. Non-empty comment cells are attached as notes.
             num_frozen_cols: Number of leftmost columns to freeze.
             num_frozen_rows: Number of top rows to freeze. When ``None``, the
-                backend uses the resolved header height.
+                backend uses the reserved top rows plus the resolved header height.
+                Panes are always frozen; resizable "Split" panes are not available
+                because the Rust writer backend cannot emit them.
+            num_reserved_top_rows: Number of blank rows kept above the header, e.g. to
+                make room for a logo inserted via ``images``.
             should_merge_header:
                 - ``True``: Merge all adjacent header labels that are identical.
                 - ``False``: Don't merge any header labels.
+            should_merge_header_vertical:
+                Merge vertically repeated header labels with real merged cells instead
+                of blanking repeats and drawing borders. Vertical runs take precedence
+                over horizontal merges. Requires ``should_merge_header``.
+            should_autofilter: Add an auto-filter on the last header row spanning the
+                written body rows of each sheet part.
+            should_write_table: Wrap each sheet part's header and body in a native Excel
+                table. Cannot be combined with ``should_merge_header`` or
+                ``should_autofilter``.
+            table_style: Excel table style name such as ``"Table Style Medium 9"``.
+                When ``None``, Excel's default table style is used.
+            zoom: Sheet zoom percentage in ``10..=400``. When ``None``, Excel's
+                default 100% is used.
+            should_hide_gridlines: Hide on-screen cell gridlines.
+            tab_color: Worksheet tab color as an RGB hex string such as
+                ``"#FF0000"``. Invalid values are reported as warnings and ignored.
+            page_setup: Print orientation, paper size, and margins (inches) applied
+                to every sheet part. When ``None``, Excel's print defaults are kept.
+                ``print_area`` addresses body rows and data columns and is clipped
+                to each sheet part; ``should_repeat_header_on_print`` repeats the
+                header rows atop every printed page. ``header_text``/``footer_text``
+                accept Excel field codes such as ``&P`` (page) and ``&N`` (pages).
+            conditional_formats:
+                Conditional formats (3-color scales or data bars) applied to
+                the body range of named columns on every sheet part.
+            should_band_rows:
+                Shade every second body row (the 2nd, 4th, ...) of each sheet part
+                via a formula conditional format over the body range.
+            band_color:
+                RGB hex background of banded rows like ``"#F2F2F2"``.
+                Defaults to light gray.
+            cols_dropdown:
+                Optional mapping from column name to allowed values. Body cells
+                of mapped columns get an Excel dropdown list validation.
+            cols_width:
+                Optional mapping from column name to an explicit Excel column width.
+                Mapped columns ignore autofit.
+            cols_fmt_overrides:
+                Optional mapping from column name to a `CellFormatPatch` merged onto
+                that column's base body format (e.g. a currency `num_format`).
+            cols_hidden:
+                Optional column identifiers to hide. Hidden columns are still written
+                (e.g. helper columns for lookups) and are skipped by autofit.
+            cols_currency:
+                Optional currency column identifiers. Values are written as numbers
+                with a currency number format (e.g. ``"$#,##0.00"``) that replaces
+                the decimal format; a ``cols_fmt_overrides`` ``num_format`` still wins.
+            currency_symbol:
+                Currency symbol for ``cols_currency``, e.g. ``"€"`` or ``"EUR "``.
+                Symbols with letters or digits are quoted. Defaults to ``"$"``.
+            currency_decimals:
+                Currency decimal places for ``cols_currency`` (``0..=20``).
+                Defaults to 2.
+            cols_percent:
+                Optional percent column identifiers. Stored fractions display as
+                percentages (``0.25`` as ``25.00%``) without pre-multiplying values.
+            percent_decimals:
+                Percent decimal places for ``cols_percent`` (``0..=20``).
+                Defaults to 2.
+            summary_row:
+                Aggregate per column name (``"sum"``, ``"mean"``, ``"count"``)
+                written in a bold total row below the body. Each sheet part gets
+                its own total row over the body rows it holds.
+            should_use_summary_formulas:
+                Write ``summary_row`` cells as live ``SUM``/``AVERAGE``/``COUNTA``
+                formulas over the body range instead of plain values.
+            rich_text_cells:
+                Rich text runs keyed by zero-based ``(body_row, data_col)``.
+                Each run is ``(CellFormatPatch, text)`` with non-empty text; the runs
+                replace that cell's value. Cells outside the written body are ignored.
+            formula_cells:
+                Formulas like ``"=B2*C2"`` keyed by zero-based ``(body_row, data_col)``;
+                each replaces that cell's value. References are plain worksheet cells,
+                so they must account for reserved/header rows and sheet parts. Cells
+                outside the written body are ignored with a report warning.
+            images:
+                Images inserted into every sheet part, anchored at sheet cell
+                coordinates (not body rows).
+            row_groups:
+                Collapsible outline groups over zero-based body rows (inclusive
+                ``row_start``/``row_end``), e.g. for subtotal blocks. Overlapping groups
+                keep the highest ``level`` per row. Sheets with row groups are
+                buffered in memory instead of streamed.
             should_keep_missing_values:
                 - ``True``: Write missing, NaN, and Inf values as text tokens.
                 - ``False``: Write missing, NaN, and Inf values as blank cells.
@@ -192,7 +469,8 @@ class XlsxWriter:
                 cell. If ``None``, scientific formatting is disabled by default.
                 Only numeric values that fall within the policy scope and
                 trigger thresholds use the scientific format; other cells keep
-                the column base format.
+                the column base format. ``precision`` sets the number of
+                mantissa decimals in the scientific number format.
 
         Returns:
             Self: The current writer instance for fluent chaining.
@@ -234,6 +512,10 @@ class XlsxWriter:
         """
         _warn_numeric_string_column_selectors(cols_integer, arg_name="cols_integer")
         _warn_numeric_string_column_selectors(cols_decimal, arg_name="cols_decimal")
+        _warn_numeric_string_column_selectors(cols_hyperlink, arg_name="cols_hyperlink")
+        _warn_numeric_string_column_selectors(cols_hidden, arg_name="cols_hidden")
+        _warn_numeric_string_column_selectors(cols_currency, arg_name="cols_currency")
+        _warn_numeric_string_column_selectors(cols_percent, arg_name="cols_percent")
         body_lazy = _normalize_body(body)
         header_normalized = _normalize_header(header)
         schema_body = _derive_schema_body(body_lazy)
@@ -241,16 +523,48 @@ class XlsxWriter:
         chunk_size = _derive_collect_batches_chunk_size(
             body_lazy, options_write=self._options_write
         )
-        if _can_write_lazy_single_pass(policy_autofit):
+        if _can_write_lazy_single_pass(
+            policy_autofit, should_write_table=should_write_table
+        ):
             self._writer.write_sheet_batches_single_pass(
                 batches_write=_collect_batches(body_lazy, chunk_size=chunk_size),
                 sheet_name=sheet_name,
                 header=header_normalized,
                 cols_integer=cols_integer,
                 cols_decimal=cols_decimal,
+                cols_hyperlink=cols_hyperlink,
+                cols_hyperlink_text=cols_hyperlink_text,
+                cols_comment_text=cols_comment_text,
                 num_frozen_cols=num_frozen_cols,
                 num_frozen_rows=num_frozen_rows,
+                num_reserved_top_rows=num_reserved_top_rows,
                 should_merge_header=should_merge_header,
+                should_merge_header_vertical=should_merge_header_vertical,
+                should_autofilter=should_autofilter,
+                should_write_table=should_write_table,
+                table_style=table_style,
+                zoom=zoom,
+                should_hide_gridlines=should_hide_gridlines,
+                tab_color=tab_color,
+                page_setup=page_setup,
+                conditional_formats=conditional_formats,
+                should_band_rows=should_band_rows,
+                band_color=band_color,
+                cols_dropdown=cols_dropdown,
+                cols_width=cols_width,
+                cols_fmt_overrides=cols_fmt_overrides,
+                cols_hidden=cols_hidden,
+                cols_currency=cols_currency,
+                currency_symbol=currency_symbol,
+                currency_decimals=currency_decimals,
+                cols_percent=cols_percent,
+                percent_decimals=percent_decimals,
+                summary_row=summary_row,
+                should_use_summary_formulas=should_use_summary_formulas,
+                rich_text_cells=rich_text_cells,
+                formula_cells=formula_cells,
+                images=images,
+                row_groups=row_groups,
                 should_keep_missing_values=should_keep_missing_values,
                 policy_autofit=policy_autofit,
                 policy_scientific=policy_scientific,
@@ -264,9 +578,39 @@ class XlsxWriter:
                 header=header_normalized,
                 cols_integer=cols_integer,
                 cols_decimal=cols_decimal,
+                cols_hyperlink=cols_hyperlink,
+                cols_hyperlink_text=cols_hyperlink_text,
+                cols_comment_text=cols_comment_text,
                 num_frozen_cols=num_frozen_cols,
                 num_frozen_rows=num_frozen_rows,
+                num_reserved_top_rows=num_reserved_top_rows,
                 should_merge_header=should_merge_header,
+                should_merge_header_vertical=should_merge_header_vertical,
+                should_autofilter=should_autofilter,
+                should_write_table=should_write_table,
+                table_style=table_style,
+                zoom=zoom,
+                should_hide_gridlines=should_hide_gridlines,
+                tab_color=tab_color,
+                page_setup=page_setup,
+                conditional_formats=conditional_formats,
+                should_band_rows=should_band_rows,
+                band_color=band_color,
+                cols_dropdown=cols_dropdown,
+                cols_width=cols_width,
+                cols_fmt_overrides=cols_fmt_overrides,
+                cols_hidden=cols_hidden,
+                cols_currency=cols_currency,
+                currency_symbol=currency_symbol,
+                currency_decimals=currency_decimals,
+                cols_percent=cols_percent,
+                percent_decimals=percent_decimals,
+                summary_row=summary_row,
+                should_use_summary_formulas=should_use_summary_formulas,
+                rich_text_cells=rich_text_cells,
+                formula_cells=formula_cells,
+                images=images,
+                row_groups=row_groups,
                 should_keep_missing_values=should_keep_missing_values,
                 policy_autofit=policy_autofit,
                 policy_scientific=policy_scientific,
@@ -274,6 +618,26 @@ class XlsxWriter:
             )
         return self
 
+    def insert_image(self, sheet_name: str, placement: ImagePlacement) -> Self:
+        """Insert an image into a worksheet that was already written.
+
+        Args:
+            sheet_name: Final (sanitized) worksheet name as reported by ``report()``.
+            placement: Image source and zero-based sheet cell anchor.
+
+        Returns:
+            Self: The current writer instance for fluent chaining.
+        """
+        self._writer.insert_image(
+            sheet_name,
+            placement.row,
+            placement.col,
+            placement.source,
+            x_offset=placement.x_offset,
+            y_offset=placement.y_offset,
+        )
+        return self
+
 
 def _normalize_body(value: pl.DataFrame | pl.LazyFrame) -> pl.LazyFrame:
     if isinstance(value, pl.LazyFrame):
@@ -293,7 +657,12 @@ def _derive_schema_body(value: pl.LazyFrame) -> pl.DataFrame:
     return pl.DataFrame(schema=value.collect_schema())
 
 
-def _can_write_lazy_single_pass(policy_autofit: AutofitPolicy | None) -> bool:
+def _can_write_lazy_single_pass(
+    policy_autofit: AutofitPolicy | None, *, should_write_table: bool
+) -> bool:
+    # Tables need the final row count up front, which single-pass cannot know.
+    if should_write_table:
+        return False
     if policy_autofit is None:
         return True
     return policy_autofit.mode in {"header", "none"}
@@ -5,7 +5,7 @@ from pathlib import Path
 
 import pytest
 from axiomkit.io.fs._rs_bridge import is_rs_backend_available  # noqa: E402
-from axiomkit.io.fs.copy import copy_tree  # noqa: E402
+from axiomkit.io.fs.copy import copy_entry, copy_tree  # noqa: E402
 from axiomkit.io.fs.spec import (  # noqa: E402
     CopyDepthLimitMode,
     CopyDirectoryConflictMode,
@@ -130,3 +130,21 @@ def test_copy_tree_smoke_and_edges(tmp_path: Path) -> None:
     # 8) overlap detection
     with pytest.raises(ValueError):
         copy_tree(src, src / "nested")
+
+
+def test_copy_entry_copies_single_file(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust fs backend is unavailable")
+
+    src = tmp_path / "src"
+    _write_text(src / "a.txt", "a")
+
+    report = copy_entry(src / "a.txt", tmp_path / "dst")
+    assert report.error_count == 0
+    assert report.cnt_copied == 1
+    assert (tmp_path / "dst" / "a.txt").read_text() == "a"
+
+    with pytest.raises(IsADirectoryError):
+        copy_entry(src, tmp_path / "dst")
+    with pytest.raises(ValueError):
+        copy_entry(src / "a.txt", src)
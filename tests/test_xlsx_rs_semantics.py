@@ -1,5 +1,8 @@
 from __future__ import annotations
 
+import base64
+import datetime as dt
+import io
 import warnings
 import xml.etree.ElementTree as ET
 import zipfile
@@ -12,6 +15,11 @@ from axiomkit.io.xlsx import XlsxWriter  # noqa: E402
 from axiomkit.io.xlsx._rs_bridge import is_rs_backend_available  # noqa: E402
 from axiomkit.io.xlsx.spec import (  # noqa: E402
     AutofitPolicy,
+    CellFormatPatch,
+    ConditionalFormatRule,
+    ImagePlacement,
+    PageSetup,
+    RowGroup,
     ScientificPolicy,
     XlsxRowChunkPolicy,
     XlsxValuePolicy,
@@ -172,6 +180,31 @@ def test_scientific_format_trigger_for_extreme_values(tmp_path: Path) -> None:
     assert "E+" in c_fmt
 
 
+def test_scientific_precision_controls_mantissa_decimals(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"metric": [1.23456e-8, 2e-8]})
+    path_file_out = tmp_path / "scientific_precision.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            policy_autofit=AutofitPolicy(mode="none"),
+            policy_scientific=ScientificPolicy(scope="decimal", precision=4),
+        )
+        with pytest.raises(ValueError, match="precision"):
+            writer.write_sheet(
+                body=df,
+                sheet_name="T",
+                policy_scientific=ScientificPolicy(scope="decimal", precision=21),
+            )
+
+    _, _, c_fmt = read_cell(path_file_out, "A2")
+    assert c_fmt == "0.0000E+0"
+
+
 def test_numeric_string_selector_targets_named_column_and_warns(tmp_path: Path) -> None:
     if not is_rs_backend_available():
         pytest.skip("Rust xlsx backend is unavailable")
@@ -321,3 +354,1243 @@ def test_row_chunk_policy_is_active_in_write_path(tmp_path: Path) -> None:
 
     _, c_value, _ = read_cell(path_file_ok, "A4")
     assert float(c_value) == 3.0
+
+
+def test_date_columns_are_written_as_native_dates(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame(
+        {
+            "day": [dt.date(2024, 1, 1)],
+            "ts": [dt.datetime(2024, 1, 1, 12, 0, 0)],
+        }
+    )
+    path_file_out = tmp_path / "dates.xlsx"
+    opts_write = XlsxWriteOptions(date_num_format="dd/mm/yyyy")
+
+    with XlsxWriter(path_file_out, options_write=opts_write) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+
+    c_type_a2, c_value_a2, c_fmt_a2 = read_cell(path_file_out, "A2")
+    assert c_type_a2 != "s"
+    assert float(c_value_a2) == 45292.0
+    assert c_fmt_a2 == "dd/mm/yyyy"
+
+    c_type_b2, c_value_b2, c_fmt_b2 = read_cell(path_file_out, "B2")
+    assert c_type_b2 != "s"
+    assert float(c_value_b2) == 45292.5
+    assert c_fmt_b2 == "yyyy-mm-dd hh:mm:ss"
+
+
+@pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
+def test_timezone_aware_datetimes_use_display_timezone(
+    tmp_path: Path, time_unit: str
+) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame(
+        {"ts": [dt.datetime(2024, 1, 1, 12, 0, 0)]},
+        schema={"ts": pl.Datetime(time_unit=time_unit, time_zone="UTC")},
+    )
+    path_file_out = tmp_path / f"tz_{time_unit}.xlsx"
+    opts_write = XlsxWriteOptions(
+        value_policy=XlsxValuePolicy(datetime_tz="Asia/Shanghai")
+    )
+
+    with XlsxWriter(path_file_out, options_write=opts_write) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+
+    c_type, c_value, _ = read_cell(path_file_out, "A2")
+    assert c_type != "s"
+    assert float(c_value) == pytest.approx(45292.5 + 8 / 24)
+
+
+def test_boolean_columns_respect_native_boolean_policy(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"flag": [True, False]})
+
+    path_file_text = tmp_path / "bool_text.xlsx"
+    opts_text = XlsxWriteOptions(value_policy=XlsxValuePolicy(bool_true_str="yes"))
+    with XlsxWriter(path_file_text, options_write=opts_text) as writer:
+        writer.write_sheet(body=df, sheet_name="S", policy_autofit=AutofitPolicy(mode="none"))
+
+    assert read_cell(path_file_text, "A2")[:2] == ("s", "yes")
+    assert read_cell(path_file_text, "A3")[:2] == ("s", "False")
+
+    path_file_native = tmp_path / "bool_native.xlsx"
+    opts_native = XlsxWriteOptions(
+        value_policy=XlsxValuePolicy(should_write_native_booleans=True)
+    )
+    with XlsxWriter(path_file_native, options_write=opts_native) as writer:
+        writer.write_sheet(body=df, sheet_name="S", policy_autofit=AutofitPolicy(mode="none"))
+
+    assert read_cell(path_file_native, "A2")[:2] == ("b", "1")
+    assert read_cell(path_file_native, "A3")[:2] == ("b", "0")
+
+
+def test_hyperlink_columns_write_urls_and_fall_back_to_text(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame(
+        {
+            "url": ["https://example.com/a", "not a url"],
+            "label": ["Dashboard", "Broken"],
+        }
+    )
+    path_file_out = tmp_path / "hyperlinks.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_hyperlink_text={"url": "label"},
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+        l_warnings = writer.report()[0].warnings
+
+    assert read_cell(path_file_out, "A2")[:2] == ("s", "Dashboard")
+    assert read_cell(path_file_out, "A3")[:2] == ("s", "not a url")
+    assert any("Invalid hyperlink" in _msg for _msg in l_warnings)
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_refs = [
+        node.attrib["ref"] for node in root_sheet.findall(".//m:hyperlink", NS_MAIN)
+    ]
+    assert l_refs == ["A2"]
+
+
+def test_comment_columns_attach_notes_to_data_cells(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame(
+        {
+            "value": [1.5, 2.5, 3.5],
+            "note": ["check source", None, ""],
+        }
+    )
+    path_file_out = tmp_path / "comments.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_comment_text={"value": "note"},
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_comments = ET.fromstring(zf.read("xl/comments1.xml"))
+    l_refs = [
+        node.attrib["ref"] for node in root_comments.findall(".//m:comment", NS_MAIN)
+    ]
+    assert l_refs == ["A2"]
+
+
+def test_autofilter_spans_last_header_row_to_last_body_row(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    df_header = pl.DataFrame({"a": ["Group", "A"], "b": ["Group", "B"]})
+    path_file_out = tmp_path / "autofilter.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            header=df_header,
+            should_autofilter=True,
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    node_filter = root_sheet.find("m:autoFilter", NS_MAIN)
+    assert node_filter is not None
+    assert node_filter.attrib["ref"] == "A2:B5"
+
+
+def test_table_mode_wraps_data_region_and_rejects_merged_header(
+    tmp_path: Path,
+) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    path_file_out = tmp_path / "table.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            should_write_table=True,
+            table_style="Table Style Light 9",
+        )
+        with pytest.raises(ValueError, match="should_merge_header"):
+            writer.write_sheet(
+                body=df,
+                sheet_name="T",
+                should_write_table=True,
+                should_merge_header=True,
+            )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_table = ET.fromstring(zf.read("xl/tables/table1.xml"))
+    assert root_table.attrib["ref"] == "A1:B3"
+    node_style = root_table.find("m:tableStyleInfo", NS_MAIN)
+    assert node_style is not None
+    assert node_style.attrib["name"] == "TableStyleLight9"
+    l_names = [
+        node.attrib["name"] for node in root_table.findall(".//m:tableColumn", NS_MAIN)
+    ]
+    assert l_names == ["a", "b"]
+
+
+def test_conditional_formats_cover_named_column_body_range(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"name": ["a", "b", "c"], "score": [1.0, 5.0, 9.0]})
+    path_file_out = tmp_path / "conditional.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            conditional_formats=[
+                ConditionalFormatRule(col="score"),
+                ConditionalFormatRule(col="score", kind="data_bar"),
+            ],
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_nodes = root_sheet.findall("m:conditionalFormatting", NS_MAIN)
+    assert [node.attrib["sqref"] for node in l_nodes] == ["B2:B4"]
+    l_types = [node.attrib["type"] for node in l_nodes[0].findall("m:cfRule", NS_MAIN)]
+    assert l_types == ["colorScale", "dataBar"]
+
+
+def test_dropdown_columns_add_list_validation(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"id": [1, 2, 3], "status": ["open", "done", "open"]})
+    path_file_out = tmp_path / "dropdown.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_dropdown={"status": ["open", "done"]},
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_nodes = root_sheet.findall(".//m:dataValidation", NS_MAIN)
+    assert [node.attrib["sqref"] for node in l_nodes] == ["B2:B4"]
+    assert l_nodes[0].attrib["type"] == "list"
+    node_formula = l_nodes[0].find("m:formula1", NS_MAIN)
+    assert node_formula is not None
+    assert node_formula.text == '"open,done"'
+
+
+def test_images_and_reserved_top_rows_shift_header(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    bytes_png = base64.b64decode(
+        "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg=="
+    )
+    df = pl.DataFrame({"id": [1, 2], "name": ["a", "b"]})
+    path_file_out = tmp_path / "images.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            num_reserved_top_rows=3,
+            images=[ImagePlacement(row=0, col=0, source=bytes_png)],
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        assert "xl/media/image1.png" in zf.namelist()
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_rows = [
+        node.attrib["r"] for node in root_sheet.findall(".//m:sheetData/m:row", NS_MAIN)
+    ]
+    assert l_rows == ["4", "5", "6"]
+    node_pane = root_sheet.find(".//m:pane", NS_MAIN)
+    assert node_pane is not None
+    assert node_pane.attrib["topLeftCell"] == "A5"
+
+
+def test_header_format_keeps_horizontal_and_vertical_alignment(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"x": [1]})
+    path_file_out = tmp_path / "align.xlsx"
+
+    with XlsxWriter(
+        path_file_out, fmt_header=CellFormatPatch(align="center", valign="top")
+    ) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        l_xfs, _ = _read_styles(zf)
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    node_cell = root_sheet.find(".//m:c[@r='A1']", NS_MAIN)
+    assert node_cell is not None
+    node_alignment = l_xfs[int(node_cell.attrib.get("s", "0"))].find("m:alignment", NS_MAIN)
+    assert node_alignment is not None
+    assert node_alignment.attrib["horizontal"] == "center"
+    assert node_alignment.attrib["vertical"] == "top"
+
+
+def test_large_integers_are_written_as_exact_text(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame(
+        {
+            "id_i64": pl.Series([2**63 - 1, 7], dtype=pl.Int64),
+            "id_u64": pl.Series([2**64 - 1, 2**53], dtype=pl.UInt64),
+        }
+    )
+    path_file_out = tmp_path / "large_integers.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+        l_warnings = writer.report()[0].warnings
+
+    assert read_cell(path_file_out, "A2")[:2] == ("s", "9223372036854775807")
+    assert read_cell(path_file_out, "B2")[:2] == ("s", "18446744073709551615")
+    c_type_a3, c_value_a3, _ = read_cell(path_file_out, "A3")
+    c_type_b3, c_value_b3, _ = read_cell(path_file_out, "B3")
+    assert c_type_a3 != "s"
+    assert float(c_value_a3) == 7.0
+    assert c_type_b3 != "s"
+    assert float(c_value_b3) == 2.0**53
+    assert any("2 integer cell(s)" in _msg for _msg in l_warnings)
+
+
+def test_to_bytes_returns_workbook_without_touching_disk(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"x": [1, 2]})
+    path_file_out = tmp_path / "unused.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+        bytes_xlsx = writer.to_bytes()
+
+    assert not path_file_out.exists()
+    assert bytes_xlsx.startswith(b"PK")
+    with zipfile.ZipFile(io.BytesIO(bytes_xlsx)) as zf:
+        assert "xl/worksheets/sheet1.xml" in zf.namelist()
+
+
+def test_reopen_appends_sheets_to_same_workbook(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"x": [1, 2]})
+    path_file_out = tmp_path / "reopen.xlsx"
+
+    writer = XlsxWriter(path_file_out)
+    with writer:
+        writer.write_sheet(body=df, sheet_name="First")
+    with writer.reopen():
+        writer.write_sheet(body=df, sheet_name="First")
+
+    l_sheet_names = [
+        _sheet.sheet_name for _report in writer.report() for _sheet in _report.sheets
+    ]
+    assert l_sheet_names == ["First", "First__2"]
+    with zipfile.ZipFile(path_file_out) as zf:
+        assert "xl/worksheets/sheet1.xml" in zf.namelist()
+        assert "xl/worksheets/sheet2.xml" in zf.namelist()
+
+
+def test_explicit_column_widths_override_autofit(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"id": [1, 2], "name": ["a", "b"]})
+    path_file_out = tmp_path / "widths.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_width={"name": 30.0},
+            policy_autofit=AutofitPolicy(mode="all"),
+        )
+        with pytest.raises(ValueError, match="cols_width"):
+            writer.write_sheet(body=df, sheet_name="T", cols_width={"name": 300.0})
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    dict_widths = {
+        node.attrib["min"]: float(node.attrib["width"])
+        for node in root_sheet.findall(".//m:cols/m:col", NS_MAIN)
+    }
+    assert dict_widths["1"] < 30.0
+    assert 30.0 <= dict_widths["2"] < 31.0
+
+
+def test_column_format_override_merges_onto_target_column_only(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"qty": [1.5, 2.5], "price": [3.25, 4.75], "cost": [5.5, 6.5]})
+    path_file_out = tmp_path / "fmt_overrides.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_fmt_overrides={"price": CellFormatPatch(num_format='"$"#,##0.00')},
+        )
+        with pytest.raises(ValueError, match="Column not found"):
+            writer.write_sheet(
+                body=df,
+                sheet_name="T",
+                cols_fmt_overrides={"missing": CellFormatPatch(bold=True)},
+            )
+
+    _, _, fmt_qty = read_cell(path_file_out, "A2")
+    _, v_price, fmt_price = read_cell(path_file_out, "B2")
+    _, _, fmt_cost = read_cell(path_file_out, "C2")
+    assert fmt_price == '"$"#,##0.00'
+    assert float(v_price) == 3.25
+    assert fmt_qty == fmt_cost
+    assert fmt_qty != fmt_price
+
+
+def test_merged_header_vertical_runs_get_visual_merge_borders(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"id": [1], "a": [2], "b": [3]})
+    df_header = pl.DataFrame({"id": ["ID", "ID"], "a": ["Group", "A"], "b": ["Group", "B"]})
+    path_file_out = tmp_path / "vertical_merge.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            header=df_header,
+            should_merge_header=True,
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        l_xfs, _ = _read_styles(zf)
+        root_styles = ET.fromstring(zf.read("xl/styles.xml"))
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_borders = root_styles.findall(".//m:borders/m:border", NS_MAIN)
+
+    def read_border_sides(cell_ref: str) -> dict[str, bool]:
+        node_cell = root_sheet.find(f".//m:c[@r='{cell_ref}']", NS_MAIN)
+        assert node_cell is not None
+        node_xf = l_xfs[int(node_cell.attrib.get("s", "0"))]
+        node_border = l_borders[int(node_xf.attrib.get("borderId", "0"))]
+        return {
+            side: (node := node_border.find(f"m:{side}", NS_MAIN)) is not None
+            and "style" in node.attrib
+            for side in ("top", "bottom", "left", "right")
+        }
+
+    assert read_border_sides("A1") == {"top": True, "bottom": False, "left": True, "right": True}
+    assert read_border_sides("A2") == {"top": False, "bottom": True, "left": True, "right": True}
+    assert read_cell(path_file_out, "A1")[1] == "ID"
+
+
+def test_vertical_header_merge_uses_real_merged_cells(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"id": [1], "a": [2], "b": [3]})
+    df_header = pl.DataFrame({"id": ["X", "X"], "a": ["X", "A"], "b": ["X", "B"]})
+    path_file_out = tmp_path / "vertical_merge_real.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            header=df_header,
+            should_merge_header=True,
+            should_merge_header_vertical=True,
+            policy_autofit=AutofitPolicy(mode="none"),
+        )
+        with pytest.raises(ValueError, match="should_merge_header_vertical"):
+            writer.write_sheet(
+                body=df,
+                sheet_name="T",
+                header=df_header,
+                should_merge_header_vertical=True,
+            )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_merge_refs = sorted(
+        node.attrib["ref"] for node in root_sheet.findall(".//m:mergeCell", NS_MAIN)
+    )
+    assert l_merge_refs == ["A1:A2", "B1:C1"]
+    assert read_cell(path_file_out, "A1")[1] == "X"
+    assert read_cell(path_file_out, "B1")[1] == "X"
+
+
+def test_hidden_columns_keep_data_and_skip_autofit(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"id": [1, 2], "helper": ["lookup-key-" * 4, "k"], "name": ["a", "b"]})
+    path_file_out = tmp_path / "hidden.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_hidden=["helper"],
+            policy_autofit=AutofitPolicy(mode="all"),
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    dict_cols = {
+        node.attrib["min"]: node.attrib for node in root_sheet.findall(".//m:cols/m:col", NS_MAIN)
+    }
+    assert dict_cols["2"].get("hidden") == "1"
+    assert "hidden" not in dict_cols["1"]
+    assert "hidden" not in dict_cols["3"]
+    assert read_cell(path_file_out, "B2")[1] == "lookup-key-" * 4
+
+
+def test_row_groups_set_outline_levels_on_body_rows(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"id": [1, 2, 3, 4, 5]})
+    path_file_out = tmp_path / "row_groups.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            row_groups=[RowGroup(row_start=0, row_end=3), RowGroup(row_start=1, row_end=2, level=2)],
+        )
+        with pytest.raises(ValueError, match="row_groups"):
+            writer.write_sheet(
+                body=df,
+                sheet_name="T",
+                row_groups=[RowGroup(row_start=2, row_end=1)],
+            )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    dict_levels = {
+        node.attrib["r"]: node.attrib.get("outlineLevel")
+        for node in root_sheet.findall(".//m:sheetData/m:row", NS_MAIN)
+    }
+    assert dict_levels == {"1": None, "2": "1", "3": "2", "4": "2", "5": "1", "6": None}
+
+
+def test_zoom_and_hidden_gridlines_set_sheet_view(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"id": [1, 2]})
+    path_file_out = tmp_path / "sheet_view.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(body=df, sheet_name="S", zoom=150, should_hide_gridlines=True)
+        with pytest.raises(ValueError, match="zoom"):
+            writer.write_sheet(body=df, sheet_name="T", zoom=5)
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    node_view = root_sheet.find(".//m:sheetViews/m:sheetView", NS_MAIN)
+    assert node_view is not None
+    assert node_view.attrib["zoomScale"] == "150"
+    assert node_view.attrib["showGridLines"] == "0"
+
+
+def test_tab_color_applies_and_invalid_value_warns(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"id": [1]})
+    path_file_out = tmp_path / "tab_color.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(body=df, sheet_name="Errors", tab_color="#FF0000")
+        writer.write_sheet(body=df, sheet_name="Summary", tab_color="green-ish")
+        l_warnings = writer.report()[1].warnings
+
+    assert any("tab_color" in _warning for _warning in l_warnings)
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet1 = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+        root_sheet2 = ET.fromstring(zf.read("xl/worksheets/sheet2.xml"))
+    node_tab_color = root_sheet1.find("m:sheetPr/m:tabColor", NS_MAIN)
+    assert node_tab_color is not None
+    assert node_tab_color.attrib["rgb"] == "FFFF0000"
+    assert root_sheet2.find("m:sheetPr/m:tabColor", NS_MAIN) is None
+
+
+def test_page_setup_sets_orientation_paper_and_margins(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"id": [1]})
+    path_file_out = tmp_path / "page_setup.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            page_setup=PageSetup(
+                should_use_landscape=True, paper_size=9, margins=(0.25, 0.25, 0.5, 0.5)
+            ),
+        )
+        with pytest.raises(ValueError, match="page_setup.margins"):
+            writer.write_sheet(
+                body=df,
+                sheet_name="T",
+                page_setup=PageSetup(margins=(-1.0, 0.25, 0.5, 0.5)),
+            )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    node_setup = root_sheet.find("m:pageSetup", NS_MAIN)
+    node_margins = root_sheet.find("m:pageMargins", NS_MAIN)
+    assert node_setup is not None
+    assert node_margins is not None
+    assert node_setup.attrib["orientation"] == "landscape"
+    assert node_setup.attrib["paperSize"] == "9"
+    assert float(node_margins.attrib["left"]) == 0.25
+    assert float(node_margins.attrib["top"]) == 0.5
+
+
+def test_page_setup_print_area_and_repeat_header_use_sheet_offsets(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [4, 5, 6], "c": [7, 8, 9]})
+    path_file_out = tmp_path / "print_layout.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            header=pl.DataFrame({"a": ["G", "a"], "b": ["G", "b"], "c": ["H", "c"]}),
+            page_setup=PageSetup(print_area=(1, 1, 2, 2), should_repeat_header_on_print=True),
+        )
+        with pytest.raises(ValueError, match="page_setup.print_area"):
+            writer.write_sheet(
+                body=df,
+                sheet_name="T",
+                page_setup=PageSetup(print_area=(2, 0, 1, 0)),
+            )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_book = ET.fromstring(zf.read("xl/workbook.xml"))
+    defined_names = {
+        node.attrib["name"]: node.text for node in root_book.iter(f"{{{NS_MAIN['m']}}}definedName")
+    }
+    assert defined_names["_xlnm.Print_Area"] == "S!$B$4:$C$5"
+    assert defined_names["_xlnm.Print_Titles"] == "S!$1:$2"
+
+
+def test_page_setup_header_and_footer_text(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"id": [1]})
+    path_file_out = tmp_path / "header_footer.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            page_setup=PageSetup(header_text="&CQuarterly Report", footer_text="&CPage &P of &N"),
+        )
+        with pytest.raises(ValueError, match="page_setup.footer_text"):
+            writer.write_sheet(
+                body=df,
+                sheet_name="T",
+                page_setup=PageSetup(footer_text="x" * 256),
+            )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    node_header_footer = root_sheet.find("m:headerFooter", NS_MAIN)
+    assert node_header_footer is not None
+    assert node_header_footer.findtext("m:oddHeader", namespaces=NS_MAIN) == "&CQuarterly Report"
+    assert node_header_footer.findtext("m:oddFooter", namespaces=NS_MAIN) == "&CPage &P of &N"
+
+
+def test_rich_text_cells_replace_body_values_with_styled_runs(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"label": ["a", "b"], "value": [1, 2]})
+    path_file_out = tmp_path / "rich_text.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            rich_text_cells={(1, 0): [(CellFormatPatch(bold=True), "Total: "), (CellFormatPatch(), "42")]},
+        )
+        with pytest.raises(ValueError, match="rich_text_cells"):
+            writer.write_sheet(
+                body=df,
+                sheet_name="T",
+                rich_text_cells={(0, 0): [(CellFormatPatch(bold=True), "")]},
+            )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_shared = ET.fromstring(zf.read("xl/sharedStrings.xml"))
+    node_rich = next(
+        _node
+        for _node in root_shared.findall("m:si", NS_MAIN)
+        if _node.find("m:r", NS_MAIN) is not None
+    )
+    runs = node_rich.findall("m:r", NS_MAIN)
+    assert [_run.findtext("m:t", namespaces=NS_MAIN) for _run in runs] == ["Total: ", "42"]
+    assert runs[0].find("m:rPr/m:b", NS_MAIN) is not None
+    assert read_cell(path_file_out, "A3")[:2] == ("s", "Total: 42")
+
+
+def test_column_format_override_applies_underline_and_strikethrough(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": ["x"], "b": ["y"]})
+    path_file_out = tmp_path / "font_decoration.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_fmt_overrides={"a": CellFormatPatch(underline=True, strikethrough=True)},
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_styles = ET.fromstring(zf.read("xl/styles.xml"))
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_fonts = root_styles.findall("m:fonts/m:font", NS_MAIN)
+    l_xfs = root_styles.findall("m:cellXfs/m:xf", NS_MAIN)
+
+    def _font_of(cell_ref: str) -> ET.Element:
+        node_cell = root_sheet.find(f".//m:c[@r='{cell_ref}']", NS_MAIN)
+        assert node_cell is not None
+        return l_fonts[int(l_xfs[int(node_cell.attrib.get("s", "0"))].attrib["fontId"])]
+
+    assert _font_of("A2").find("m:u", NS_MAIN) is not None
+    assert _font_of("A2").find("m:strike", NS_MAIN) is not None
+    assert _font_of("B2").find("m:u", NS_MAIN) is None
+    assert CellFormatPatch(strikethrough=True).to_xlsxwriter() == {"font_strikeout": True}
+
+
+def test_format_rotation_applies_and_warns_on_out_of_range(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": ["x"], "b": ["y"]})
+    path_file_out = tmp_path / "rotation.xlsx"
+
+    with XlsxWriter(path_file_out, fmt_header=CellFormatPatch(rotation=90)) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_fmt_overrides={"b": CellFormatPatch(rotation=120)},
+        )
+        report = writer.report()[0]
+
+    assert any("rotation 120" in _warning for _warning in report.warnings)
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_styles = ET.fromstring(zf.read("xl/styles.xml"))
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_xfs = root_styles.findall("m:cellXfs/m:xf", NS_MAIN)
+
+    def _rotation_of(cell_ref: str) -> str | None:
+        node_cell = root_sheet.find(f".//m:c[@r='{cell_ref}']", NS_MAIN)
+        assert node_cell is not None
+        node_align = l_xfs[int(node_cell.attrib.get("s", "0"))].find("m:alignment", NS_MAIN)
+        return None if node_align is None else node_align.attrib.get("textRotation")
+
+    assert _rotation_of("A1") == "90"
+    assert _rotation_of("B2") is None
+
+
+def test_format_indent_is_applied_and_clamped(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": ["x"], "b": ["y"]})
+    path_file_out = tmp_path / "indent.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_fmt_overrides={"a": CellFormatPatch(indent=2), "b": CellFormatPatch(indent=99)},
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_styles = ET.fromstring(zf.read("xl/styles.xml"))
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_xfs = root_styles.findall("m:cellXfs/m:xf", NS_MAIN)
+
+    def _indent_of(cell_ref: str) -> str | None:
+        node_cell = root_sheet.find(f".//m:c[@r='{cell_ref}']", NS_MAIN)
+        assert node_cell is not None
+        node_align = l_xfs[int(node_cell.attrib.get("s", "0"))].find("m:alignment", NS_MAIN)
+        return None if node_align is None else node_align.attrib.get("indent")
+
+    assert _indent_of("A2") == "2"
+    assert _indent_of("B2") == "15"
+
+
+def test_format_shrink_to_fit_is_applied(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": ["a long label that overflows"], "b": ["y"]})
+    path_file_out = tmp_path / "shrink.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_fmt_overrides={"a": CellFormatPatch(shrink_to_fit=True)},
+            cols_width={"a": 8.0},
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_styles = ET.fromstring(zf.read("xl/styles.xml"))
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_xfs = root_styles.findall("m:cellXfs/m:xf", NS_MAIN)
+    node_cell = root_sheet.find(".//m:c[@r='A2']", NS_MAIN)
+    assert node_cell is not None
+    node_align = l_xfs[int(node_cell.attrib.get("s", "0"))].find("m:alignment", NS_MAIN)
+    assert node_align is not None
+    assert node_align.attrib.get("shrinkToFit") == "1"
+    assert CellFormatPatch(shrink_to_fit=True).to_xlsxwriter() == {"shrink": True}
+
+
+def test_format_pattern_fill_uses_foreground_and_background_colors(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": ["x"], "b": ["y"]})
+    path_file_out = tmp_path / "pattern_fill.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_fmt_overrides={
+                "a": CellFormatPatch(pattern=9, fg_color="#FF0000", bg_color="#FFFF00"),
+                "b": CellFormatPatch(bg_color="#00FF00"),
+            },
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_styles = ET.fromstring(zf.read("xl/styles.xml"))
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_fills = root_styles.findall("m:fills/m:fill", NS_MAIN)
+    l_xfs = root_styles.findall("m:cellXfs/m:xf", NS_MAIN)
+
+    def _pattern_fill_of(cell_ref: str) -> ET.Element:
+        node_cell = root_sheet.find(f".//m:c[@r='{cell_ref}']", NS_MAIN)
+        assert node_cell is not None
+        n_fill_id = int(l_xfs[int(node_cell.attrib.get("s", "0"))].attrib["fillId"])
+        node_pattern = l_fills[n_fill_id].find("m:patternFill", NS_MAIN)
+        assert node_pattern is not None
+        return node_pattern
+
+    node_grid = _pattern_fill_of("A2")
+    assert node_grid.attrib["patternType"] == "darkGrid"
+    assert node_grid.find("m:fgColor", NS_MAIN).attrib["rgb"] == "FFFF0000"
+    assert node_grid.find("m:bgColor", NS_MAIN).attrib["rgb"] == "FFFFFF00"
+    assert _pattern_fill_of("B2").attrib["patternType"] == "solid"
+
+
+def test_format_pattern_warns_on_out_of_range_and_is_ignored(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": ["x"]})
+    path_file_out = tmp_path / "pattern_invalid.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_fmt_overrides={"a": CellFormatPatch(pattern=42, fg_color="#FF0000")},
+        )
+        report = writer.report()[0]
+
+    assert any("pattern 42" in _warning for _warning in report.warnings)
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_styles = ET.fromstring(zf.read("xl/styles.xml"))
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    node_cell = root_sheet.find(".//m:c[@r='A2']", NS_MAIN)
+    assert node_cell is not None
+    node_xf = root_styles.findall("m:cellXfs/m:xf", NS_MAIN)[int(node_cell.attrib["s"])]
+    node_fill = root_styles.findall("m:fills/m:fill", NS_MAIN)[int(node_xf.attrib["fillId"])]
+    # The foreground color alone still gives a solid fill.
+    assert node_fill.find("m:patternFill", NS_MAIN).attrib["patternType"] == "solid"
+
+
+def test_format_diagonal_border_is_applied(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": ["x"], "b": ["y"]})
+    path_file_out = tmp_path / "diagonal.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_fmt_overrides={"a": CellFormatPatch(diagonal=1, diagonal_type=3)},
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_styles = ET.fromstring(zf.read("xl/styles.xml"))
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_borders = root_styles.findall("m:borders/m:border", NS_MAIN)
+    l_xfs = root_styles.findall("m:cellXfs/m:xf", NS_MAIN)
+    node_cell = root_sheet.find(".//m:c[@r='A2']", NS_MAIN)
+    assert node_cell is not None
+    node_border = l_borders[int(l_xfs[int(node_cell.attrib.get("s", "0"))].attrib["borderId"])]
+    assert node_border.attrib.get("diagonalUp") == "1"
+    assert node_border.attrib.get("diagonalDown") == "1"
+    node_diagonal = node_border.find("m:diagonal", NS_MAIN)
+    assert node_diagonal is not None
+    assert node_diagonal.attrib["style"] == "thin"
+    assert CellFormatPatch(diagonal=1, diagonal_type=3).to_xlsxwriter() == {
+        "diag_border": 1,
+        "diag_type": 3,
+    }
+
+
+def test_split_mode_is_validated_by_rust_backend(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    opts_bad = XlsxWriteOptions(split_mode="diagonal")  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="split_mode"):
+        XlsxWriter(tmp_path / "bad_split.xlsx", options_write=opts_bad)
+
+    path_file_out = tmp_path / "rows_first.xlsx"
+    opts_rows_first = XlsxWriteOptions(split_mode="rows_first")
+    with XlsxWriter(path_file_out, options_write=opts_rows_first) as writer:
+        writer.write_sheet(body=pl.DataFrame({"x": [1]}), sheet_name="S")
+
+    _, c_value, _ = read_cell(path_file_out, "A2")
+    assert float(c_value) == 1.0
+
+
+def test_sheet_split_template_requires_part_placeholder(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"x": [1]})
+    opts_bad = XlsxWriteOptions(sheet_split_template="{base} part")
+    with XlsxWriter(tmp_path / "bad_template.xlsx", options_write=opts_bad) as writer:
+        with pytest.raises(ValueError, match="sheet_split_template"):
+            writer.write_sheet(body=df, sheet_name="S")
+
+    path_file_out = tmp_path / "template.xlsx"
+    opts_ok = XlsxWriteOptions(sheet_split_template="{base} (part {n})")
+    with XlsxWriter(path_file_out, options_write=opts_ok) as writer:
+        writer.write_sheet(body=df, sheet_name="S")
+        assert [_sheet.sheet_name for _sheet in writer.report()[0].sheets] == ["S"]
+
+
+def test_currency_columns_use_currency_num_format(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"price": ["12.5", "3"], "qty": [1, 2], "fee": [0.5, 1.25]})
+    path_file_out = tmp_path / "currency.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_currency=["price", "qty"],
+            cols_fmt_overrides={"qty": CellFormatPatch(num_format="0")},
+            currency_symbol="€",
+        )
+        with pytest.raises(ValueError, match="currency_decimals"):
+            writer.write_sheet(body=df, sheet_name="T", cols_currency=["fee"], currency_decimals=21)
+
+    c_type, c_value, c_num_fmt = read_cell(path_file_out, "A2")
+    assert c_type != "s"
+    assert float(c_value) == 12.5
+    assert c_num_fmt == "€#,##0.00"
+    assert read_cell(path_file_out, "B2")[2] == "0"
+    assert read_cell(path_file_out, "C2")[2] != "€#,##0.00"
+
+
+def test_percent_columns_use_percent_num_format(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"rate": [0.25, 0.5], "share": [0.125, 1.0], "n": [1, 2]})
+    path_file_out = tmp_path / "percent.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(body=df, sheet_name="S", cols_percent=["rate", "share"], percent_decimals=1)
+        with pytest.raises(ValueError, match="percent_decimals"):
+            writer.write_sheet(body=df, sheet_name="T", cols_percent=["rate"], percent_decimals=21)
+
+    _, c_value, c_num_fmt = read_cell(path_file_out, "A2")
+    assert float(c_value) == 0.25
+    assert c_num_fmt == "0.0%"
+    assert read_cell(path_file_out, "B2")[2] == "0.0%"
+    assert read_cell(path_file_out, "C2")[2] != "0.0%"
+
+
+def test_integer_thousands_sep_keeps_column_overrides(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"count": [1_234_567, 8], "id": [42, 7]})
+    path_file_out = tmp_path / "thousands.xlsx"
+    opts = XlsxWriteOptions(should_use_integer_thousands_sep=True)
+
+    with XlsxWriter(path_file_out, options_write=opts) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            cols_fmt_overrides={"id": CellFormatPatch(num_format="000")},
+        )
+
+    _, c_value, c_num_fmt = read_cell(path_file_out, "A2")
+    assert float(c_value) == 1_234_567
+    assert c_num_fmt == "#,##0"
+    assert read_cell(path_file_out, "B2")[2] == "000"
+
+
+def test_default_col_width_and_row_height_apply_under_autofit(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"a": [1], "b": ["x"]})
+    opts_bad = XlsxWriteOptions(default_col_width=0.0)
+    with XlsxWriter(tmp_path / "bad_layout.xlsx", options_write=opts_bad) as writer:
+        with pytest.raises(ValueError, match="default_col_width"):
+            writer.write_sheet(body=df, sheet_name="S")
+
+    path_file_out = tmp_path / "layout.xlsx"
+    opts = XlsxWriteOptions(default_col_width=20.0, default_row_height=24.0)
+    with XlsxWriter(path_file_out, options_write=opts) as writer:
+        writer.write_sheet(
+            body=df, sheet_name="S", cols_width={"b": 30.0}, policy_autofit=AutofitPolicy(mode="none")
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    node_fmt = root_sheet.find(".//m:sheetFormatPr", NS_MAIN)
+    assert node_fmt is not None
+    assert float(node_fmt.attrib["defaultRowHeight"]) == 24.0
+    dict_widths = {
+        node.attrib["min"]: float(node.attrib["width"])
+        for node in root_sheet.findall(".//m:cols/m:col", NS_MAIN)
+    }
+    assert dict_widths["1"] > 20.0
+    assert dict_widths["2"] > 30.0
+
+
+def test_banded_rows_shade_every_second_body_row(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"name": ["a", "b", "c"], "score": [1.0, 5.0, 9.0]})
+    path_file_out = tmp_path / "banded.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(body=df, sheet_name="S", should_band_rows=True, band_color="#DDEEFF")
+        with pytest.raises(ValueError, match="band_color"):
+            writer.write_sheet(body=df, sheet_name="T", should_band_rows=True, band_color="blue")
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+        c_styles = zf.read("xl/styles.xml").decode()
+    l_nodes = root_sheet.findall("m:conditionalFormatting", NS_MAIN)
+    assert [node.attrib["sqref"] for node in l_nodes] == ["A2:B4"]
+    node_formula = l_nodes[0].find("m:cfRule/m:formula", NS_MAIN)
+    assert node_formula is not None
+    assert node_formula.text == "MOD(ROW()-1,2)=0"
+    assert "FFDDEEFF" in c_styles
+
+
+def test_report_lists_inferred_numeric_integer_and_scientific_columns(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"name": ["a", "b"], "n": [1, 2], "x": [0.5, 1e-9]})
+
+    with XlsxWriter(tmp_path / "report_cols.xlsx") as writer:
+        writer.write_sheet(
+            body=df, sheet_name="S", policy_scientific=ScientificPolicy(scope="decimal")
+        )
+        report = writer.report()[0]
+
+    assert report.cols_numeric == ["n", "x"]
+    assert report.cols_integer == ["n"]
+    assert report.cols_scientific == ["x"]
+
+
+def test_report_counts_cells_blanked_and_coerced_by_value_policy(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"x": [1.0, None, float("nan")], "n": [2.5, 3.0, 4.0]})
+    opts = XlsxWriteOptions(value_policy=XlsxValuePolicy(integer_coerce="coerce"))
+
+    with XlsxWriter(tmp_path / "report_counts.xlsx", options_write=opts) as writer:
+        writer.write_sheet(body=df, sheet_name="S", cols_integer=["n"])
+        writer.write_sheet(
+            body=df.select("x"), sheet_name="T", should_keep_missing_values=True
+        )
+        report_blank, report_keep = writer.report()
+
+    assert (report_blank.count_cells_blanked, report_blank.count_cells_coerced) == (2, 1)
+    assert (report_keep.count_cells_blanked, report_keep.count_cells_coerced) == (0, 2)
+
+
+def test_summary_row_writes_values_or_formulas_below_body(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"x": [1, 2, 3], "y": [1.5, 2.5, 4.0], "name": ["a", None, "c"]})
+    summary_row = {"x": "sum", "y": "mean", "name": "count"}
+    path_file_out = tmp_path / "summary_row.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(body=df, sheet_name="S", summary_row=summary_row)
+        writer.write_sheet(
+            body=df,
+            sheet_name="F",
+            summary_row=summary_row,
+            should_use_summary_formulas=True,
+        )
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_values = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+        root_formulas = ET.fromstring(zf.read("xl/worksheets/sheet2.xml"))
+    l_values = root_values.findall("m:sheetData/m:row[@r='5']/m:c/m:v", NS_MAIN)
+    assert [float(node.text or "") for node in l_values] == [6.0, 8.0 / 3.0, 2.0]
+    l_formulas = root_formulas.findall("m:sheetData/m:row[@r='5']/m:c/m:f", NS_MAIN)
+    assert [node.text for node in l_formulas] == [
+        "SUM(A2:A4)",
+        "AVERAGE(B2:B4)",
+        "COUNTA(C2:C4)",
+    ]
+
+
+def test_formula_cells_replace_body_values_with_live_formulas(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"x": [1, 2], "y": [3, 4], "xy": [None, None]})
+    path_file_out = tmp_path / "formula_cells.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            formula_cells={(0, 2): "=A2*B2", (1, 2): "=A3*B3"},
+        )
+        with pytest.raises(ValueError, match="must start with '='"):
+            writer.write_sheet(body=df, sheet_name="E", formula_cells={(0, 2): "A2*B2"})
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_formulas = root_sheet.findall("m:sheetData/m:row/m:c/m:f", NS_MAIN)
+    assert [node.text for node in l_formulas] == ["A2*B2", "A3*B3"]
+
+
+def test_formula_cells_outside_body_are_ignored_with_warning(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    df = pl.DataFrame({"x": [1, 2]})
+    path_file_out = tmp_path / "formula_cells_out_of_range.xlsx"
+
+    with XlsxWriter(path_file_out) as writer:
+        writer.write_sheet(
+            body=df,
+            sheet_name="S",
+            formula_cells={(0, 0): "=1+1", (2, 0): "=2+2", (0, 1): "=3+3"},
+        )
+        report = writer.report()[0]
+
+    assert any(
+        "Ignored 2 formula_cells key(s)" in _warning for _warning in report.warnings
+    )
+    with zipfile.ZipFile(path_file_out) as zf:
+        root_sheet = ET.fromstring(zf.read("xl/worksheets/sheet1.xml"))
+    l_formulas = root_sheet.findall("m:sheetData/m:row/m:c/m:f", NS_MAIN)
+    assert [node.text for node in l_formulas] == ["1+1"]
+
+
+def test_doc_properties_are_saved_and_blank_fields_left_unset(tmp_path: Path) -> None:
+    if not is_rs_backend_available():
+        pytest.skip("Rust xlsx backend is unavailable")
+
+    path_file_out = tmp_path / "doc_properties.xlsx"
+    doc_properties = {"title": "Q3 report", "author": "  ", "company": "Acme"}
+
+    with XlsxWriter(path_file_out, doc_properties=doc_properties) as writer:
+        writer.write_sheet(body=pl.DataFrame({"x": [1]}), sheet_name="S")
+    with pytest.raises(ValueError, match="doc_properties key"):
+        XlsxWriter(tmp_path / "bad.xlsx", doc_properties={"owner": "me"})
+
+    with zipfile.ZipFile(path_file_out) as zf:
+        c_core = zf.read("docProps/core.xml").decode()
+        c_app = zf.read("docProps/app.xml").decode()
+    assert "<dc:title>Q3 report</dc:title>" in c_core
+    assert "<dc:creator></dc:creator>" in c_core
+    assert "<Company>Acme</Company>" in c_app